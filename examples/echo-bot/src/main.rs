@@ -6,13 +6,11 @@ use axum::{
     routing::post,
     Router,
 };
-use line_bot_sdk_messaging_api::{
-    apis::{configuration::Configuration, messaging_api_api::reply_message},
-    models::{Message, ReplyMessageRequest, TextMessage},
+use line_bot_sdk_messaging_api::apis::{
+    configuration::Configuration, messaging_api_api::reply_text,
 };
 use line_bot_sdk_utils::signature::validate_signature;
 use line_bot_sdk_webhook::models::{CallbackRequest, Event};
-use serde_json::json;
 use std::env;
 
 #[tokio::main]
@@ -108,64 +106,25 @@ async fn handle_event(
     event: &Event,
     config: &Configuration,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Check if this is a message event with text content
-    // The Event enum uses internally tagged enum, so we need to deserialize to get full MessageEvent
-    let event_json = serde_json::to_value(event)?;
-    
-    // Check if it's a message event
-    if event_json.get("type") != Some(&json!("message")) {
-        return Ok(());
-    }
-
-    // Deserialize to get the full MessageEvent structure
-    // We'll parse it manually from the JSON
-    let reply_token = event_json
-        .get("replyToken")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string());
-
-    let reply_token = match reply_token {
-        Some(token) => token,
-        None => {
-            // No reply token, ignore this event
-            return Ok(());
-        }
+    // Only message events carry a message to echo
+    let message_event = match event.as_message_event() {
+        Some(message_event) => message_event,
+        None => return Ok(()),
     };
 
-    // Get the message content
-    let message_obj = event_json
-        .get("message")
-        .ok_or("Missing message field")?;
-
-    // Check if it's a text message
-    if message_obj.get("type") != Some(&json!("text")) {
-        return Ok(());
-    }
-
-    let text = message_obj
-        .get("text")
-        .and_then(|v| v.as_str())
-        .ok_or("Missing text field")?
-        .to_string();
-
-    // Create TextMessage struct
-    let text_message = TextMessage {
-        r#type: Some("text".to_string()),
-        quick_reply: None,
-        sender: None,
-        text: text.clone(),
-        emojis: None,
-        quote_token: None,
+    // No reply token, ignore this event
+    let reply_token = match event.reply_token() {
+        Some(token) => token.to_string(),
+        None => return Ok(()),
     };
 
-    // Convert TextMessage struct to Message enum
-    let message: Message = text_message.into();
-
-    // Create reply request
-    let reply_request = ReplyMessageRequest::new(reply_token, vec![message]);
+    // Only echo text messages
+    let text = match message_event.message.as_text() {
+        Some(content) => content.text.clone(),
+        None => return Ok(()),
+    };
 
-    // Send reply
-    reply_message(config, reply_request).await?;
+    // Echo the text back
+    reply_text(config, reply_token, text).await?;
     Ok(())
 }
-
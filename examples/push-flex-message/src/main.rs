@@ -11,18 +11,6 @@ use line_bot_sdk_messaging_api::models::flex_text::Weight;
 use line_bot_sdk_messaging_api::models::flex_button::{Style, Height};
 use std::env;
 
-/// Helper trait to convert structs to their enum wrappers
-/// Needed because OpenAPI generator doesn't support allOf in enum variants
-trait IntoEnum<T> {
-    fn into_enum(self) -> Result<T, serde_json::Error>;
-}
-
-impl<S: serde::Serialize, T: serde::de::DeserializeOwned> IntoEnum<T> for S {
-    fn into_enum(self) -> Result<T, serde_json::Error> {
-        serde_json::from_value(serde_json::to_value(self)?)
-    }
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get configuration from environment variables
@@ -37,7 +25,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Build the flex message structure
-    let flex_message = build_flex_message()?;
+    let flex_message = build_flex_message();
 
     // Convert FlexMessage struct to Message enum
     let message: Message = flex_message.into();
@@ -54,7 +42,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
+fn build_flex_message() -> FlexMessage {
     // Build hero image with URI action
     let hero_uri_action = UriAction {
         r#type: Some("uri".to_string()),
@@ -62,7 +50,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         uri: Some("https://line.me/".to_string()),
         alt_uri: None,
     };
-    let hero_action: Action = hero_uri_action.into_enum()?;
+    let hero_action: Action = hero_uri_action.into();
 
     let hero_image = FlexImage {
         r#type: "image".to_string(),
@@ -83,7 +71,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         action: Some(Box::new(hero_action)),
         animated: None,
     };
-    let hero_component: FlexComponent = hero_image.into_enum()?;
+    let hero_component: FlexComponent = hero_image.into();
 
     // Build body content
     // Title text: "Brown Cafe"
@@ -112,7 +100,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let title_component: FlexComponent = title_text.into_enum()?;
+    let title_component: FlexComponent = title_text.into();
 
     // Rating stars and text
     let gold_star_icon = FlexIcon {
@@ -129,7 +117,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         offset_end: None,
         scaling: None,
     };
-    let gold_star_component: FlexComponent = gold_star_icon.into_enum()?;
+    let gold_star_component: FlexComponent = gold_star_icon.into();
 
     let gray_star_icon = FlexIcon {
         r#type: Some("icon".to_string()),
@@ -145,7 +133,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         offset_end: None,
         scaling: None,
     };
-    let gray_star_component: FlexComponent = gray_star_icon.into_enum()?;
+    let gray_star_component: FlexComponent = gray_star_icon.into();
 
     let rating_text = FlexText {
         r#type: "text".to_string(),
@@ -172,7 +160,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let rating_text_component: FlexComponent = rating_text.into_enum()?;
+    let rating_text_component: FlexComponent = rating_text.into();
 
     // Rating box (baseline layout with stars and rating)
     let rating_box_contents = vec![
@@ -183,37 +171,10 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         gray_star_component,
         rating_text_component,
     ];
-    let rating_box = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Baseline,
-        flex: None,
-        contents: rating_box_contents,
-        spacing: None,
-        margin: Some("md".to_string()),
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
-    let rating_box_component: FlexComponent = rating_box.into_enum()?;
+    let rating_box = FlexBox::builder(Layout::Baseline, rating_box_contents)
+        .margin("md")
+        .build();
+    let rating_box_component: FlexComponent = rating_box.into();
 
     // Place row
     let place_label = FlexText {
@@ -241,7 +202,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let place_label_component: FlexComponent = place_label.into_enum()?;
+    let place_label_component: FlexComponent = place_label.into();
 
     let place_value = FlexText {
         r#type: "text".to_string(),
@@ -268,39 +229,15 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let place_value_component: FlexComponent = place_value.into_enum()?;
+    let place_value_component: FlexComponent = place_value.into();
 
-    let place_row = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Baseline,
-        flex: None,
-        contents: vec![place_label_component, place_value_component],
-        spacing: Some("sm".to_string()),
-        margin: None,
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
-    let place_row_component: FlexComponent = place_row.into_enum()?;
+    let place_row = FlexBox::builder(
+        Layout::Baseline,
+        vec![place_label_component, place_value_component],
+    )
+    .spacing("sm")
+    .build();
+    let place_row_component: FlexComponent = place_row.into();
 
     // Time row
     let time_label = FlexText {
@@ -328,7 +265,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let time_label_component: FlexComponent = time_label.into_enum()?;
+    let time_label_component: FlexComponent = time_label.into();
 
     let time_value = FlexText {
         r#type: "text".to_string(),
@@ -355,104 +292,32 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let time_value_component: FlexComponent = time_value.into_enum()?;
+    let time_value_component: FlexComponent = time_value.into();
 
-    let time_row = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Baseline,
-        flex: None,
-        contents: vec![time_label_component, time_value_component],
-        spacing: Some("sm".to_string()),
-        margin: None,
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
-    let time_row_component: FlexComponent = time_row.into_enum()?;
+    let time_row = FlexBox::builder(
+        Layout::Baseline,
+        vec![time_label_component, time_value_component],
+    )
+    .spacing("sm")
+    .build();
+    let time_row_component: FlexComponent = time_row.into();
 
     // Info box (vertical layout with place and time rows)
-    let info_box = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Vertical,
-        flex: None,
-        contents: vec![place_row_component, time_row_component],
-        spacing: Some("sm".to_string()),
-        margin: Some("lg".to_string()),
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
-    let info_box_component: FlexComponent = info_box.into_enum()?;
+    let info_box = FlexBox::builder(
+        Layout::Vertical,
+        vec![place_row_component, time_row_component],
+    )
+    .spacing("sm")
+    .margin("lg")
+    .build();
+    let info_box_component: FlexComponent = info_box.into();
 
     // Body box (vertical layout with title, rating, and info)
-    let body_box = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Vertical,
-        flex: None,
-        contents: vec![title_component, rating_box_component, info_box_component],
-        spacing: None,
-        margin: None,
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
+    let body_box = FlexBox::vertical(vec![
+        title_component,
+        rating_box_component,
+        info_box_component,
+    ]);
 
     // Footer buttons
     let call_button_action = UriAction {
@@ -461,7 +326,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         uri: Some("https://line.me/".to_string()),
         alt_uri: None,
     };
-    let call_button_action_enum: Action = call_button_action.into_enum()?;
+    let call_button_action_enum: Action = call_button_action.into();
 
     let call_button = FlexButton {
         r#type: Some("button".to_string()),
@@ -480,7 +345,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let call_button_component: FlexComponent = call_button.into_enum()?;
+    let call_button_component: FlexComponent = call_button.into();
 
     let website_button_action = UriAction {
         r#type: Some("uri".to_string()),
@@ -488,7 +353,7 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         uri: Some("https://line.me/".to_string()),
         alt_uri: None,
     };
-    let website_button_action_enum: Action = website_button_action.into_enum()?;
+    let website_button_action_enum: Action = website_button_action.into();
 
     let website_button = FlexButton {
         r#type: Some("button".to_string()),
@@ -507,90 +372,34 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         adjust_mode: None,
         scaling: None,
     };
-    let website_button_component: FlexComponent = website_button.into_enum()?;
+    let website_button_component: FlexComponent = website_button.into();
 
     // Empty spacer box
-    let spacer_box = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Vertical,
-        flex: None,
-        contents: vec![],
-        spacing: None,
-        margin: Some("sm".to_string()),
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
-    let spacer_box_component: FlexComponent = spacer_box.into_enum()?;
+    let spacer_box = FlexBox::builder(Layout::Vertical, vec![])
+        .margin("sm")
+        .build();
+    let spacer_box_component: FlexComponent = spacer_box.into();
 
     // Footer box
-    let footer_box = FlexBox {
-        r#type: Some("box".to_string()),
-        layout: Layout::Vertical,
-        flex: Some(0),
-        contents: vec![
+    let footer_box = FlexBox::builder(
+        Layout::Vertical,
+        vec![
             call_button_component,
             website_button_component,
             spacer_box_component,
         ],
-        spacing: Some("sm".to_string()),
-        margin: None,
-        position: None,
-        offset_top: None,
-        offset_bottom: None,
-        offset_start: None,
-        offset_end: None,
-        background_color: None,
-        border_color: None,
-        border_width: None,
-        corner_radius: None,
-        width: None,
-        max_width: None,
-        height: None,
-        max_height: None,
-        padding_all: None,
-        padding_top: None,
-        padding_bottom: None,
-        padding_start: None,
-        padding_end: None,
-        action: None,
-        justify_content: None,
-        align_items: None,
-        background: None,
-    };
+    )
+    .flex(0)
+    .spacing("sm")
+    .build();
 
     // Build FlexBubble
-    let bubble = FlexBubble {
-        r#type: "bubble".to_string(),
-        direction: None,
-        styles: None,
-        header: None,
-        hero: Some(Box::new(hero_component)),
-        body: Some(Box::new(body_box)),
-        footer: Some(Box::new(footer_box)),
-        size: None,
-        action: None,
-    };
-    let flex_container: FlexContainer = bubble.into_enum()?;
+    let bubble = FlexBubble::builder()
+        .hero(hero_component)
+        .body(body_box)
+        .footer(footer_box)
+        .build();
+    let flex_container: FlexContainer = bubble.into();
 
     // Build FlexMessage
     let flex_message = FlexMessage {
@@ -601,6 +410,5 @@ fn build_flex_message() -> Result<FlexMessage, Box<dyn std::error::Error>> {
         contents: Box::new(flex_container),
     };
 
-    Ok(flex_message)
+    flex_message
 }
-
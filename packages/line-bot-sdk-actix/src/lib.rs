@@ -0,0 +1,130 @@
+//! Actix Web integration for LINE webhooks.
+//!
+//! [`LineWebhook`] reads the request body, verifies the `X-Line-Signature`
+//! header and deserializes the [`CallbackRequest`], rejecting the request
+//! before the handler runs if any step fails.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::{
+    dev::Payload, http::StatusCode, web, FromRequest, HttpRequest, HttpResponse, ResponseError,
+};
+use line_bot_sdk_utils::signature::SIGNATURE_HEADER;
+use line_bot_sdk_webhook::{models::CallbackRequest, WebhookError, WebhookParser};
+
+/// Extractor for a verified LINE webhook request.
+///
+/// The [`WebhookParser`] holding the channel secret is taken from the app
+/// data as `web::Data<WebhookParser>`.
+///
+/// # Example
+///
+/// ```no_run
+/// use actix_web::{web, App};
+/// use line_bot_sdk_actix::LineWebhook;
+/// use line_bot_sdk_webhook::WebhookParser;
+///
+/// async fn callback(LineWebhook(req): LineWebhook) -> String {
+///     format!("{} events", req.events.len())
+/// }
+///
+/// let parser = WebhookParser::new("your_channel_secret").unwrap();
+/// let app = App::new()
+///     .app_data(web::Data::new(parser))
+///     .route("/callback", web::post().to(callback));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineWebhook(pub CallbackRequest);
+
+impl FromRequest for LineWebhook {
+    type Error = LineWebhookRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let parser = req
+            .app_data::<web::Data<WebhookParser>>()
+            .map(|parser| parser.get_ref().clone());
+        let signature = req
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .map(|value| value.to_str().map(str::to_owned));
+        let body = web::Bytes::from_request(req, payload);
+
+        Box::pin(async move {
+            let parser = parser.ok_or(LineWebhookRejection::MissingParser)?;
+            let signature = signature
+                .ok_or(LineWebhookRejection::MissingSignature)?
+                .map_err(|_| LineWebhookRejection::InvalidSignatureHeader)?;
+            let body = body
+                .await
+                .map_err(|e| LineWebhookRejection::Body(e.to_string()))?;
+
+            parser
+                .parse(&body, &signature)
+                .map(LineWebhook)
+                .map_err(LineWebhookRejection::Webhook)
+        })
+    }
+}
+
+/// Why a request was rejected by the [`LineWebhook`] extractor.
+///
+/// Signature mismatches respond with `401 Unauthorized` and a missing
+/// [`WebhookParser`] with `500 Internal Server Error`; everything else
+/// responds with `400 Bad Request`.
+#[derive(Debug)]
+pub enum LineWebhookRejection {
+    /// No `web::Data<WebhookParser>` was registered with the app.
+    MissingParser,
+    /// The `X-Line-Signature` header is missing.
+    MissingSignature,
+    /// The `X-Line-Signature` header is not valid UTF-8.
+    InvalidSignatureHeader,
+    /// The request body could not be read.
+    Body(String),
+    /// The signature did not match or the body could not be parsed.
+    Webhook(WebhookError),
+}
+
+impl fmt::Display for LineWebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWebhookRejection::MissingParser => {
+                write!(f, "no WebhookParser registered in the app data")
+            }
+            LineWebhookRejection::MissingSignature => write!(f, "missing X-Line-Signature header"),
+            LineWebhookRejection::InvalidSignatureHeader => {
+                write!(f, "invalid X-Line-Signature header")
+            }
+            LineWebhookRejection::Body(e) => write!(f, "error reading request body: {}", e),
+            LineWebhookRejection::Webhook(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for LineWebhookRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineWebhookRejection::Webhook(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl ResponseError for LineWebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            LineWebhookRejection::MissingParser => StatusCode::INTERNAL_SERVER_ERROR,
+            LineWebhookRejection::Webhook(WebhookError::InvalidSignature) => {
+                StatusCode::UNAUTHORIZED
+            }
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
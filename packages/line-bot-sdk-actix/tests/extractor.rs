@@ -0,0 +1,69 @@
+use actix_web::{http::StatusCode, test, web, App};
+use line_bot_sdk_actix::LineWebhook;
+use line_bot_sdk_utils::signature::compute_signature;
+use line_bot_sdk_webhook::WebhookParser;
+
+const CHANNEL_SECRET: &str = "testsecret";
+const BODY: &str = r#"{"destination":"U0123456789abcdef0123456789abcdef","events":[]}"#;
+
+async fn callback(LineWebhook(req): LineWebhook) -> String {
+    format!("{} events for {}", req.events.len(), req.destination)
+}
+
+async fn call(with_parser: bool, signature: Option<&str>, body: &str) -> (StatusCode, String) {
+    let mut app = App::new().route("/callback", web::post().to(callback));
+    if with_parser {
+        app = app.app_data(web::Data::new(WebhookParser::new(CHANNEL_SECRET).unwrap()));
+    }
+    let app = test::init_service(app).await;
+
+    let mut request = test::TestRequest::post()
+        .uri("/callback")
+        .insert_header(("content-type", "application/json"));
+    if let Some(signature) = signature {
+        request = request.insert_header(("x-line-signature", signature));
+    }
+    let response =
+        test::call_service(&app, request.set_payload(body.to_owned()).to_request()).await;
+    let status = response.status();
+    let body = test::read_body(response).await;
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[actix_web::test]
+async fn valid_signature_reaches_the_handler() {
+    let signature = compute_signature(BODY.as_bytes(), CHANNEL_SECRET);
+
+    let (status, body) = call(true, Some(&signature), BODY).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "0 events for U0123456789abcdef0123456789abcdef");
+}
+
+#[actix_web::test]
+async fn tampered_body_is_unauthorized() {
+    let signature = compute_signature(BODY.as_bytes(), CHANNEL_SECRET);
+    let tampered = r#"{"destination":"U456","events":[]}"#;
+
+    let (status, _) = call(true, Some(&signature), tampered).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn missing_signature_is_a_bad_request() {
+    let (status, body) = call(true, None, BODY).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, "missing X-Line-Signature header");
+}
+
+#[actix_web::test]
+async fn missing_parser_is_a_server_error() {
+    let signature = compute_signature(BODY.as_bytes(), CHANNEL_SECRET);
+
+    let (status, body) = call(false, Some(&signature), BODY).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body, "no WebhookParser registered in the app data");
+}
@@ -0,0 +1,119 @@
+//! Axum integration for LINE webhooks.
+//!
+//! [`LineWebhook`] reads the request body, verifies the `X-Line-Signature`
+//! header and deserializes the [`CallbackRequest`], rejecting the request
+//! before the handler runs if any step fails.
+
+use std::fmt;
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{FromRef, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use line_bot_sdk_utils::signature::SIGNATURE_HEADER;
+use line_bot_sdk_webhook::{models::CallbackRequest, WebhookError, WebhookParser};
+
+/// Extractor for a verified LINE webhook request.
+///
+/// The [`WebhookParser`] holding the channel secret is taken from the router
+/// state, either directly or through [`FromRef`].
+///
+/// # Example
+///
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use line_bot_sdk_axum::LineWebhook;
+/// use line_bot_sdk_webhook::WebhookParser;
+///
+/// async fn handler(LineWebhook(req): LineWebhook) {
+///     println!("{} events", req.events.len());
+/// }
+///
+/// let parser = WebhookParser::new("your_channel_secret").unwrap();
+/// let app: Router = Router::new()
+///     .route("/callback", post(handler))
+///     .with_state(parser);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineWebhook(pub CallbackRequest);
+
+#[async_trait]
+impl<S> FromRequest<S> for LineWebhook
+where
+    WebhookParser: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = LineWebhookRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let parser = WebhookParser::from_ref(state);
+        let signature = req
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .ok_or(LineWebhookRejection::MissingSignature)?
+            .to_str()
+            .map_err(|_| LineWebhookRejection::InvalidSignatureHeader)?
+            .to_owned();
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| LineWebhookRejection::Body(e.body_text()))?;
+
+        parser
+            .parse(&body, &signature)
+            .map(LineWebhook)
+            .map_err(LineWebhookRejection::Webhook)
+    }
+}
+
+/// Why a request was rejected by the [`LineWebhook`] extractor.
+///
+/// Signature mismatches respond with `401 Unauthorized`; everything else
+/// responds with `400 Bad Request`.
+#[derive(Debug)]
+pub enum LineWebhookRejection {
+    /// The `X-Line-Signature` header is missing.
+    MissingSignature,
+    /// The `X-Line-Signature` header is not valid UTF-8.
+    InvalidSignatureHeader,
+    /// The request body could not be read.
+    Body(String),
+    /// The signature did not match or the body could not be parsed.
+    Webhook(WebhookError),
+}
+
+impl fmt::Display for LineWebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWebhookRejection::MissingSignature => write!(f, "missing X-Line-Signature header"),
+            LineWebhookRejection::InvalidSignatureHeader => {
+                write!(f, "invalid X-Line-Signature header")
+            }
+            LineWebhookRejection::Body(e) => write!(f, "error reading request body: {}", e),
+            LineWebhookRejection::Webhook(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for LineWebhookRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineWebhookRejection::Webhook(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl IntoResponse for LineWebhookRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            LineWebhookRejection::Webhook(WebhookError::InvalidSignature) => {
+                StatusCode::UNAUTHORIZED
+            }
+            _ => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,66 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Router,
+};
+use http_body_util::BodyExt;
+use line_bot_sdk_axum::LineWebhook;
+use line_bot_sdk_utils::signature::compute_signature;
+use line_bot_sdk_webhook::WebhookParser;
+use tower::ServiceExt;
+
+const CHANNEL_SECRET: &str = "testsecret";
+const BODY: &str = r#"{"destination":"U0123456789abcdef0123456789abcdef","events":[]}"#;
+
+async fn handler(LineWebhook(req): LineWebhook) -> String {
+    format!("{} events for {}", req.events.len(), req.destination)
+}
+
+fn app() -> Router {
+    Router::new()
+        .route("/callback", post(handler))
+        .with_state(WebhookParser::new(CHANNEL_SECRET).unwrap())
+}
+
+fn request(signature: Option<&str>) -> Request<Body> {
+    let mut builder = Request::post("/callback").header("content-type", "application/json");
+    if let Some(signature) = signature {
+        builder = builder.header("x-line-signature", signature);
+    }
+    builder.body(Body::from(BODY)).unwrap()
+}
+
+async fn call(request: Request<Body>) -> (StatusCode, String) {
+    let response = app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn valid_signature_reaches_the_handler() {
+    let signature = compute_signature(BODY.as_bytes(), CHANNEL_SECRET);
+
+    let (status, body) = call(request(Some(&signature))).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "0 events for U0123456789abcdef0123456789abcdef");
+}
+
+#[tokio::test]
+async fn bad_signature_is_unauthorized() {
+    let signature = compute_signature(BODY.as_bytes(), "another channel secret");
+
+    let (status, _) = call(request(Some(&signature))).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn missing_signature_is_a_bad_request() {
+    let (status, body) = call(request(None)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, "missing X-Line-Signature header");
+}
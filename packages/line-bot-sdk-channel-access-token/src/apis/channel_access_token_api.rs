@@ -180,7 +180,17 @@ pub async fn issue_channel_token(
     }
 }
 
+/// `grant_type` expected by [`issue_channel_token_by_jwt`].
+pub const CLIENT_CREDENTIALS_GRANT_TYPE: &str = "client_credentials";
+/// `client_assertion_type` expected by [`issue_channel_token_by_jwt`].
+pub const JWT_BEARER_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
 /// Issues a channel access token that allows you to specify a desired expiration date. This method lets you use JWT assertion for authentication.
+///
+/// Pass [`CLIENT_CREDENTIALS_GRANT_TYPE`] and [`JWT_BEARER_ASSERTION_TYPE`]
+/// along with the signed JWT as `client_assertion`. Revoke the issued token
+/// with [`revoke_channel_token_by_jwt`].
 pub async fn issue_channel_token_by_jwt(
     configuration: &configuration::Configuration,
     grant_type: &str,
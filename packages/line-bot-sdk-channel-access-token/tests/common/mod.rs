@@ -0,0 +1,101 @@
+//! A local HTTP server answering each request with a scripted reply.
+
+#![allow(dead_code)]
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A request received by [`serve`].
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    /// The path and query, e.g. `/oauth2/v2.1/tokens/kid?client_assertion=...`.
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+
+    /// Decodes an `application/x-www-form-urlencoded` body, sorted by key.
+    pub fn form(&self) -> Vec<(String, String)> {
+        let mut form: Vec<_> = url::form_urlencoded::parse(&self.body)
+            .into_owned()
+            .collect();
+        form.sort();
+        form
+    }
+}
+
+/// Answers one connection per `(status, json_body)` reply, in order.
+///
+/// Returns the base path to configure and a handle resolving to the
+/// requests received once every reply has been sent.
+pub async fn serve(replies: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Recorded>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_path = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let mut requests = vec![];
+        for (status, body) in replies {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            requests.push(read_request(&mut stream).await);
+            let response = format!(
+                "HTTP/1.1 {status} Status\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream
+                .get_mut()
+                .write_all(response.as_bytes())
+                .await
+                .unwrap();
+        }
+        requests
+    });
+    (base_path, handle)
+}
+
+async fn read_request(stream: &mut BufReader<tokio::net::TcpStream>) -> Recorded {
+    let mut line = String::new();
+    stream.read_line(&mut line).await.unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap().to_string();
+    let target = parts.next().unwrap().to_string();
+
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').unwrap();
+        headers.push((name.to_ascii_lowercase(), value.trim().to_string()));
+    }
+
+    let length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .map_or(0, |(_, value)| value.parse().unwrap());
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).await.unwrap();
+
+    Recorded {
+        method,
+        target,
+        headers,
+        body,
+    }
+}
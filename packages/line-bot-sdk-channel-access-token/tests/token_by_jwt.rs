@@ -0,0 +1,127 @@
+mod common;
+
+use line_bot_sdk_channel_access_token::apis::channel_access_token_api::{
+    issue_channel_token_by_jwt, revoke_channel_token_by_jwt, CLIENT_CREDENTIALS_GRANT_TYPE,
+    JWT_BEARER_ASSERTION_TYPE,
+};
+use line_bot_sdk_channel_access_token::apis::configuration::Configuration;
+use line_bot_sdk_channel_access_token::apis::Error;
+
+const TOKEN_RESPONSE: &str = r#"{
+    "access_token": "eyJhbGciOiJIUz.....",
+    "token_type": "Bearer",
+    "expires_in": 2592000,
+    "key_id": "sDTOzw5wIfxxxxPEzcmeQA"
+}"#;
+
+fn configuration(base_path: String) -> Configuration {
+    Configuration {
+        base_path,
+        ..Configuration::default()
+    }
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn issue_channel_token_by_jwt_posts_a_form_and_parses_the_token() {
+    let (base_path, server) = common::serve(vec![(200, TOKEN_RESPONSE)]).await;
+
+    let token = issue_channel_token_by_jwt(
+        &configuration(base_path),
+        CLIENT_CREDENTIALS_GRANT_TYPE,
+        JWT_BEARER_ASSERTION_TYPE,
+        "eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl+/=",
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(token.access_token, "eyJhbGciOiJIUz.....");
+    assert_eq!(token.token_type, "Bearer");
+    assert_eq!(token.expires_in, 2592000);
+    assert_eq!(token.key_id, "sDTOzw5wIfxxxxPEzcmeQA");
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/oauth2/v2.1/token");
+    assert_eq!(
+        requests[0].header("content-type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(
+        requests[0].form(),
+        pairs(&[
+            (
+                "client_assertion",
+                "eyJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl+/="
+            ),
+            (
+                "client_assertion_type",
+                "urn:ietf:params:oauth:client-assertion-type:jwt-bearer"
+            ),
+            ("grant_type", "client_credentials"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn issue_channel_token_by_jwt_surfaces_an_invalid_assertion() {
+    let (base_path, server) = common::serve(vec![(
+        400,
+        r#"{"error":"invalid_client","error_description":"Invalid signature"}"#,
+    )])
+    .await;
+
+    let err = issue_channel_token_by_jwt(
+        &configuration(base_path),
+        CLIENT_CREDENTIALS_GRANT_TYPE,
+        JWT_BEARER_ASSERTION_TYPE,
+        "not-a-jwt",
+    )
+    .await
+    .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => {
+            assert_eq!(content.status, 400);
+            assert!(content.content.contains("invalid_client"));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn revoke_channel_token_by_jwt_posts_a_form() {
+    let (base_path, server) = common::serve(vec![(200, "")]).await;
+
+    revoke_channel_token_by_jwt(
+        &configuration(base_path),
+        "1234567890",
+        "channel secret",
+        "eyJhbGciOiJIUz.....",
+    )
+    .await
+    .unwrap();
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/oauth2/v2.1/revoke");
+    assert_eq!(
+        requests[0].header("content-type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(
+        requests[0].form(),
+        pairs(&[
+            ("access_token", "eyJhbGciOiJIUz....."),
+            ("client_id", "1234567890"),
+            ("client_secret", "channel secret"),
+        ])
+    );
+}
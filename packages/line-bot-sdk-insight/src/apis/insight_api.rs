@@ -241,7 +241,54 @@ pub async fn get_number_of_message_deliveries(
     }
 }
 
+/// Longest period, in days after `from`, that [`get_statistics_per_unit`] accepts.
+pub const STATISTICS_PER_UNIT_MAX_DAYS: u32 = 30;
+
 /// You can check the per-unit statistics of how users interact with push messages and multicast messages sent from your LINE Official Account.
+///
+/// `from` and `to` are `yyyyMMdd` dates in UTC+9, with `to` at most
+/// [`STATISTICS_PER_UNIT_MAX_DAYS`] days after `from`; other values are
+/// rejected with [`Error::Validation`] before any request is sent.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_insight::apis::configuration::Configuration;
+/// use line_bot_sdk_insight::apis::insight_api::get_statistics_per_unit;
+/// use line_bot_sdk_insight::apis::Error;
+/// use line_bot_sdk_insight::models::{GetStatisticsPerUnitResponse, ValidationError};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let response: GetStatisticsPerUnitResponse = serde_json::from_str(r#"{
+///     "overview": {"uniqueImpression": 40, "uniqueClick": 30, "uniqueMediaPlayed": 25,
+///                  "uniqueMediaPlayed100Percent": null},
+///     "messages": [{"seq": 1, "impression": 42, "mediaPlayed": 30, "mediaPlayed25Percent": null,
+///                   "mediaPlayed50Percent": null, "mediaPlayed75Percent": null,
+///                   "mediaPlayed100Percent": null, "uniqueMediaPlayed": 25,
+///                   "uniqueMediaPlayed25Percent": null, "uniqueMediaPlayed50Percent": null,
+///                   "uniqueMediaPlayed75Percent": null, "uniqueMediaPlayed100Percent": null}],
+///     "clicks": [{"seq": 1, "url": "https://developers.line.biz/", "click": 35,
+///                 "uniqueClick": 25, "uniqueClickOfRequest": null}]
+/// }"#).unwrap();
+/// assert_eq!(response.overview.unique_impression, Some(Some(40)));
+/// assert_eq!(response.messages[0].media_played25_percent, Some(None));
+/// assert_eq!(response.clicks[0].url, "https://developers.line.biz/");
+///
+/// let configuration = Configuration::new();
+/// let result = get_statistics_per_unit(&configuration, "promotion_a", "2021-03-01", "20210331").await;
+/// assert!(matches!(
+///     result,
+///     Err(Error::Validation(ValidationError::InvalidDate(date))) if date == "2021-03-01"
+/// ));
+///
+/// let result = get_statistics_per_unit(&configuration, "promotion_a", "20210301", "20210401").await;
+/// assert!(matches!(
+///     result,
+///     Err(Error::Validation(ValidationError::InvalidDateRange { .. }))
+/// ));
+/// # }
+/// ```
 pub async fn get_statistics_per_unit(
     configuration: &configuration::Configuration,
     custom_aggregation_unit: &str,
@@ -252,6 +299,11 @@ pub async fn get_statistics_per_unit(
     let p_query_custom_aggregation_unit = custom_aggregation_unit;
     let p_query_from = from;
     let p_query_to = to;
+    models::validation_error::validate_date_range(
+        p_query_from,
+        p_query_to,
+        STATISTICS_PER_UNIT_MAX_DAYS,
+    )?;
 
     let uri_str = format!(
         "{}/v2/bot/insight/message/event/aggregation",
@@ -14,6 +14,8 @@ pub enum Error<T> {
     Serde(serde_json::Error),
     Io(std::io::Error),
     ResponseError(ResponseContent<T>),
+    /// The request was rejected locally, before being sent.
+    Validation(crate::models::ValidationError),
 }
 
 impl<T> fmt::Display for Error<T> {
@@ -23,6 +25,7 @@ impl<T> fmt::Display for Error<T> {
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
             Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::Validation(e) => ("validation", e.to_string()),
         };
         write!(f, "error in {}: {}", module, e)
     }
@@ -35,6 +38,7 @@ impl<T: fmt::Debug> error::Error for Error<T> {
             Error::Serde(e) => e,
             Error::Io(e) => e,
             Error::ResponseError(_) => return None,
+            Error::Validation(e) => e,
         })
     }
 }
@@ -57,6 +61,12 @@ impl<T> From<std::io::Error> for Error<T> {
     }
 }
 
+impl<T> From<crate::models::ValidationError> for Error<T> {
+    fn from(e: crate::models::ValidationError) -> Self {
+        Error::Validation(e)
+    }
+}
+
 pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
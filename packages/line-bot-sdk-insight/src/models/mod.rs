@@ -34,3 +34,5 @@ pub mod get_statistics_per_unit_response_overview;
 pub use self::get_statistics_per_unit_response_overview::GetStatisticsPerUnitResponseOverview;
 pub mod subscription_period_tile;
 pub use self::subscription_period_tile::SubscriptionPeriodTile;
+pub mod validation_error;
+pub use self::validation_error::ValidationError;
@@ -0,0 +1,89 @@
+//! Client-side checks for the request limits documented by the LINE Insight API.
+//!
+//! Requests that break these limits are rejected before any HTTP call is made.
+
+use std::fmt;
+
+/// A request that the LINE Platform would reject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A date parameter isn't a valid `yyyyMMdd` date.
+    InvalidDate(String),
+    /// `to` is before `from`, or more than `max_days` days after it.
+    InvalidDateRange {
+        from: String,
+        to: String,
+        max_days: u32,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidDate(date) => {
+                write!(f, "date `{}` must be in yyyyMMdd format", date)
+            }
+            ValidationError::InvalidDateRange { from, to, max_days } => write!(
+                f,
+                "`to` ({}) must be between `from` ({}) and {} days after it",
+                to, from, max_days
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks that `date` is a `yyyyMMdd` date and returns it as a day number.
+pub(crate) fn validate_date(date: &str) -> Result<i64, ValidationError> {
+    let invalid = || ValidationError::InvalidDate(date.to_string());
+    if date.len() != 8 || !date.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let year: i64 = date[0..4].parse().map_err(|_| invalid())?;
+    let month: u32 = date[4..6].parse().map_err(|_| invalid())?;
+    let day: u32 = date[6..8].parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+    Ok(days_from_civil(year, month, day))
+}
+
+/// Checks that `from` and `to` are `yyyyMMdd` dates with `to` at most
+/// `max_days` days after `from`.
+pub(crate) fn validate_date_range(
+    from: &str,
+    to: &str,
+    max_days: u32,
+) -> Result<(), ValidationError> {
+    let days = validate_date(to)? - validate_date(from)?;
+    if !(0..=i64::from(max_days)).contains(&days) {
+        return Err(ValidationError::InvalidDateRange {
+            from: from.to_string(),
+            to: to.to_string(),
+            max_days,
+        });
+    }
+    Ok(())
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Count years from March so the leap day falls at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
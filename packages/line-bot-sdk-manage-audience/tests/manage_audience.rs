@@ -0,0 +1,120 @@
+mod common;
+
+use line_bot_sdk_manage_audience::apis::configuration::Configuration;
+use line_bot_sdk_manage_audience::apis::manage_audience_api::{
+    create_audience_group, get_audience_groups,
+};
+use line_bot_sdk_manage_audience::models::{
+    Audience, AudienceGroupCreateRoute, AudienceGroupStatus, CreateAudienceGroupRequest,
+};
+use serde_json::json;
+
+fn configuration(base_path: String) -> Configuration {
+    Configuration {
+        base_path,
+        bearer_access_token: Some("YOUR_CHANNEL_ACCESS_TOKEN".to_string()),
+        ..Configuration::default()
+    }
+}
+
+#[tokio::test]
+async fn create_audience_group_posts_the_user_ids() {
+    let (base_path, server) = common::serve(vec![(
+        202,
+        r#"{
+            "audienceGroupId": 4389303728991,
+            "createRoute": "MESSAGING_API",
+            "type": "UPLOAD",
+            "description": "audienceGroupName_01",
+            "created": 1613698278,
+            "permission": "READ_WRITE",
+            "expireTimestamp": 1629250278,
+            "isIfaAudience": false
+        }"#,
+    )])
+    .await;
+    let request = CreateAudienceGroupRequest {
+        description: Some("audienceGroupName_01".to_string()),
+        is_ifa_audience: Some(false),
+        upload_description: Some("uploadDescription".to_string()),
+        audiences: Some(vec![Audience {
+            id: Some("U4af4980629...".to_string()),
+        }]),
+    };
+
+    let response = create_audience_group(&configuration(base_path), request)
+        .await
+        .unwrap();
+
+    assert_eq!(response.audience_group_id, Some(4389303728991));
+    assert_eq!(
+        response.description.as_deref(),
+        Some("audienceGroupName_01")
+    );
+    let requests = server.await.unwrap();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].target, "/v2/bot/audienceGroup/upload");
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer YOUR_CHANNEL_ACCESS_TOKEN")
+    );
+    assert_eq!(
+        requests[0].json(),
+        json!({
+            "description": "audienceGroupName_01",
+            "isIfaAudience": false,
+            "uploadDescription": "uploadDescription",
+            "audiences": [{"id": "U4af4980629..."}]
+        })
+    );
+}
+
+#[tokio::test]
+async fn get_audience_groups_sends_the_pagination_parameters() {
+    let (base_path, server) = common::serve(vec![(
+        200,
+        r#"{
+            "audienceGroups": [{
+                "audienceGroupId": 4389303728991,
+                "type": "UPLOAD",
+                "description": "audienceGroupName_01",
+                "status": "READY",
+                "audienceCount": 1,
+                "created": 1613698278,
+                "permission": "READ_WRITE",
+                "createRoute": "MESSAGING_API",
+                "isIfaAudience": false
+            }],
+            "hasNextPage": true,
+            "totalCount": 41,
+            "readWriteAudienceGroupTotalCount": 41,
+            "page": 2,
+            "size": 20
+        }"#,
+    )])
+    .await;
+
+    let response = get_audience_groups(
+        &configuration(base_path),
+        2,
+        None,
+        Some(AudienceGroupStatus::Ready),
+        Some(20),
+        None,
+        Some(AudienceGroupCreateRoute::MessagingApi),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.has_next_page, Some(true));
+    assert_eq!(response.page, Some(2));
+    assert_eq!(response.size, Some(20));
+    let groups = response.audience_groups.unwrap();
+    assert_eq!(groups[0].status, Some(AudienceGroupStatus::Ready));
+    let requests = server.await.unwrap();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(
+        requests[0].target,
+        "/v2/bot/audienceGroup/list?page=2&status=READY&size=20&createRoute=MESSAGING_API"
+    );
+}
@@ -8,15 +8,66 @@
  * Generated by: https://openapi-generator.tech
  */
 
-#[derive(Debug, Clone)]
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::transport::HttpTransport;
+use super::ResponseMeta;
+
+/// Callback invoked with the outcome of each API call. See [`Configuration::on_response`].
+pub type ResponseHook = Arc<dyn Fn(&ResponseMeta) + Send + Sync>;
+
+#[derive(Clone)]
 pub struct Configuration {
     pub base_path: String,
+    /// Base path for the endpoints served from `api-data.line.me`, such as
+    /// message content and rich menu images.
+    pub data_base_path: String,
     pub user_agent: Option<String>,
+    /// Client used to send requests when `transport` isn't set.
+    ///
+    /// With the `gzip` feature, enabled by default, the default client asks
+    /// for gzip or deflate encoded responses and decompresses them; a client
+    /// of your own needs `reqwest`'s `gzip` and `deflate` features for that.
     pub client: reqwest::Client,
     pub basic_auth: Option<BasicAuth>,
     pub oauth_access_token: Option<String>,
     pub bearer_access_token: Option<String>,
     pub api_key: Option<ApiKey>,
+    /// Retries failed requests when set. See [`RetryPolicy`].
+    pub retry_policy: Option<RetryPolicy>,
+    /// Timeout applied to each request, overriding any timeout set on `client`.
+    ///
+    /// The timeout covers the whole request, including reading the response body,
+    /// and applies to each attempt separately when `retry_policy` is set.
+    pub timeout: Option<Duration>,
+    /// Sends the requests instead of `client` when set. See [`HttpTransport`].
+    pub transport: Option<Arc<dyn HttpTransport>>,
+    /// Called once per API call that gets a response, after any retries,
+    /// e.g. to record metrics or tracing spans.
+    ///
+    /// Not called when no response was received at all.
+    pub on_response: Option<ResponseHook>,
+}
+
+impl fmt::Debug for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Configuration")
+            .field("base_path", &self.base_path)
+            .field("data_base_path", &self.data_base_path)
+            .field("user_agent", &self.user_agent)
+            .field("client", &self.client)
+            .field("basic_auth", &self.basic_auth)
+            .field("oauth_access_token", &self.oauth_access_token)
+            .field("bearer_access_token", &self.bearer_access_token)
+            .field("api_key", &self.api_key)
+            .field("retry_policy", &self.retry_policy)
+            .field("timeout", &self.timeout)
+            .field("transport", &self.transport)
+            .field("on_response", &self.on_response.as_ref().map(|_| "Fn"))
+            .finish()
+    }
 }
 
 pub type BasicAuth = (String, Option<String>);
@@ -27,22 +78,214 @@ pub struct ApiKey {
     pub key: String,
 }
 
+const DEFAULT_BASE_PATH: &str = "https://api.line.me";
+const DEFAULT_DATA_BASE_PATH: &str = "https://api-data.line.me";
+const CHANNEL_ACCESS_TOKEN_ENV: &str = "CHANNEL_ACCESS_TOKEN";
+
 impl Configuration {
     pub fn new() -> Configuration {
         Configuration::default()
     }
+
+    /// Default configuration with a client tuned for bots sending many
+    /// requests concurrently.
+    ///
+    /// The client keeps up to 32 idle connections per host for 90 seconds
+    /// and sends TCP keepalives every 60 seconds, so bursts reuse warm
+    /// connections instead of paying for new TLS handshakes. The tradeoff is
+    /// more open sockets and memory while the bot is idle. With the `http2`
+    /// feature, HTTP/2 connections are kept alive with pings as well; HTTP/2
+    /// is only negotiated by TLS backends supporting ALPN, such as `rustls-tls`.
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+    ///
+    /// let configuration = Configuration {
+    ///     bearer_access_token: Some("YOUR_CHANNEL_ACCESS_TOKEN".to_string()),
+    ///     ..Configuration::high_throughput()
+    /// };
+    /// ```
+    pub fn high_throughput() -> Configuration {
+        let builder = reqwest::Client::builder()
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(Duration::from_secs(90))
+            .tcp_keepalive(Duration::from_secs(60))
+            .tcp_nodelay(true);
+        #[cfg(feature = "http2")]
+        let builder = builder
+            .http2_adaptive_window(true)
+            .http2_keep_alive_interval(Duration::from_secs(30))
+            .http2_keep_alive_while_idle(true);
+        let client = builder.build().expect("TLS backend cannot be initialized");
+        Configuration::builder().client(client).build()
+    }
+
+    /// Starts a [`ConfigurationBuilder`] with the default settings.
+    pub fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder::default()
+    }
+
+    /// Default configuration authenticated with the `CHANNEL_ACCESS_TOKEN`
+    /// environment variable.
+    ///
+    /// The channel secret isn't part of the API configuration; read
+    /// `CHANNEL_SECRET` separately to verify webhook signatures.
+    pub fn from_env() -> Result<Configuration, std::env::VarError> {
+        let token = std::env::var(CHANNEL_ACCESS_TOKEN_ENV)?;
+        Ok(Configuration::builder().channel_access_token(token).build())
+    }
+
+    /// The transport requests are sent through: `transport` if set, otherwise `client`.
+    pub fn transport(&self) -> &dyn HttpTransport {
+        match self.transport {
+            Some(ref transport) => transport.as_ref(),
+            None => &self.client,
+        }
+    }
 }
 
 impl Default for Configuration {
     fn default() -> Self {
         Configuration {
-            base_path: "https://api.line.me".to_owned(),
+            base_path: DEFAULT_BASE_PATH.to_owned(),
+            data_base_path: DEFAULT_DATA_BASE_PATH.to_owned(),
             user_agent: Some("OpenAPI-Generator/0.0.1/rust".to_owned()),
             client: reqwest::Client::new(),
             basic_auth: None,
             oauth_access_token: None,
             bearer_access_token: None,
             api_key: None,
+            retry_policy: None,
+            timeout: None,
+            transport: None,
+            on_response: None,
+        }
+    }
+}
+
+/// Builds a [`Configuration`], starting from [`Configuration::default`].
+///
+/// ```
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+///
+/// let configuration = Configuration::builder()
+///     .channel_access_token("YOUR_CHANNEL_ACCESS_TOKEN")
+///     .build();
+/// assert_eq!(
+///     configuration.bearer_access_token.as_deref(),
+///     Some("YOUR_CHANNEL_ACCESS_TOKEN")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigurationBuilder {
+    configuration: Configuration,
+}
+
+impl ConfigurationBuilder {
+    /// Channel access token sent as the bearer token of every request.
+    pub fn channel_access_token(mut self, token: impl Into<String>) -> Self {
+        self.configuration.bearer_access_token = Some(token.into());
+        self
+    }
+
+    pub fn base_path(mut self, base_path: impl Into<String>) -> Self {
+        self.configuration.base_path = base_path.into();
+        self
+    }
+
+    /// Base path for the content endpoints, which don't follow `base_path`.
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+    ///
+    /// let configuration = Configuration::builder()
+    ///     .base_path("http://localhost:8080")
+    ///     .data_base_path("http://localhost:8081")
+    ///     .build();
+    /// ```
+    pub fn data_base_path(mut self, data_base_path: impl Into<String>) -> Self {
+        self.configuration.data_base_path = data_base_path.into();
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.configuration.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.configuration.client = client;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.configuration.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.configuration.timeout = Some(timeout);
+        self
+    }
+
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.configuration.transport = Some(transport);
+        self
+    }
+
+    pub fn on_response(mut self, hook: impl Fn(&ResponseMeta) + Send + Sync + 'static) -> Self {
+        self.configuration.on_response = Some(Arc::new(hook));
+        self
+    }
+
+    pub fn build(self) -> Configuration {
+        self.configuration
+    }
+}
+
+/// Retry behaviour for requests that fail with a retryable status code, or
+/// that fail to connect or time out.
+///
+/// Only idempotent requests are retried: `GET`, `PUT`, `DELETE`, and requests
+/// carrying an `X-Line-Retry-Key` header. The endpoints that accept a retry
+/// key generate one when none is passed, so every retry of a message send
+/// reuses the same key. A `Retry-After` header given in seconds takes
+/// precedence over the exponential backoff, capped at `max_delay`; the
+/// HTTP-date form is ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each following retry.
+    pub base_delay: Duration,
+    /// Upper bound for the delay before a retry, including one asked for by
+    /// `Retry-After`.
+    pub max_delay: Duration,
+    /// Status codes that trigger a retry.
+    pub retry_on: Vec<reqwest::StatusCode>,
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (starting at 0), ignoring `Retry-After`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_on: vec![
+                reqwest::StatusCode::TOO_MANY_REQUESTS,
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                reqwest::StatusCode::BAD_GATEWAY,
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            ],
         }
     }
 }
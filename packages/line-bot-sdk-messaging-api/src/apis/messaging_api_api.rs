@@ -8,8 +8,9 @@
  * Generated by: https://openapi-generator.tech
  */
 
-use super::{configuration, ContentType, Error};
+use super::{configuration, ContentType, Error, RateLimit, ResponseWithHttpInfo};
 use crate::{apis::ResponseContent, models};
+use line_bot_sdk_utils::retry_key::RetryKey;
 use reqwest;
 use serde::{de::Error as _, Deserialize, Serialize};
 
@@ -120,6 +121,10 @@ pub enum GetDefaultRichMenuIdError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum GetFollowersError {
+    /// The channel isn't a verified or premium account, which this endpoint
+    /// requires (`403 Forbidden`).
+    #[serde(skip)]
+    Forbidden,
     UnknownValue(serde_json::Value),
 }
 
@@ -195,6 +200,9 @@ pub enum GetMessageQuotaConsumptionError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum GetNarrowcastProgressError {
+    /// No narrowcast was found for the request id (`404 Not Found`).
+    #[serde(skip)]
+    RequestNotFound,
     UnknownValue(serde_json::Value),
 }
 
@@ -482,6 +490,7 @@ pub enum UpdateRichMenuAliasError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ValidateBroadcastError {
+    Status400(models::MessageValidationError),
     UnknownValue(serde_json::Value),
 }
 
@@ -489,6 +498,7 @@ pub enum ValidateBroadcastError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ValidateMulticastError {
+    Status400(models::MessageValidationError),
     UnknownValue(serde_json::Value),
 }
 
@@ -496,6 +506,7 @@ pub enum ValidateMulticastError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ValidateNarrowcastError {
+    Status400(models::MessageValidationError),
     UnknownValue(serde_json::Value),
 }
 
@@ -503,6 +514,7 @@ pub enum ValidateNarrowcastError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ValidatePushError {
+    Status400(models::MessageValidationError),
     UnknownValue(serde_json::Value),
 }
 
@@ -510,6 +522,7 @@ pub enum ValidatePushError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ValidateReplyError {
+    Status400(models::MessageValidationError),
     UnknownValue(serde_json::Value),
 }
 
@@ -528,11 +541,25 @@ pub enum ValidateRichMenuObjectError {
 }
 
 /// Sends a message to multiple users at any time.
+///
+/// The response body is empty; use [`broadcast_with_http_info`] to read the
+/// `X-Line-Request-Id` of the accepted request.
 pub async fn broadcast(
     configuration: &configuration::Configuration,
     broadcast_request: models::BroadcastRequest,
     x_line_retry_key: Option<&str>,
 ) -> Result<serde_json::Value, Error<BroadcastError>> {
+    broadcast_with_http_info(configuration, broadcast_request, x_line_retry_key)
+        .await
+        .map(|response| response.entity)
+}
+
+/// Same as [`broadcast`], but also returns the HTTP status, headers, and rate limit of the response.
+pub async fn broadcast_with_http_info(
+    configuration: &configuration::Configuration,
+    broadcast_request: models::BroadcastRequest,
+    x_line_retry_key: Option<&str>,
+) -> Result<ResponseWithHttpInfo<serde_json::Value>, Error<BroadcastError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_broadcast_request = broadcast_request;
     let p_header_x_line_retry_key = x_line_retry_key;
@@ -547,6 +574,9 @@ pub async fn broadcast(
     }
     if let Some(param_value) = p_header_x_line_retry_key {
         req_builder = req_builder.header("X-Line-Retry-Key", param_value.to_string());
+    } else if configuration.retry_policy.is_some() {
+        // A retry key makes the request safe for `execute` to retry.
+        req_builder = req_builder.header("X-Line-Retry-Key", RetryKey::new().as_str());
     }
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
@@ -554,9 +584,10 @@ pub async fn broadcast(
     req_builder = req_builder.json(&p_body_broadcast_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
+    let headers = resp.headers().clone();
     let content_type = resp
         .headers()
         .get("content-type")
@@ -566,17 +597,26 @@ pub async fn broadcast(
 
     if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+        let entity = match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::<BroadcastError>::from)?,
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
-        }
+        };
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity,
+            deduplicated: false,
+        })
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<BroadcastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -599,18 +639,20 @@ pub async fn cancel_default_rich_menu(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<CancelDefaultRichMenuError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -639,18 +681,20 @@ pub async fn close_coupon(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<CloseCouponError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -678,7 +722,7 @@ pub async fn create_coupon(
     req_builder = req_builder.json(&p_body_coupon_create_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -696,11 +740,13 @@ pub async fn create_coupon(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CouponCreateResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<CreateCouponError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -728,7 +774,7 @@ pub async fn create_rich_menu(
     req_builder = req_builder.json(&p_body_rich_menu_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -746,11 +792,13 @@ pub async fn create_rich_menu(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuIdResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<CreateRichMenuError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -778,18 +826,20 @@ pub async fn create_rich_menu_alias(
     req_builder = req_builder.json(&p_body_create_rich_menu_alias_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<CreateRichMenuAliasError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -820,18 +870,20 @@ pub async fn delete_rich_menu(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<DeleteRichMenuError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -862,18 +914,20 @@ pub async fn delete_rich_menu_alias(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<DeleteRichMenuAliasError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -909,7 +963,7 @@ pub async fn get_aggregation_unit_name_list(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -927,16 +981,49 @@ pub async fn get_aggregation_unit_name_list(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetAggregationUnitNameListResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetAggregationUnitNameListError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Streams the names of the aggregation units used this month, following the
+/// `next` continuation token of [`get_aggregation_unit_name_list`] until every
+/// page is read.
+///
+/// `limit` is the page size, not a cap on the number of names yielded.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::TryStreamExt as _;
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::messaging_api_api::get_aggregation_unit_name_list_stream;
+///
+/// # async fn example(configuration: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+/// let names: Vec<String> = get_aggregation_unit_name_list_stream(configuration, Some("100"))
+///     .try_collect()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn get_aggregation_unit_name_list_stream<'a>(
+    configuration: &'a configuration::Configuration,
+    limit: Option<&'a str>,
+) -> impl futures_util::Stream<Item = Result<String, Error<GetAggregationUnitNameListError>>> + 'a {
+    super::paginate(move |start| async move {
+        let response =
+            get_aggregation_unit_name_list(configuration, limit, start.as_deref()).await?;
+        Ok((response.custom_aggregation_units, response.next))
+    })
+}
+
 /// Get number of units used this month
 pub async fn get_aggregation_unit_usage(
     configuration: &configuration::Configuration,
@@ -955,7 +1042,7 @@ pub async fn get_aggregation_unit_usage(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -973,11 +1060,13 @@ pub async fn get_aggregation_unit_usage(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetAggregationUnitUsageResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetAggregationUnitUsageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -998,7 +1087,7 @@ pub async fn get_bot_info(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1016,11 +1105,13 @@ pub async fn get_bot_info(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BotInfoResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetBotInfoError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1049,7 +1140,7 @@ pub async fn get_coupon_detail(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1067,11 +1158,13 @@ pub async fn get_coupon_detail(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::CouponResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetCouponDetailError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1092,7 +1185,7 @@ pub async fn get_default_rich_menu_id(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1110,17 +1203,22 @@ pub async fn get_default_rich_menu_id(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuIdResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetDefaultRichMenuIdError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Get a list of users who added your LINE Official Account as a friend
+///
+/// Only available to verified and premium accounts; other accounts get
+/// [`GetFollowersError::Forbidden`]. Use [`get_followers_stream`] to read every page.
 pub async fn get_followers(
     configuration: &configuration::Configuration,
     start: Option<&str>,
@@ -1147,7 +1245,7 @@ pub async fn get_followers(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1165,16 +1263,35 @@ pub async fn get_followers(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetFollowersResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
-        let entity: Option<GetFollowersError> = serde_json::from_str(&content).ok();
+        let entity = match status {
+            reqwest::StatusCode::FORBIDDEN => Some(GetFollowersError::Forbidden),
+            _ => serde_json::from_str(&content).ok(),
+        };
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Streams the user IDs of the friends of your LINE Official Account, following
+/// the `next` continuation token of [`get_followers`] until every page is read.
+///
+/// `limit` is the page size, not a cap on the number of IDs yielded.
+pub fn get_followers_stream(
+    configuration: &configuration::Configuration,
+    limit: Option<i32>,
+) -> impl futures_util::Stream<Item = Result<String, Error<GetFollowersError>>> + '_ {
+    super::paginate(move |start| async move {
+        let response = get_followers(configuration, start.as_deref(), limit).await?;
+        Ok((response.user_ids, response.next))
+    })
+}
+
 /// Get number of users in a group chat
 pub async fn get_group_member_count(
     configuration: &configuration::Configuration,
@@ -1198,7 +1315,7 @@ pub async fn get_group_member_count(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1216,11 +1333,13 @@ pub async fn get_group_member_count(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GroupMemberCountResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetGroupMemberCountError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1252,7 +1371,7 @@ pub async fn get_group_member_profile(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1270,11 +1389,13 @@ pub async fn get_group_member_profile(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GroupUserProfileResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetGroupMemberProfileError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1308,7 +1429,7 @@ pub async fn get_group_members_ids(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1326,16 +1447,30 @@ pub async fn get_group_members_ids(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::MembersIdsResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetGroupMembersIdsError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Streams the user IDs of the members of a group chat, following the `next`
+/// continuation token of [`get_group_members_ids`] until every page is read.
+pub fn get_group_members_ids_stream<'a>(
+    configuration: &'a configuration::Configuration,
+    group_id: &'a str,
+) -> impl futures_util::Stream<Item = Result<String, Error<GetGroupMembersIdsError>>> + 'a {
+    super::paginate(move |start| async move {
+        let response = get_group_members_ids(configuration, group_id, start.as_deref()).await?;
+        Ok((response.member_ids, response.next))
+    })
+}
+
 /// Get group chat summary
 pub async fn get_group_summary(
     configuration: &configuration::Configuration,
@@ -1359,7 +1494,7 @@ pub async fn get_group_summary(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1377,11 +1512,13 @@ pub async fn get_group_summary(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GroupSummaryResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetGroupSummaryError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1420,7 +1557,7 @@ pub async fn get_joined_membership_users(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1438,11 +1575,13 @@ pub async fn get_joined_membership_users(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetJoinedMembershipUsersResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetJoinedMembershipUsersError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1463,7 +1602,7 @@ pub async fn get_membership_list(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1481,11 +1620,13 @@ pub async fn get_membership_list(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::MembershipListResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMembershipListError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1514,7 +1655,7 @@ pub async fn get_membership_subscription(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1532,11 +1673,13 @@ pub async fn get_membership_subscription(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetMembershipSubscriptionResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMembershipSubscriptionError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1557,7 +1700,7 @@ pub async fn get_message_quota(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1575,11 +1718,13 @@ pub async fn get_message_quota(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::MessageQuotaResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMessageQuotaError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1603,7 +1748,7 @@ pub async fn get_message_quota_consumption(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1621,17 +1766,22 @@ pub async fn get_message_quota_consumption(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::QuotaConsumptionResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMessageQuotaConsumptionError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Gets the status of a narrowcast message.
+///
+/// `request_id` is the `X-Line-Request-Id` returned by [`narrowcast_with_http_info`].
+/// An unknown request id yields [`GetNarrowcastProgressError::RequestNotFound`].
 pub async fn get_narrowcast_progress(
     configuration: &configuration::Configuration,
     request_id: &str,
@@ -1654,7 +1804,7 @@ pub async fn get_narrowcast_progress(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1672,11 +1822,16 @@ pub async fn get_narrowcast_progress(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NarrowcastProgressResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
-        let entity: Option<GetNarrowcastProgressError> = serde_json::from_str(&content).ok();
+        let entity = match status {
+            reqwest::StatusCode::NOT_FOUND => Some(GetNarrowcastProgressError::RequestNotFound),
+            _ => serde_json::from_str(&content).ok(),
+        };
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1689,6 +1844,7 @@ pub async fn get_number_of_sent_broadcast_messages(
 ) -> Result<models::NumberOfMessagesResponse, Error<GetNumberOfSentBroadcastMessagesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_date = date;
+    models::validation_error::validate_date(p_query_date)?;
 
     let uri_str = format!(
         "{}/v2/bot/message/delivery/broadcast",
@@ -1705,7 +1861,7 @@ pub async fn get_number_of_sent_broadcast_messages(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1723,12 +1879,14 @@ pub async fn get_number_of_sent_broadcast_messages(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NumberOfMessagesResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetNumberOfSentBroadcastMessagesError> =
             serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1741,6 +1899,7 @@ pub async fn get_number_of_sent_multicast_messages(
 ) -> Result<models::NumberOfMessagesResponse, Error<GetNumberOfSentMulticastMessagesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_date = date;
+    models::validation_error::validate_date(p_query_date)?;
 
     let uri_str = format!(
         "{}/v2/bot/message/delivery/multicast",
@@ -1757,7 +1916,7 @@ pub async fn get_number_of_sent_multicast_messages(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1775,12 +1934,14 @@ pub async fn get_number_of_sent_multicast_messages(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NumberOfMessagesResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetNumberOfSentMulticastMessagesError> =
             serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1793,6 +1954,7 @@ pub async fn get_number_of_sent_push_messages(
 ) -> Result<models::NumberOfMessagesResponse, Error<GetNumberOfSentPushMessagesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_date = date;
+    models::validation_error::validate_date(p_query_date)?;
 
     let uri_str = format!("{}/v2/bot/message/delivery/push", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -1806,7 +1968,7 @@ pub async fn get_number_of_sent_push_messages(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1824,11 +1986,13 @@ pub async fn get_number_of_sent_push_messages(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NumberOfMessagesResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetNumberOfSentPushMessagesError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1841,6 +2005,7 @@ pub async fn get_number_of_sent_reply_messages(
 ) -> Result<models::NumberOfMessagesResponse, Error<GetNumberOfSentReplyMessagesError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_date = date;
+    models::validation_error::validate_date(p_query_date)?;
 
     let uri_str = format!("{}/v2/bot/message/delivery/reply", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -1854,7 +2019,7 @@ pub async fn get_number_of_sent_reply_messages(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1872,23 +2037,29 @@ pub async fn get_number_of_sent_reply_messages(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NumberOfMessagesResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetNumberOfSentReplyMessagesError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Get number of sent LINE notification messages
+///
+/// Like the other delivery statistics, `date` is a `yyyyMMdd` date (UTC+9)
+/// and is validated before the request is sent.
 pub async fn get_pnp_message_statistics(
     configuration: &configuration::Configuration,
     date: &str,
 ) -> Result<models::NumberOfMessagesResponse, Error<GetPnpMessageStatisticsError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_date = date;
+    models::validation_error::validate_date(p_query_date)?;
 
     let uri_str = format!("{}/v2/bot/message/delivery/pnp", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -1902,7 +2073,7 @@ pub async fn get_pnp_message_statistics(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1920,11 +2091,13 @@ pub async fn get_pnp_message_statistics(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NumberOfMessagesResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetPnpMessageStatisticsError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -1953,7 +2126,7 @@ pub async fn get_profile(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -1971,11 +2144,13 @@ pub async fn get_profile(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UserProfileResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetProfileError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2004,7 +2179,7 @@ pub async fn get_rich_menu(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2022,11 +2197,13 @@ pub async fn get_rich_menu(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2055,7 +2232,7 @@ pub async fn get_rich_menu_alias(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2073,11 +2250,13 @@ pub async fn get_rich_menu_alias(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuAliasResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuAliasError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2098,7 +2277,7 @@ pub async fn get_rich_menu_alias_list(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2116,11 +2295,13 @@ pub async fn get_rich_menu_alias_list(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuAliasListResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuAliasListError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2146,7 +2327,7 @@ pub async fn get_rich_menu_batch_progress(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2164,21 +2345,40 @@ pub async fn get_rich_menu_batch_progress(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuBatchProgressResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuBatchProgressError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Get rich menu ID of user
+///
+/// Returns `None` when no rich menu is linked to the user, which LINE
+/// reports as `404 Not Found`.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::messaging_api_api::get_rich_menu_id_of_user;
+///
+/// # async fn example(configuration: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+/// if let Some(linked) = get_rich_menu_id_of_user(configuration, "U1").await? {
+///     println!("{}", linked.rich_menu_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 pub async fn get_rich_menu_id_of_user(
     configuration: &configuration::Configuration,
     user_id: &str,
-) -> Result<models::RichMenuIdResponse, Error<GetRichMenuIdOfUserError>> {
+) -> Result<Option<models::RichMenuIdResponse>, Error<GetRichMenuIdOfUserError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_path_user_id = user_id;
 
@@ -2197,7 +2397,7 @@ pub async fn get_rich_menu_id_of_user(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2207,19 +2407,23 @@ pub async fn get_rich_menu_id_of_user(
         .unwrap_or("application/octet-stream");
     let content_type = super::ContentType::from(content_type);
 
-    if !status.is_client_error() && !status.is_server_error() {
+    if status == reqwest::StatusCode::NOT_FOUND {
+        Ok(None)
+    } else if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
         match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Json => serde_json::from_str(&content).map(Some).map_err(Error::from),
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::RichMenuIdResponse`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuIdResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuIdOfUserError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2240,7 +2444,7 @@ pub async fn get_rich_menu_list(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2258,11 +2462,13 @@ pub async fn get_rich_menu_list(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RichMenuListResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuListError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2291,7 +2497,7 @@ pub async fn get_room_member_count(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2309,11 +2515,13 @@ pub async fn get_room_member_count(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RoomMemberCountResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRoomMemberCountError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2345,7 +2553,7 @@ pub async fn get_room_member_profile(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2363,11 +2571,13 @@ pub async fn get_room_member_profile(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::RoomUserProfileResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRoomMemberProfileError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2401,7 +2611,7 @@ pub async fn get_room_members_ids(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2419,16 +2629,30 @@ pub async fn get_room_members_ids(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::MembersIdsResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRoomMembersIdsError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Streams the user IDs of the members of a multi-person chat, following the
+/// `next` continuation token of [`get_room_members_ids`] until every page is read.
+pub fn get_room_members_ids_stream<'a>(
+    configuration: &'a configuration::Configuration,
+    room_id: &'a str,
+) -> impl futures_util::Stream<Item = Result<String, Error<GetRoomMembersIdsError>>> + 'a {
+    super::paginate(move |start| async move {
+        let response = get_room_members_ids(configuration, room_id, start.as_deref()).await?;
+        Ok((response.member_ids, response.next))
+    })
+}
+
 /// Get webhook endpoint information
 pub async fn get_webhook_endpoint(
     configuration: &configuration::Configuration,
@@ -2447,7 +2671,7 @@ pub async fn get_webhook_endpoint(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2465,11 +2689,13 @@ pub async fn get_webhook_endpoint(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetWebhookEndpointResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetWebhookEndpointError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2500,7 +2726,7 @@ pub async fn issue_link_token(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2518,11 +2744,13 @@ pub async fn issue_link_token(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::IssueLinkTokenResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<IssueLinkTokenError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2553,18 +2781,20 @@ pub async fn leave_group(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<LeaveGroupError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2595,18 +2825,20 @@ pub async fn leave_room(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<LeaveRoomError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2640,18 +2872,20 @@ pub async fn link_rich_menu_id_to_user(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<LinkRichMenuIdToUserError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2664,6 +2898,7 @@ pub async fn link_rich_menu_id_to_users(
 ) -> Result<(), Error<LinkRichMenuIdToUsersError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_rich_menu_bulk_link_request = rich_menu_bulk_link_request;
+    p_body_rich_menu_bulk_link_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/richmenu/bulk/link", configuration.base_path);
     let mut req_builder = configuration
@@ -2679,18 +2914,20 @@ pub async fn link_rich_menu_id_to_users(
     req_builder = req_builder.json(&p_body_rich_menu_bulk_link_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<LinkRichMenuIdToUsersError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2744,7 +2981,7 @@ pub async fn list_coupon(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -2762,11 +2999,13 @@ pub async fn list_coupon(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::MessagingApiPagerCouponListResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ListCouponError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2794,18 +3033,20 @@ pub async fn mark_messages_as_read(
     req_builder = req_builder.json(&p_body_mark_messages_as_read_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<MarkMessagesAsReadError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2833,18 +3074,20 @@ pub async fn mark_messages_as_read_by_token(
     req_builder = req_builder.json(&p_body_mark_messages_as_read_by_token_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<MarkMessagesAsReadByTokenError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2856,9 +3099,25 @@ pub async fn multicast(
     multicast_request: models::MulticastRequest,
     x_line_retry_key: Option<&str>,
 ) -> Result<serde_json::Value, Error<MulticastError>> {
+    multicast_with_http_info(configuration, multicast_request, x_line_retry_key)
+        .await
+        .map(|response| response.entity)
+}
+
+/// Same as [`multicast`], but also returns the HTTP status, headers, and rate limit of the response.
+///
+/// A `409 Conflict` with an `X-Line-Accepted-Request-Id` header means the
+/// retry key was already used and the messages were sent by an earlier
+/// request; it is returned as `Ok` with [`ResponseWithHttpInfo::deduplicated`] set.
+pub async fn multicast_with_http_info(
+    configuration: &configuration::Configuration,
+    multicast_request: models::MulticastRequest,
+    x_line_retry_key: Option<&str>,
+) -> Result<ResponseWithHttpInfo<serde_json::Value>, Error<MulticastError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_multicast_request = multicast_request;
     let p_header_x_line_retry_key = x_line_retry_key;
+    p_body_multicast_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/message/multicast", configuration.base_path);
     let mut req_builder = configuration
@@ -2870,6 +3129,9 @@ pub async fn multicast(
     }
     if let Some(param_value) = p_header_x_line_retry_key {
         req_builder = req_builder.header("X-Line-Retry-Key", param_value.to_string());
+    } else if configuration.retry_policy.is_some() {
+        // A retry key makes the request safe for `execute` to retry.
+        req_builder = req_builder.header("X-Line-Retry-Key", RetryKey::new().as_str());
     }
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
@@ -2877,9 +3139,10 @@ pub async fn multicast(
     req_builder = req_builder.json(&p_body_multicast_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
+    let headers = resp.headers().clone();
     let content_type = resp
         .headers()
         .get("content-type")
@@ -2889,31 +3152,112 @@ pub async fn multicast(
 
     if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+        let entity = match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::<MulticastError>::from)?,
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
-        }
+        };
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity,
+            deduplicated: false,
+        })
+    } else if super::is_deduplicated(status, &headers) {
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity: Default::default(),
+            deduplicated: true,
+        })
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<MulticastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Outcome of one request sent by [`multicast_message_chunked`].
+#[derive(Debug)]
+pub struct MulticastChunkResult {
+    /// The user IDs this request was sent to.
+    pub to: Vec<String>,
+    /// The retry key sent with this request, for resending it without duplicates.
+    pub retry_key: RetryKey,
+    pub result: Result<ResponseWithHttpInfo<serde_json::Value>, Error<MulticastError>>,
+}
+
+/// Sends `messages` to any number of users, splitting `to` into requests of
+/// at most [`models::MulticastRequest::MAX_RECIPIENTS`] user IDs.
+///
+/// Each request gets its own retry key. Returns one result per request sent,
+/// in order. With `stop_on_error`, no further requests are sent after the
+/// first one fails. Fails without sending anything if `to` is empty or a
+/// message is invalid.
+pub async fn multicast_message_chunked(
+    configuration: &configuration::Configuration,
+    to: Vec<String>,
+    messages: Vec<models::Message>,
+    notification_disabled: Option<bool>,
+    stop_on_error: bool,
+) -> Result<Vec<MulticastChunkResult>, Error<MulticastError>> {
+    if to.is_empty() {
+        return Err(models::ValidationError::EmptyRecipients.into());
+    }
+    messages.iter().try_for_each(models::Message::validate)?;
+
+    let mut results = Vec::new();
+    for chunk in to.chunks(models::MulticastRequest::MAX_RECIPIENTS) {
+        let retry_key = RetryKey::new();
+        let mut request = models::MulticastRequest::new(messages.clone(), chunk.to_vec());
+        request.notification_disabled = notification_disabled;
+        let result =
+            multicast_with_http_info(configuration, request, Some(retry_key.as_str())).await;
+        let failed = result.is_err();
+        results.push(MulticastChunkResult {
+            to: chunk.to_vec(),
+            retry_key,
+            result,
+        });
+        if failed && stop_on_error {
+            break;
+        }
+    }
+    Ok(results)
+}
+
 /// Send narrowcast message
+///
+/// The response body is empty; use [`narrowcast_with_http_info`] to read the
+/// `X-Line-Request-Id` needed by [`get_narrowcast_progress`].
 pub async fn narrowcast(
     configuration: &configuration::Configuration,
     narrowcast_request: models::NarrowcastRequest,
     x_line_retry_key: Option<&str>,
 ) -> Result<serde_json::Value, Error<NarrowcastError>> {
+    narrowcast_with_http_info(configuration, narrowcast_request, x_line_retry_key)
+        .await
+        .map(|response| response.entity)
+}
+
+/// Same as [`narrowcast`], but also returns the HTTP status, headers, and rate limit of the response.
+pub async fn narrowcast_with_http_info(
+    configuration: &configuration::Configuration,
+    narrowcast_request: models::NarrowcastRequest,
+    x_line_retry_key: Option<&str>,
+) -> Result<ResponseWithHttpInfo<serde_json::Value>, Error<NarrowcastError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_narrowcast_request = narrowcast_request;
     let p_header_x_line_retry_key = x_line_retry_key;
+    p_body_narrowcast_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/message/narrowcast", configuration.base_path);
     let mut req_builder = configuration
@@ -2925,6 +3269,9 @@ pub async fn narrowcast(
     }
     if let Some(param_value) = p_header_x_line_retry_key {
         req_builder = req_builder.header("X-Line-Retry-Key", param_value.to_string());
+    } else if configuration.retry_policy.is_some() {
+        // A retry key makes the request safe for `execute` to retry.
+        req_builder = req_builder.header("X-Line-Retry-Key", RetryKey::new().as_str());
     }
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
@@ -2932,9 +3279,10 @@ pub async fn narrowcast(
     req_builder = req_builder.json(&p_body_narrowcast_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
+    let headers = resp.headers().clone();
     let content_type = resp
         .headers()
         .get("content-type")
@@ -2944,17 +3292,26 @@ pub async fn narrowcast(
 
     if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+        let entity = match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::<NarrowcastError>::from)?,
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `serde_json::Value`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
-        }
+        };
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity,
+            deduplicated: false,
+        })
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<NarrowcastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -2966,9 +3323,49 @@ pub async fn push_message(
     push_message_request: models::PushMessageRequest,
     x_line_retry_key: Option<&str>,
 ) -> Result<models::PushMessageResponse, Error<PushMessageError>> {
+    push_message_with_http_info(configuration, push_message_request, x_line_retry_key)
+        .await
+        .map(|response| response.entity)
+}
+
+/// Same as [`push_message`], but also returns the HTTP status, headers, and rate limit of the response.
+///
+/// A `409 Conflict` with an `X-Line-Accepted-Request-Id` header means the
+/// retry key was already used and the messages were sent by an earlier
+/// request; it is returned as `Ok` with [`ResponseWithHttpInfo::deduplicated`] set.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::messaging_api_api::push_message_with_http_info;
+/// use line_bot_sdk_messaging_api::models::PushMessageRequest;
+///
+/// # async fn example(
+/// #     configuration: &Configuration,
+/// #     request: PushMessageRequest,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let response = push_message_with_http_info(
+///     configuration,
+///     request,
+///     Some("123e4567-e89b-12d3-a456-426614174000"),
+/// )
+/// .await?;
+/// if response.deduplicated {
+///     println!("already sent as {:?}", response.accepted_request_id());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn push_message_with_http_info(
+    configuration: &configuration::Configuration,
+    push_message_request: models::PushMessageRequest,
+    x_line_retry_key: Option<&str>,
+) -> Result<ResponseWithHttpInfo<models::PushMessageResponse>, Error<PushMessageError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_push_message_request = push_message_request;
     let p_header_x_line_retry_key = x_line_retry_key;
+    p_body_push_message_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/message/push", configuration.base_path);
     let mut req_builder = configuration
@@ -2980,6 +3377,9 @@ pub async fn push_message(
     }
     if let Some(param_value) = p_header_x_line_retry_key {
         req_builder = req_builder.header("X-Line-Retry-Key", param_value.to_string());
+    } else if configuration.retry_policy.is_some() {
+        // A retry key makes the request safe for `execute` to retry.
+        req_builder = req_builder.header("X-Line-Retry-Key", RetryKey::new().as_str());
     }
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
@@ -2987,9 +3387,10 @@ pub async fn push_message(
     req_builder = req_builder.json(&p_body_push_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
+    let headers = resp.headers().clone();
     let content_type = resp
         .headers()
         .get("content-type")
@@ -2999,22 +3400,50 @@ pub async fn push_message(
 
     if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+        let entity = match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::<PushMessageError>::from)?,
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::PushMessageResponse`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::PushMessageResponse`")))),
-        }
+        };
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity,
+            deduplicated: false,
+        })
+    } else if super::is_deduplicated(status, &headers) {
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity: Default::default(),
+            deduplicated: true,
+        })
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<PushMessageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Pushes a single text message to `to`, a user, group chat or multi-person chat ID.
+pub async fn push_text(
+    configuration: &configuration::Configuration,
+    to: impl Into<String>,
+    text: impl Into<String>,
+) -> Result<models::PushMessageResponse, Error<PushMessageError>> {
+    let request =
+        models::PushMessageRequest::new(to.into(), vec![models::TextMessage::new(text).into()]);
+    push_message(configuration, request, None).await
+}
+
 /// Send LINE notification message
 pub async fn push_messages_by_phone(
     configuration: &configuration::Configuration,
@@ -3042,18 +3471,20 @@ pub async fn push_messages_by_phone(
     req_builder = req_builder.json(&p_body_pnp_messages_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<PushMessagesByPhoneError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3064,8 +3495,19 @@ pub async fn reply_message(
     configuration: &configuration::Configuration,
     reply_message_request: models::ReplyMessageRequest,
 ) -> Result<models::ReplyMessageResponse, Error<ReplyMessageError>> {
+    reply_message_with_http_info(configuration, reply_message_request)
+        .await
+        .map(|response| response.entity)
+}
+
+/// Same as [`reply_message`], but also returns the HTTP status, headers, and rate limit of the response.
+pub async fn reply_message_with_http_info(
+    configuration: &configuration::Configuration,
+    reply_message_request: models::ReplyMessageRequest,
+) -> Result<ResponseWithHttpInfo<models::ReplyMessageResponse>, Error<ReplyMessageError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_reply_message_request = reply_message_request;
+    p_body_reply_message_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/message/reply", configuration.base_path);
     let mut req_builder = configuration
@@ -3081,9 +3523,10 @@ pub async fn reply_message(
     req_builder = req_builder.json(&p_body_reply_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
+    let headers = resp.headers().clone();
     let content_type = resp
         .headers()
         .get("content-type")
@@ -3093,22 +3536,69 @@ pub async fn reply_message(
 
     if !status.is_client_error() && !status.is_server_error() {
         let content = resp.text().await?;
-        match content_type {
-            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+        let entity = match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::<ReplyMessageError>::from)?,
             ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ReplyMessageResponse`"))),
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ReplyMessageResponse`")))),
-        }
+        };
+        Ok(ResponseWithHttpInfo {
+            status,
+            rate_limit: RateLimit::from_headers(&headers),
+            headers,
+            entity,
+            deduplicated: false,
+        })
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ReplyMessageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Replies to `reply_token` with a single text message.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::messaging_api_api::reply_text;
+///
+/// # async fn example(configuration: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+/// reply_text(configuration, "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA", "Hello").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reply_text(
+    configuration: &configuration::Configuration,
+    reply_token: impl Into<String>,
+    text: impl Into<String>,
+) -> Result<models::ReplyMessageResponse, Error<ReplyMessageError>> {
+    reply_messages(
+        configuration,
+        reply_token,
+        [models::TextMessage::new(text).into()],
+    )
+    .await
+}
+
+/// Replies to `reply_token` with `messages`, at most
+/// [`models::ReplyMessageRequest::MAX_MESSAGES`] of them.
+pub async fn reply_messages(
+    configuration: &configuration::Configuration,
+    reply_token: impl Into<String>,
+    messages: impl IntoIterator<Item = models::Message>,
+) -> Result<models::ReplyMessageResponse, Error<ReplyMessageError>> {
+    let request =
+        models::ReplyMessageRequest::new(reply_token.into(), messages.into_iter().collect());
+    reply_message(configuration, request).await
+}
+
 /// You can use this endpoint to batch control the rich menu linked to the users using the endpoint such as Link rich menu to user. The following operations are available:  1. Replace a rich menu with another rich menu for all users linked to a specific rich menu 2. Unlink a rich menu for all users linked to a specific rich menu 3. Unlink a rich menu for all users linked the rich menu
 pub async fn rich_menu_batch(
     configuration: &configuration::Configuration,
@@ -3131,18 +3621,20 @@ pub async fn rich_menu_batch(
     req_builder = req_builder.json(&p_body_rich_menu_batch_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<RichMenuBatchError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3173,23 +3665,57 @@ pub async fn set_default_rich_menu(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<SetDefaultRichMenuError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Sets the rich menu that `rich_menu_alias_id` points to as the default
+/// rich menu.
+///
+/// Looks the alias up with [`get_rich_menu_alias`] first; if that fails,
+/// its error is returned with the response body and status unchanged, e.g.
+/// `404 Not Found` for an unknown alias.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::messaging_api_api::set_default_rich_menu_with_alias;
+///
+/// # async fn example(configuration: &Configuration) -> Result<(), Box<dyn std::error::Error>> {
+/// set_default_rich_menu_with_alias(configuration, "richmenu-alias-a").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_default_rich_menu_with_alias(
+    configuration: &configuration::Configuration,
+    rich_menu_alias_id: &str,
+) -> Result<(), Error<SetDefaultRichMenuError>> {
+    let alias = get_rich_menu_alias(configuration, rich_menu_alias_id)
+        .await
+        .map_err(|e| {
+            e.map_entity(|GetRichMenuAliasError::UnknownValue(value)| {
+                SetDefaultRichMenuError::UnknownValue(value)
+            })
+        })?;
+    set_default_rich_menu(configuration, &alias.rich_menu_id).await
+}
+
 /// Set webhook endpoint URL
 pub async fn set_webhook_endpoint(
     configuration: &configuration::Configuration,
@@ -3213,18 +3739,20 @@ pub async fn set_webhook_endpoint(
     req_builder = req_builder.json(&p_body_set_webhook_endpoint_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<SetWebhookEndpointError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3237,6 +3765,7 @@ pub async fn show_loading_animation(
 ) -> Result<serde_json::Value, Error<ShowLoadingAnimationError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_show_loading_animation_request = show_loading_animation_request;
+    p_body_show_loading_animation_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/chat/loading/start", configuration.base_path);
     let mut req_builder = configuration
@@ -3252,7 +3781,7 @@ pub async fn show_loading_animation(
     req_builder = req_builder.json(&p_body_show_loading_animation_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -3270,11 +3799,13 @@ pub async fn show_loading_animation(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `serde_json::Value`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ShowLoadingAnimationError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3302,7 +3833,7 @@ pub async fn test_webhook_endpoint(
     req_builder = req_builder.json(&p_body_test_webhook_endpoint_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -3320,11 +3851,13 @@ pub async fn test_webhook_endpoint(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::TestWebhookEndpointResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<TestWebhookEndpointError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3355,18 +3888,20 @@ pub async fn unlink_rich_menu_id_from_user(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<UnlinkRichMenuIdFromUserError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3379,6 +3914,7 @@ pub async fn unlink_rich_menu_id_from_users(
 ) -> Result<(), Error<UnlinkRichMenuIdFromUsersError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_body_rich_menu_bulk_unlink_request = rich_menu_bulk_unlink_request;
+    p_body_rich_menu_bulk_unlink_request.validate()?;
 
     let uri_str = format!("{}/v2/bot/richmenu/bulk/unlink", configuration.base_path);
     let mut req_builder = configuration
@@ -3394,18 +3930,20 @@ pub async fn unlink_rich_menu_id_from_users(
     req_builder = req_builder.json(&p_body_rich_menu_bulk_unlink_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<UnlinkRichMenuIdFromUsersError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3439,24 +3977,29 @@ pub async fn update_rich_menu_alias(
     req_builder = req_builder.json(&p_body_update_rich_menu_alias_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<UpdateRichMenuAliasError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Validate message objects of a broadcast message
+///
+/// Invalid messages are reported as [`ValidateBroadcastError::Status400`], which lists the
+/// rejected properties.
 pub async fn validate_broadcast(
     configuration: &configuration::Configuration,
     validate_message_request: models::ValidateMessageRequest,
@@ -3481,24 +4024,29 @@ pub async fn validate_broadcast(
     req_builder = req_builder.json(&p_body_validate_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateBroadcastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Validate message objects of a multicast message
+///
+/// Invalid messages are reported as [`ValidateMulticastError::Status400`], which lists the
+/// rejected properties.
 pub async fn validate_multicast(
     configuration: &configuration::Configuration,
     validate_message_request: models::ValidateMessageRequest,
@@ -3523,24 +4071,29 @@ pub async fn validate_multicast(
     req_builder = req_builder.json(&p_body_validate_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateMulticastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Validate message objects of a narrowcast message
+///
+/// Invalid messages are reported as [`ValidateNarrowcastError::Status400`], which lists the
+/// rejected properties.
 pub async fn validate_narrowcast(
     configuration: &configuration::Configuration,
     validate_message_request: models::ValidateMessageRequest,
@@ -3565,24 +4118,29 @@ pub async fn validate_narrowcast(
     req_builder = req_builder.json(&p_body_validate_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateNarrowcastError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Validate message objects of a push message
+///
+/// Invalid messages are reported as [`ValidatePushError::Status400`], which lists the
+/// rejected properties.
 pub async fn validate_push(
     configuration: &configuration::Configuration,
     validate_message_request: models::ValidateMessageRequest,
@@ -3604,24 +4162,29 @@ pub async fn validate_push(
     req_builder = req_builder.json(&p_body_validate_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidatePushError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
 /// Validate message objects of a reply message
+///
+/// Invalid messages are reported as [`ValidateReplyError::Status400`], which lists the
+/// rejected properties.
 pub async fn validate_reply(
     configuration: &configuration::Configuration,
     validate_message_request: models::ValidateMessageRequest,
@@ -3643,18 +4206,20 @@ pub async fn validate_reply(
     req_builder = req_builder.json(&p_body_validate_message_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateReplyError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3682,18 +4247,20 @@ pub async fn validate_rich_menu_batch_request(
     req_builder = req_builder.json(&p_body_rich_menu_batch_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateRichMenuBatchRequestError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -3721,18 +4288,20 @@ pub async fn validate_rich_menu_object(
     req_builder = req_builder.json(&p_body_rich_menu_request);
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<ValidateRichMenuObjectError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -19,6 +19,10 @@ use tokio_util::codec::{BytesCodec, FramedRead};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum GetMessageContentError {
+    /// The content is still being prepared (`202 Accepted`); try again later.
+    /// Only returned by [`get_message_content_bytes`] and [`get_message_content_stream`].
+    #[serde(skip)]
+    ContentNotReady,
     UnknownValue(serde_json::Value),
 }
 
@@ -60,7 +64,7 @@ pub async fn get_message_content(
 
     let uri_str = format!(
         "{}/v2/bot/message/{messageId}/content",
-        configuration.base_path,
+        configuration.data_base_path,
         messageId = crate::apis::urlencode(p_path_message_id)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -73,23 +77,78 @@ pub async fn get_message_content(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(resp)
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMessageContentError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Download image, video, and audio data sent from users into memory.
+///
+/// Returns [`GetMessageContentError::ContentNotReady`] while a video or audio
+/// is still being prepared.
+pub async fn get_message_content_bytes(
+    configuration: &configuration::Configuration,
+    message_id: &str,
+) -> Result<Vec<u8>, Error<GetMessageContentError>> {
+    let resp = get_message_content(configuration, message_id).await?;
+    let resp = ensure_content_ready(resp).await?;
+    Ok(resp.bytes().await?.to_vec())
+}
+
+/// Download image, video, and audio data sent from users as a stream of chunks.
+///
+/// Unlike [`get_message_content_bytes`], the content is never held in memory as
+/// a whole, which matters for large videos: each chunk can be written to a file
+/// as it arrives. Returns [`GetMessageContentError::ContentNotReady`] while a
+/// video or audio is still being prepared.
+pub async fn get_message_content_stream(
+    configuration: &configuration::Configuration,
+    message_id: &str,
+) -> Result<
+    impl futures_util::Stream<Item = Result<bytes::Bytes, Error<GetMessageContentError>>>,
+    Error<GetMessageContentError>,
+> {
+    use futures_util::StreamExt as _;
+
+    let resp = get_message_content(configuration, message_id).await?;
+    let resp = ensure_content_ready(resp).await?;
+    Ok(resp.bytes_stream().map(|r| r.map_err(Error::from)))
+}
+
+/// Maps a `202 Accepted` from the content endpoint to [`GetMessageContentError::ContentNotReady`].
+async fn ensure_content_ready(
+    resp: reqwest::Response,
+) -> Result<reqwest::Response, Error<GetMessageContentError>> {
+    let status = resp.status();
+
+    if status == reqwest::StatusCode::ACCEPTED {
+        let request_id = super::request_id(resp.headers());
+        let content = resp.text().await?;
+        Err(Error::ResponseError(ResponseContent {
+            status,
+            content,
+            request_id,
+            entity: Some(GetMessageContentError::ContentNotReady),
+        }))
+    } else {
+        Ok(resp)
+    }
+}
+
 /// Get a preview image of the image or video
 pub async fn get_message_content_preview(
     configuration: &configuration::Configuration,
@@ -100,7 +159,7 @@ pub async fn get_message_content_preview(
 
     let uri_str = format!(
         "{}/v2/bot/message/{messageId}/content/preview",
-        configuration.base_path,
+        configuration.data_base_path,
         messageId = crate::apis::urlencode(p_path_message_id)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -113,18 +172,20 @@ pub async fn get_message_content_preview(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(resp)
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMessageContentPreviewError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -143,7 +204,7 @@ pub async fn get_message_content_transcoding_by_message_id(
 
     let uri_str = format!(
         "{}/v2/bot/message/{messageId}/content/transcoding",
-        configuration.base_path,
+        configuration.data_base_path,
         messageId = crate::apis::urlencode(p_path_message_id)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -156,7 +217,7 @@ pub async fn get_message_content_transcoding_by_message_id(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
     let content_type = resp
@@ -174,12 +235,14 @@ pub async fn get_message_content_transcoding_by_message_id(
             ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GetMessageContentTranscodingResponse`")))),
         }
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetMessageContentTranscodingByMessageIdError> =
             serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
@@ -195,7 +258,7 @@ pub async fn get_rich_menu_image(
 
     let uri_str = format!(
         "{}/v2/bot/richmenu/{richMenuId}/content",
-        configuration.base_path,
+        configuration.data_base_path,
         richMenuId = crate::apis::urlencode(p_path_rich_menu_id)
     );
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
@@ -208,23 +271,34 @@ pub async fn get_rich_menu_image(
     };
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(resp)
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<GetRichMenuImageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
 }
 
+/// Download rich menu image into memory.
+pub async fn get_rich_menu_image_bytes(
+    configuration: &configuration::Configuration,
+    rich_menu_id: &str,
+) -> Result<Vec<u8>, Error<GetRichMenuImageError>> {
+    let resp = get_rich_menu_image(configuration, rich_menu_id).await?;
+    Ok(resp.bytes().await?.to_vec())
+}
+
 /// Upload rich menu image
 pub async fn set_rich_menu_image(
     configuration: &configuration::Configuration,
@@ -232,12 +306,67 @@ pub async fn set_rich_menu_image(
     body: Option<std::path::PathBuf>,
 ) -> Result<(), Error<SetRichMenuImageError>> {
     // add a prefix to parameters to efficiently prevent name collisions
-    let p_path_rich_menu_id = rich_menu_id;
     let p_body_body = body;
 
+    let body = match p_body_body {
+        Some(param_value) => {
+            let file = TokioFile::open(param_value).await?;
+            let stream = FramedRead::new(file, BytesCodec::new());
+            Some(reqwest::Body::wrap_stream(stream))
+        }
+        None => None,
+    };
+    upload_rich_menu_image(configuration, rich_menu_id, body, None).await
+}
+
+/// Maximum size of a rich menu image, in bytes.
+pub const RICH_MENU_IMAGE_MAX_BYTES: usize = 1024 * 1024;
+
+/// Upload rich menu image from memory.
+///
+/// `content_type` must be `image/jpeg` or `image/png`, and the image must not
+/// exceed [`RICH_MENU_IMAGE_MAX_BYTES`].
+pub async fn set_rich_menu_image_bytes(
+    configuration: &configuration::Configuration,
+    rich_menu_id: &str,
+    image: Vec<u8>,
+    content_type: &str,
+) -> Result<(), Error<SetRichMenuImageError>> {
+    if content_type != "image/jpeg" && content_type != "image/png" {
+        return Err(
+            models::ValidationError::UnsupportedContentType(content_type.to_string()).into(),
+        );
+    }
+    if image.len() > RICH_MENU_IMAGE_MAX_BYTES {
+        return Err(models::ValidationError::ContentTooLarge {
+            max_bytes: RICH_MENU_IMAGE_MAX_BYTES,
+            actual_bytes: image.len(),
+        }
+        .into());
+    }
+
+    upload_rich_menu_image(
+        configuration,
+        rich_menu_id,
+        Some(reqwest::Body::from(image)),
+        Some(content_type),
+    )
+    .await
+}
+
+/// Sends the request shared by [`set_rich_menu_image`] and [`set_rich_menu_image_bytes`].
+async fn upload_rich_menu_image(
+    configuration: &configuration::Configuration,
+    rich_menu_id: &str,
+    body: Option<reqwest::Body>,
+    content_type: Option<&str>,
+) -> Result<(), Error<SetRichMenuImageError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_rich_menu_id = rich_menu_id;
+
     let uri_str = format!(
         "{}/v2/bot/richmenu/{richMenuId}/content",
-        configuration.base_path,
+        configuration.data_base_path,
         richMenuId = crate::apis::urlencode(p_path_rich_menu_id)
     );
     let mut req_builder = configuration
@@ -250,25 +379,28 @@ pub async fn set_rich_menu_image(
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
     };
-    if let Some(param_value) = p_body_body {
-        let file = TokioFile::open(param_value).await?;
-        let stream = FramedRead::new(file, BytesCodec::new());
-        req_builder = req_builder.body(reqwest::Body::wrap_stream(stream));
+    if let Some(content_type) = content_type {
+        req_builder = req_builder.header(reqwest::header::CONTENT_TYPE, content_type);
+    }
+    if let Some(body) = body {
+        req_builder = req_builder.body(body);
     }
 
     let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
+    let resp = super::execute(configuration, req).await?;
 
     let status = resp.status();
 
     if !status.is_client_error() && !status.is_server_error() {
         Ok(())
     } else {
+        let request_id = super::request_id(resp.headers());
         let content = resp.text().await?;
         let entity: Option<SetRichMenuImageError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent {
             status,
             content,
+            request_id,
             entity,
         }))
     }
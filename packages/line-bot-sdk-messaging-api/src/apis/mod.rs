@@ -1,19 +1,167 @@
 use std::error;
 use std::fmt;
 
+/// A response with an error status, as returned in [`Error::ResponseError`].
+///
+/// `request_id` was added in 0.2.0, so struct literals written against 0.1
+/// need `request_id: None`; [`ResponseContent::new`] fills it in for you.
 #[derive(Debug, Clone)]
 pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
     pub content: String,
+    /// The `X-Line-Request-Id` header of the failed response.
+    pub request_id: Option<String>,
     pub entity: Option<T>,
 }
 
+impl<T> ResponseContent<T> {
+    /// A response without a request id, e.g. for a stubbed API in tests.
+    pub fn new(status: reqwest::StatusCode, content: impl Into<String>, entity: Option<T>) -> Self {
+        ResponseContent {
+            status,
+            content: content.into(),
+            request_id: None,
+            entity,
+        }
+    }
+
+    /// Sets the `X-Line-Request-Id` of the response.
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Parses `content` as LINE's standard `{ message, details }` error body.
+    pub fn line_error(&self) -> Option<LineApiError> {
+        let body: crate::models::ErrorResponse = serde_json::from_str(&self.content).ok()?;
+        Some(LineApiError {
+            status: self.status,
+            message: body.message,
+            details: body.details.unwrap_or_default(),
+            request_id: self.request_id.clone(),
+        })
+    }
+}
+
+/// A LINE Platform error response, independent of the endpoint that returned it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineApiError {
+    pub status: reqwest::StatusCode,
+    pub message: String,
+    /// Which properties of the request were rejected, and why.
+    pub details: Vec<crate::models::ErrorDetail>,
+    pub request_id: Option<String>,
+}
+
+impl fmt::Display for LineApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (status code {})", self.message, self.status)
+    }
+}
+
+impl error::Error for LineApiError {}
+
+/// Reads the `X-Line-Request-Id` header.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get("x-line-request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// A deserialized response body along with the HTTP status and headers it came with.
+#[derive(Debug, Clone)]
+pub struct ResponseWithHttpInfo<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub rate_limit: RateLimit,
+    pub entity: T,
+    /// `true` when LINE answered `409 Conflict` because the retry key was
+    /// already used, i.e. the messages were sent by an earlier request. The
+    /// entity is then the default value.
+    pub deduplicated: bool,
+}
+
+impl<T> ResponseWithHttpInfo<T> {
+    /// The `X-Line-Request-Id` header, which identifies the request when contacting LINE.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("x-line-request-id")
+            .and_then(|v| v.to_str().ok())
+    }
+
+    /// The `X-Line-Accepted-Request-Id` header of a deduplicated response,
+    /// identifying the earlier request that used the same retry key.
+    pub fn accepted_request_id(&self) -> Option<&str> {
+        self.headers
+            .get(ACCEPTED_REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+const ACCEPTED_REQUEST_ID_HEADER: &str = "x-line-accepted-request-id";
+
+/// Whether a response means that the retry key of the request was already
+/// accepted, in which case the request must not be treated as failed.
+pub(crate) fn is_deduplicated(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> bool {
+    status == reqwest::StatusCode::CONFLICT && headers.contains_key(ACCEPTED_REQUEST_ID_HEADER)
+}
+
+/// Rate limit information reported in the `X-RateLimit-*` response headers.
+///
+/// Each field is `None` when the corresponding header is absent or malformed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests allowed in the current window.
+    pub limit: Option<u64>,
+    /// Number of requests left in the current window.
+    pub remaining: Option<u64>,
+    /// Seconds until the current window resets.
+    pub reset: Option<u64>,
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> RateLimit {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+        };
+        RateLimit {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            reset: parse("x-ratelimit-reset"),
+        }
+    }
+}
+
+/// What [`configuration::Configuration::on_response`] is told about an API call.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    pub method: reqwest::Method,
+    /// Path of the endpoint, e.g. `/v2/bot/message/push`.
+    pub path: String,
+    pub status: reqwest::StatusCode,
+    /// The `X-Line-Request-Id` header of the response.
+    pub request_id: Option<String>,
+    /// Number of retries before this response, 0 if the first attempt succeeded.
+    pub retries: u32,
+    /// Time from sending the first attempt until the final response headers arrived.
+    pub elapsed: std::time::Duration,
+}
+
 #[derive(Debug)]
 pub enum Error<T> {
     Reqwest(reqwest::Error),
     Serde(serde_json::Error),
     Io(std::io::Error),
     ResponseError(ResponseContent<T>),
+    /// The request was rejected locally, before being sent.
+    Validation(crate::models::ValidationError),
 }
 
 impl<T> fmt::Display for Error<T> {
@@ -23,11 +171,38 @@ impl<T> fmt::Display for Error<T> {
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
             Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
+            Error::Validation(e) => ("validation", e.to_string()),
         };
         write!(f, "error in {}: {}", module, e)
     }
 }
 
+impl<T> Error<T> {
+    /// The parsed LINE error body, if the request failed with an error response.
+    pub fn line_error(&self) -> Option<LineApiError> {
+        match self {
+            Error::ResponseError(content) => content.line_error(),
+            _ => None,
+        }
+    }
+
+    /// Converts the typed error body, for helpers that call several endpoints.
+    pub(crate) fn map_entity<U>(self, f: impl FnOnce(T) -> U) -> Error<U> {
+        match self {
+            Error::Reqwest(e) => Error::Reqwest(e),
+            Error::Serde(e) => Error::Serde(e),
+            Error::Io(e) => Error::Io(e),
+            Error::ResponseError(content) => Error::ResponseError(ResponseContent {
+                status: content.status,
+                content: content.content,
+                request_id: content.request_id,
+                entity: content.entity.map(f),
+            }),
+            Error::Validation(e) => Error::Validation(e),
+        }
+    }
+}
+
 impl<T: fmt::Debug> error::Error for Error<T> {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         Some(match self {
@@ -35,6 +210,7 @@ impl<T: fmt::Debug> error::Error for Error<T> {
             Error::Serde(e) => e,
             Error::Io(e) => e,
             Error::ResponseError(_) => return None,
+            Error::Validation(e) => e,
         })
     }
 }
@@ -57,6 +233,155 @@ impl<T> From<std::io::Error> for Error<T> {
     }
 }
 
+impl<T> From<crate::models::ValidationError> for Error<T> {
+    fn from(e: crate::models::ValidationError) -> Self {
+        Error::Validation(e)
+    }
+}
+
+/// Sends `req` with the configured transport and timeout, retrying idempotent requests
+/// according to [`configuration::RetryPolicy`], and reports the final response to
+/// [`configuration::Configuration::on_response`].
+pub(crate) async fn execute(
+    configuration: &configuration::Configuration,
+    mut req: reqwest::Request,
+) -> Result<reqwest::Response, reqwest::Error> {
+    if let Some(timeout) = configuration.timeout {
+        *req.timeout_mut() = Some(timeout);
+    }
+
+    let method = req.method().clone();
+    let path = req.url().path().to_owned();
+    let start = std::time::Instant::now();
+
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!(
+        "line_api_request",
+        method = %method,
+        endpoint = %path,
+        status = tracing::field::Empty,
+        retries = tracing::field::Empty,
+        request_id = tracing::field::Empty,
+    );
+    #[cfg(feature = "tracing")]
+    let (resp, retries) = {
+        use tracing::Instrument as _;
+        let result = send_with_retries(configuration, req)
+            .instrument(span.clone())
+            .await;
+        if let Ok((ref resp, retries)) = result {
+            span.record("status", resp.status().as_u16());
+            span.record("retries", retries);
+            if let Some(request_id) = request_id(resp.headers()) {
+                span.record("request_id", request_id.as_str());
+            }
+        }
+        result?
+    };
+    #[cfg(not(feature = "tracing"))]
+    let (resp, retries) = send_with_retries(configuration, req).await?;
+
+    if let Some(ref hook) = configuration.on_response {
+        hook(&ResponseMeta {
+            method,
+            path,
+            status: resp.status(),
+            request_id: request_id(resp.headers()),
+            retries,
+            elapsed: start.elapsed(),
+        });
+    }
+    Ok(resp)
+}
+
+/// Returns the final response along with the number of retries it took.
+async fn send_with_retries(
+    configuration: &configuration::Configuration,
+    mut req: reqwest::Request,
+) -> Result<(reqwest::Response, u32), reqwest::Error> {
+    let policy = match configuration.retry_policy {
+        Some(ref policy) if is_idempotent(&req) => policy,
+        _ => return Ok((configuration.transport().execute(req).await?, 0)),
+    };
+
+    let mut retry = 0;
+    loop {
+        // Requests with a streaming body can't be replayed.
+        let next = if retry < policy.max_retries {
+            req.try_clone()
+        } else {
+            None
+        };
+        let result = configuration.transport().execute(req).await;
+        let retryable = match result {
+            Ok(ref resp) => policy.retry_on.contains(&resp.status()),
+            Err(ref err) => err.is_connect() || err.is_timeout(),
+        };
+        let next = match next {
+            Some(next) if retryable => next,
+            _ => return result.map(|resp| (resp, retry)),
+        };
+
+        let delay = match result.as_ref().ok().and_then(retry_after) {
+            Some(delay) => delay.min(policy.max_delay),
+            None => policy.backoff(retry),
+        };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            status = result.as_ref().ok().map(|resp| resp.status().as_u16()),
+            error = result.as_ref().err().map(tracing::field::display),
+            retry = retry + 1,
+            delay_ms = delay.as_millis() as u64,
+            "retrying LINE API request"
+        );
+        tokio::time::sleep(delay).await;
+        req = next;
+        retry += 1;
+    }
+}
+
+fn is_idempotent(req: &reqwest::Request) -> bool {
+    matches!(
+        *req.method(),
+        reqwest::Method::GET | reqwest::Method::PUT | reqwest::Method::DELETE
+    ) || req.headers().contains_key("x-line-retry-key")
+}
+
+/// Parses a `Retry-After` header given in seconds. The HTTP-date form isn't
+/// supported and falls back to the policy's backoff.
+fn retry_after(resp: &reqwest::Response) -> Option<std::time::Duration> {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+        .map(std::time::Duration::from_secs)
+}
+
+/// Yields the items of a paginated endpoint one at a time, following the
+/// `next` continuation token returned by `fetch` until it runs out.
+pub(crate) fn paginate<T, E, F, Fut>(
+    fetch: F,
+) -> impl futures_util::Stream<Item = Result<T, Error<E>>>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Option<String>), Error<E>>>,
+{
+    use futures_util::{stream, StreamExt as _, TryStreamExt as _};
+
+    // `None` once the last page has been fetched, otherwise the start token.
+    let start: Option<Option<String>> = Some(None);
+    stream::try_unfold((fetch, start), |(mut fetch, start)| async move {
+        let start = match start {
+            Some(start) => start,
+            None => return Ok::<_, Error<E>>(None),
+        };
+        let (items, next) = fetch(start).await?;
+        Ok(Some((items, (fetch, next.map(Some)))))
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
 pub fn urlencode<T: AsRef<str>>(s: T) -> String {
     ::url::form_urlencoded::byte_serialize(s.as_ref().as_bytes()).collect()
 }
@@ -117,3 +442,4 @@ pub mod messaging_api_api;
 pub mod messaging_api_blob_api;
 
 pub mod configuration;
+pub mod transport;
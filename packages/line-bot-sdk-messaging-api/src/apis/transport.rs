@@ -0,0 +1,55 @@
+//! The HTTP layer the API functions send their requests through.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by [`HttpTransport::execute`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<reqwest::Response, reqwest::Error>> + Send + 'a>>;
+
+/// Sends a built request and returns the response.
+///
+/// [`reqwest::Client`] is the default transport. Set
+/// [`Configuration::transport`](super::configuration::Configuration::transport)
+/// to another implementation, e.g. one returning canned responses, to exercise
+/// bot logic without a network.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+/// use line_bot_sdk_messaging_api::apis::transport::{HttpTransport, TransportFuture};
+///
+/// #[derive(Debug)]
+/// struct CannedTransport;
+///
+/// impl HttpTransport for CannedTransport {
+///     fn execute(&self, req: reqwest::Request) -> TransportFuture<'_> {
+///         assert_eq!(req.url().path(), "/v2/bot/message/reply");
+///         Box::pin(async {
+///             let resp = http::Response::builder()
+///                 .header("content-type", "application/json")
+///                 .body(r#"{"sentMessages":[]}"#)
+///                 .unwrap();
+///             Ok(reqwest::Response::from(resp))
+///         })
+///     }
+/// }
+///
+/// let configuration = Configuration {
+///     transport: Some(Arc::new(CannedTransport)),
+///     ..Configuration::default()
+/// };
+/// ```
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    fn execute(&self, req: reqwest::Request) -> TransportFuture<'_>;
+}
+
+impl HttpTransport for reqwest::Client {
+    fn execute(&self, req: reqwest::Request) -> TransportFuture<'_> {
+        Box::pin(reqwest::Client::execute(self, req))
+    }
+}
@@ -10,11 +10,12 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// Action enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Action {
     CameraAction(Box<models::CameraAction>),
@@ -28,12 +29,256 @@ pub enum Action {
     UriAction(Box<models::UriAction>),
 }
 
+impl<'de> Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "camera" => Action::CameraAction(Box::new(from_value(value)?)),
+            "cameraRoll" => Action::CameraRollAction(Box::new(from_value(value)?)),
+            "clipboard" => Action::ClipboardAction(Box::new(from_value(value)?)),
+            "datetimepicker" => Action::DatetimePickerAction(Box::new(from_value(value)?)),
+            "location" => Action::LocationAction(Box::new(from_value(value)?)),
+            "message" => Action::MessageAction(Box::new(from_value(value)?)),
+            "postback" => Action::PostbackAction(Box::new(from_value(value)?)),
+            "richmenuswitch" => Action::RichMenuSwitchAction(Box::new(from_value(value)?)),
+            "uri" => Action::UriAction(Box::new(from_value(value)?)),
+            other => return Err(D::Error::custom(format!("unknown action type `{other}`"))),
+        })
+    }
+}
+
 impl Default for Action {
     fn default() -> Self {
         Self::CameraAction(Box::new(Default::default()))
     }
 }
 
+impl Action {
+    /// Maximum number of characters in the `data` of a postback, datetime
+    /// picker or rich menu switch action.
+    pub const MAX_DATA_LENGTH: usize = 300;
+
+    /// An action that opens `uri`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::Action;
+    ///
+    /// let json = serde_json::to_value(Action::uri("Open", "https://example.com")).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     serde_json::json!({"type": "uri", "label": "Open", "uri": "https://example.com"})
+    /// );
+    /// ```
+    pub fn uri(label: impl Into<String>, uri: impl Into<String>) -> Action {
+        Action::from(models::UriAction {
+            r#type: Some("uri".to_string()),
+            label: Some(label.into()),
+            uri: Some(uri.into()),
+            alt_uri: None,
+        })
+    }
+
+    /// An action that sends `text` as a message from the user.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::Action;
+    ///
+    /// let json = serde_json::to_value(Action::message("Yes", "yes")).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     serde_json::json!({"type": "message", "label": "Yes", "text": "yes"})
+    /// );
+    /// ```
+    pub fn message(label: impl Into<String>, text: impl Into<String>) -> Action {
+        Action::from(models::MessageAction {
+            r#type: Some("message".to_string()),
+            label: Some(label.into()),
+            text: Some(text.into()),
+        })
+    }
+
+    /// An action that returns `data` in a postback event, optionally showing
+    /// `display_text` as a message from the user.
+    ///
+    /// Fails if `data` is longer than [`Self::MAX_DATA_LENGTH`] characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{Action, ValidationError};
+    ///
+    /// let action = Action::postback("Buy", "action=buy&item=1", Some("Buy it".to_string())).unwrap();
+    /// assert_eq!(
+    ///     serde_json::to_value(action).unwrap(),
+    ///     serde_json::json!({
+    ///         "type": "postback",
+    ///         "label": "Buy",
+    ///         "data": "action=buy&item=1",
+    ///         "displayText": "Buy it"
+    ///     })
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Action::postback("Buy", "x".repeat(301), None),
+    ///     Err(ValidationError::ActionDataTooLong { max: 300, actual: 301 })
+    /// );
+    /// ```
+    pub fn postback(
+        label: impl Into<String>,
+        data: impl Into<String>,
+        display_text: Option<String>,
+    ) -> Result<Action, models::ValidationError> {
+        let data = data.into();
+        models::validation_error::validate_action_data(&data)?;
+        Ok(Action::from(models::PostbackAction {
+            r#type: Some("postback".to_string()),
+            label: Some(label.into()),
+            data: Some(data),
+            display_text,
+            ..Default::default()
+        }))
+    }
+
+    /// An action that lets the user pick a date, time or both, returning
+    /// `data` and the selection in a postback event.
+    ///
+    /// Fails if `data` is longer than [`Self::MAX_DATA_LENGTH`] characters or
+    /// `initial` doesn't match the format of `mode`; see
+    /// [`models::DatetimePickerAction::new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::datetime_picker_action::Mode;
+    /// use line_bot_sdk_messaging_api::models::Action;
+    ///
+    /// let action =
+    ///     Action::datetime_picker("Pick", "booking", Mode::Date, Some("2024-01-01".to_string()))
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     serde_json::to_value(action).unwrap(),
+    ///     serde_json::json!({
+    ///         "type": "datetimepicker",
+    ///         "label": "Pick",
+    ///         "data": "booking",
+    ///         "mode": "date",
+    ///         "initial": "2024-01-01"
+    ///     })
+    /// );
+    /// assert!(Action::datetime_picker("Pick", "x".repeat(301), Mode::Date, None).is_err());
+    /// assert!(Action::datetime_picker("Pick", "booking", Mode::Time, Some("2024-01-01".to_string())).is_err());
+    /// ```
+    pub fn datetime_picker(
+        label: impl Into<String>,
+        data: impl Into<String>,
+        mode: models::datetime_picker_action::Mode,
+        initial: Option<String>,
+    ) -> Result<Action, models::ValidationError> {
+        let mut action = models::DatetimePickerAction::new(label, data, mode)?;
+        if let Some(initial) = initial {
+            action = action.with_initial(initial)?;
+        }
+        Ok(Action::from(action))
+    }
+
+    /// An action that opens the camera.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::Action;
+    ///
+    /// let json = serde_json::to_value(Action::camera("Camera")).unwrap();
+    /// assert_eq!(json, serde_json::json!({"type": "camera", "label": "Camera"}));
+    /// ```
+    pub fn camera(label: impl Into<String>) -> Action {
+        Action::from(models::CameraAction {
+            r#type: Some("camera".to_string()),
+            label: Some(label.into()),
+        })
+    }
+
+    /// An action that opens the location screen.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::Action;
+    ///
+    /// let json = serde_json::to_value(Action::location("Location")).unwrap();
+    /// assert_eq!(json, serde_json::json!({"type": "location", "label": "Location"}));
+    /// ```
+    pub fn location(label: impl Into<String>) -> Action {
+        Action::from(models::LocationAction {
+            r#type: Some("location".to_string()),
+            label: Some(label.into()),
+        })
+    }
+
+    /// An action that switches to the rich menu aliased `rich_menu_alias_id`,
+    /// returning `data` in a postback event.
+    ///
+    /// Fails if `data` is longer than [`Self::MAX_DATA_LENGTH`] characters.
+    /// Usable as a rich menu area's action to build tabbed menus, or on a
+    /// flex button.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{Action, FlexButton, RichMenuArea, ValidationError};
+    ///
+    /// let action = Action::rich_menu_switch("Next", "richmenu-alias-b", "page=2").unwrap();
+    /// assert_eq!(
+    ///     serde_json::to_value(&action).unwrap(),
+    ///     serde_json::json!({
+    ///         "type": "richmenuswitch",
+    ///         "label": "Next",
+    ///         "richMenuAliasId": "richmenu-alias-b",
+    ///         "data": "page=2"
+    ///     })
+    /// );
+    ///
+    /// let area = RichMenuArea {
+    ///     action: Some(Box::new(action.clone())),
+    ///     ..RichMenuArea::new()
+    /// };
+    /// let json = serde_json::to_string(&area).unwrap();
+    /// assert_eq!(serde_json::from_str::<RichMenuArea>(&json).unwrap(), area);
+    ///
+    /// let button = FlexButton::new(action.clone());
+    /// let json = serde_json::to_string(&button).unwrap();
+    /// let button: FlexButton = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(*button.action, action);
+    ///
+    /// assert_eq!(
+    ///     Action::rich_menu_switch("Next", "richmenu-alias-b", "x".repeat(301)),
+    ///     Err(ValidationError::ActionDataTooLong { max: 300, actual: 301 })
+    /// );
+    /// ```
+    pub fn rich_menu_switch(
+        label: impl Into<String>,
+        rich_menu_alias_id: impl Into<String>,
+        data: impl Into<String>,
+    ) -> Result<Action, models::ValidationError> {
+        let data = data.into();
+        models::validation_error::validate_action_data(&data)?;
+        Ok(Action::from(models::RichMenuSwitchAction {
+            r#type: Some("richmenuswitch".to_string()),
+            label: Some(label.into()),
+            data: Some(data),
+            rich_menu_alias_id: Some(rich_menu_alias_id.into()),
+        }))
+    }
+}
+
 // Conversion methods from struct types to enum variants
 impl From<models::CameraAction> for Action {
     fn from(value: models::CameraAction) -> Self {
@@ -27,6 +27,27 @@ pub struct AudioMessage {
 }
 
 impl AudioMessage {
+    /// Creates an audio message playing the file at `original_content_url`,
+    /// which is `duration` milliseconds long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{AudioMessage, Message, ValidationError};
+    ///
+    /// let message: Message = AudioMessage::new("https://example.com/a.m4a".to_string(), 60000).into();
+    /// assert!(message.validate().is_ok());
+    /// assert_eq!(serde_json::to_value(&message).unwrap()["duration"], 60000);
+    ///
+    /// let message = AudioMessage::new("http://example.com/a.m4a".to_string(), 60000);
+    /// assert_eq!(
+    ///     message.validate(),
+    ///     Err(ValidationError::InsecureUrl("http://example.com/a.m4a".to_string()))
+    /// );
+    ///
+    /// let message = AudioMessage::new("https://example.com/a.m4a".to_string(), 0);
+    /// assert_eq!(message.validate(), Err(ValidationError::InvalidDuration(0)));
+    /// ```
     pub fn new(original_content_url: String, duration: i64) -> AudioMessage {
         AudioMessage {
             r#type: None,
@@ -36,4 +57,19 @@ impl AudioMessage {
             duration,
         }
     }
+
+    /// Checks that `original_content_url` uses https and `duration` is positive.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_https(&self.original_content_url)?;
+        if self.duration <= 0 {
+            return Err(models::ValidationError::InvalidDuration(self.duration));
+        }
+        Ok(())
+    }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
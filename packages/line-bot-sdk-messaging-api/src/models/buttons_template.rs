@@ -50,4 +50,62 @@ impl ButtonsTemplate {
             actions,
         }
     }
+
+    /// Maximum number of actions in a buttons template.
+    pub const MAX_ACTIONS: usize = 4;
+
+    /// Returns a [`ButtonsTemplateBuilder`] with empty text and no actions.
+    pub fn builder() -> ButtonsTemplateBuilder {
+        ButtonsTemplateBuilder::default()
+    }
+
+    /// Checks that the template has between 1 and [`Self::MAX_ACTIONS`] actions.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_action_count(&self.actions, 1, Self::MAX_ACTIONS)
+    }
+}
+
+/// Builder for [`ButtonsTemplate`] that fills in the `buttons` type.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ButtonsTemplateBuilder {
+    template: ButtonsTemplate,
+}
+
+impl ButtonsTemplateBuilder {
+    pub fn thumbnail_image_url(mut self, thumbnail_image_url: impl Into<String>) -> Self {
+        self.template.thumbnail_image_url = Some(thumbnail_image_url.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.template.title = Some(title.into());
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.template.text = text.into();
+        self
+    }
+
+    pub fn default_action(mut self, action: impl Into<models::Action>) -> Self {
+        self.template.default_action = Some(Box::new(action.into()));
+        self
+    }
+
+    /// Appends a button.
+    pub fn action(mut self, action: impl Into<models::Action>) -> Self {
+        self.template.actions.push(action.into());
+        self
+    }
+
+    /// Builds the template, rejecting it unless it has 1 to
+    /// [`ButtonsTemplate::MAX_ACTIONS`] actions.
+    pub fn build(self) -> Result<ButtonsTemplate, models::ValidationError> {
+        let template = ButtonsTemplate {
+            r#type: Some("buttons".to_string()),
+            ..self.template
+        };
+        template.validate()?;
+        Ok(template)
+    }
 }
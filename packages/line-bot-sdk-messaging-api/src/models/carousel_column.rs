@@ -43,4 +43,12 @@ impl CarouselColumn {
             actions,
         }
     }
+
+    /// Maximum number of actions in a carousel column.
+    pub const MAX_ACTIONS: usize = 3;
+
+    /// Checks that the column has between 1 and [`Self::MAX_ACTIONS`] actions.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_action_count(&self.actions, 1, Self::MAX_ACTIONS)
+    }
 }
@@ -32,4 +32,23 @@ impl CarouselTemplate {
             image_size: None,
         }
     }
+
+    /// Minimum number of columns in a carousel template.
+    pub const MIN_COLUMNS: usize = 1;
+    /// Maximum number of columns in a carousel template.
+    pub const MAX_COLUMNS: usize = 10;
+
+    /// Checks the number of columns and the actions of each column.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if !(Self::MIN_COLUMNS..=Self::MAX_COLUMNS).contains(&self.columns.len()) {
+            return Err(models::ValidationError::InvalidColumnCount {
+                min: Self::MIN_COLUMNS,
+                max: Self::MAX_COLUMNS,
+                actual: self.columns.len(),
+            });
+        }
+        self.columns
+            .iter()
+            .try_for_each(models::CarouselColumn::validate)
+    }
 }
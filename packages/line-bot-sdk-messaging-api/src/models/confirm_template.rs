@@ -22,11 +22,21 @@ pub struct ConfirmTemplate {
 }
 
 impl ConfirmTemplate {
-    pub fn new(text: String, actions: Vec<models::Action>) -> ConfirmTemplate {
+    /// Creates a confirm template with its two buttons, e.g. "Yes" and "No".
+    pub fn new(
+        text: impl Into<String>,
+        yes_action: impl Into<models::Action>,
+        no_action: impl Into<models::Action>,
+    ) -> ConfirmTemplate {
         ConfirmTemplate {
-            r#type: None,
-            text,
-            actions,
+            r#type: Some("confirm".to_string()),
+            text: text.into(),
+            actions: vec![yes_action.into(), no_action.into()],
         }
     }
+
+    /// Checks that the template has exactly two actions.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_action_count(&self.actions, 2, 2)
+    }
 }
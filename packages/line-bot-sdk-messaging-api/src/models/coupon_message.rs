@@ -38,4 +38,10 @@ impl CouponMessage {
             delivery_tag: None,
         }
     }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
@@ -32,16 +32,99 @@ pub struct DatetimePickerAction {
 }
 
 impl DatetimePickerAction {
-    pub fn new() -> DatetimePickerAction {
-        DatetimePickerAction {
-            r#type: None,
-            label: None,
-            data: None,
-            mode: None,
+    /// A picker for `mode` returning `data` in a postback event.
+    ///
+    /// Fails if `data` is longer than [`models::Action::MAX_DATA_LENGTH`] characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::datetime_picker_action::Mode;
+    /// use line_bot_sdk_messaging_api::models::DatetimePickerAction;
+    ///
+    /// let date = DatetimePickerAction::new("Pick", "booking", Mode::Date)
+    ///     .and_then(|action| action.with_initial("2024-01-01"))
+    ///     .and_then(|action| action.with_min("2024-01-01"))
+    ///     .and_then(|action| action.with_max("2024-12-31"))
+    ///     .unwrap();
+    /// assert_eq!(date.min.as_deref(), Some("2024-01-01"));
+    /// let date = DatetimePickerAction::new("Pick", "booking", Mode::Date).unwrap();
+    /// assert!(date.clone().with_initial("2024-1-1").is_err());
+    /// assert!(date.clone().with_initial("2024-13-01").is_err());
+    /// assert!(date.with_initial("2024-01-01T10:00").is_err());
+    ///
+    /// let time = DatetimePickerAction::new("Pick", "alarm", Mode::Time).unwrap();
+    /// assert!(time.clone().with_initial("09:30").is_ok());
+    /// assert!(time.clone().with_max("24:00").is_err());
+    /// assert!(time.with_min("9:30").is_err());
+    ///
+    /// let datetime = DatetimePickerAction::new("Pick", "meeting", Mode::Datetime).unwrap();
+    /// assert!(datetime.clone().with_initial("2024-01-01T09:30").is_ok());
+    /// assert!(datetime.clone().with_initial("2024-01-01").is_err());
+    /// assert!(datetime.with_initial("2024-01-01 09:30").is_err());
+    /// ```
+    pub fn new(
+        label: impl Into<String>,
+        data: impl Into<String>,
+        mode: Mode,
+    ) -> Result<DatetimePickerAction, models::ValidationError> {
+        let data = data.into();
+        models::validation_error::validate_action_data(&data)?;
+        Ok(DatetimePickerAction {
+            r#type: Some("datetimepicker".to_string()),
+            label: Some(label.into()),
+            data: Some(data),
+            mode: Some(mode),
             initial: None,
             max: None,
             min: None,
+        })
+    }
+
+    /// Sets the value selected when the picker opens, failing if it doesn't
+    /// match the format of the mode.
+    pub fn with_initial(
+        mut self,
+        initial: impl Into<String>,
+    ) -> Result<Self, models::ValidationError> {
+        let initial = initial.into();
+        self.check(&initial)?;
+        self.initial = Some(initial);
+        Ok(self)
+    }
+
+    /// Sets the earliest selectable value, failing if it doesn't match the
+    /// format of the mode.
+    pub fn with_min(mut self, min: impl Into<String>) -> Result<Self, models::ValidationError> {
+        let min = min.into();
+        self.check(&min)?;
+        self.min = Some(min);
+        Ok(self)
+    }
+
+    /// Sets the latest selectable value, failing if it doesn't match the
+    /// format of the mode.
+    pub fn with_max(mut self, max: impl Into<String>) -> Result<Self, models::ValidationError> {
+        let max = max.into();
+        self.check(&max)?;
+        self.max = Some(max);
+        Ok(self)
+    }
+
+    fn check(&self, value: &str) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_datetime(self.mode.unwrap_or_default(), value)
+    }
+
+    /// Checks the length of `data` and that `initial`, `min` and `max` match
+    /// the format of the mode.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if let Some(ref data) = self.data {
+            models::validation_error::validate_action_data(data)?;
         }
+        for value in [&self.initial, &self.min, &self.max].into_iter().flatten() {
+            self.check(value)?;
+        }
+        Ok(())
     }
 }
 ///
@@ -55,6 +138,17 @@ pub enum Mode {
     Datetime,
 }
 
+impl Mode {
+    /// The format of the `initial`, `min` and `max` values of a picker in this mode.
+    pub fn format(&self) -> &'static str {
+        match self {
+            Self::Date => "yyyy-MM-dd",
+            Self::Time => "HH:mm",
+            Self::Datetime => "yyyy-MM-ddTHH:mm",
+        }
+    }
+}
+
 impl Default for Mode {
     fn default() -> Mode {
         Self::Date
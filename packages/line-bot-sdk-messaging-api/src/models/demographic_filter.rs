@@ -31,39 +31,199 @@ impl Default for DemographicFilter {
     }
 }
 
-// Conversion methods from struct types to enum variants
+// Conversion methods from struct types to enum variants.
+// The enum is untagged, so each conversion fills in the `type` discriminator
+// when the struct was built without one.
 impl From<models::AgeDemographicFilter> for DemographicFilter {
-    fn from(value: models::AgeDemographicFilter) -> Self {
+    fn from(mut value: models::AgeDemographicFilter) -> Self {
+        value.r#type.get_or_insert_with(|| "age".to_string());
         DemographicFilter::AgeDemographicFilter(Box::new(value))
     }
 }
 
 impl From<models::AppTypeDemographicFilter> for DemographicFilter {
-    fn from(value: models::AppTypeDemographicFilter) -> Self {
+    fn from(mut value: models::AppTypeDemographicFilter) -> Self {
+        value.r#type.get_or_insert_with(|| "appType".to_string());
         DemographicFilter::AppTypeDemographicFilter(Box::new(value))
     }
 }
 
 impl From<models::AreaDemographicFilter> for DemographicFilter {
-    fn from(value: models::AreaDemographicFilter) -> Self {
+    fn from(mut value: models::AreaDemographicFilter) -> Self {
+        value.r#type.get_or_insert_with(|| "area".to_string());
         DemographicFilter::AreaDemographicFilter(Box::new(value))
     }
 }
 
 impl From<models::GenderDemographicFilter> for DemographicFilter {
-    fn from(value: models::GenderDemographicFilter) -> Self {
+    fn from(mut value: models::GenderDemographicFilter) -> Self {
+        value.r#type.get_or_insert_with(|| "gender".to_string());
         DemographicFilter::GenderDemographicFilter(Box::new(value))
     }
 }
 
 impl From<models::OperatorDemographicFilter> for DemographicFilter {
-    fn from(value: models::OperatorDemographicFilter) -> Self {
+    fn from(mut value: models::OperatorDemographicFilter) -> Self {
+        value.r#type.get_or_insert_with(|| "operator".to_string());
         DemographicFilter::OperatorDemographicFilter(Box::new(value))
     }
 }
 
 impl From<models::SubscriptionPeriodDemographicFilter> for DemographicFilter {
-    fn from(value: models::SubscriptionPeriodDemographicFilter) -> Self {
+    fn from(mut value: models::SubscriptionPeriodDemographicFilter) -> Self {
+        value
+            .r#type
+            .get_or_insert_with(|| "subscriptionPeriod".to_string());
         DemographicFilter::SubscriptionPeriodDemographicFilter(Box::new(value))
     }
 }
+
+/// Constructors that fill in the `type` discriminators, so filters can be
+/// written as nested expressions.
+///
+/// ```
+/// use line_bot_sdk_messaging_api::models::{AgeDemographic, DemographicFilter, GenderDemographic};
+///
+/// let filter = DemographicFilter::and([
+///     DemographicFilter::gender(GenderDemographic::Male),
+///     DemographicFilter::age_between(AgeDemographic::Age20, AgeDemographic::Age40),
+/// ]);
+/// assert!(filter.validate().is_ok());
+/// ```
+impl DemographicFilter {
+    /// Operators can be nested at most this many levels deep.
+    pub const MAX_DEPTH: usize = 10;
+
+    /// Matches users who match every filter.
+    pub fn and(filters: impl IntoIterator<Item = DemographicFilter>) -> Self {
+        models::OperatorDemographicFilter {
+            and: Some(filters.into_iter().collect()),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Matches users who match at least one filter.
+    pub fn or(filters: impl IntoIterator<Item = DemographicFilter>) -> Self {
+        models::OperatorDemographicFilter {
+            or: Some(filters.into_iter().collect()),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Matches users who don't match `filter`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(filter: DemographicFilter) -> Self {
+        models::OperatorDemographicFilter {
+            not: Some(Box::new(filter)),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Matches users at least `gte` old and younger than `lt`.
+    pub fn age_between(gte: models::AgeDemographic, lt: models::AgeDemographic) -> Self {
+        models::AgeDemographicFilter {
+            gte: Some(gte),
+            lt: Some(lt),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn age_at_least(gte: models::AgeDemographic) -> Self {
+        models::AgeDemographicFilter {
+            gte: Some(gte),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn age_under(lt: models::AgeDemographic) -> Self {
+        models::AgeDemographicFilter {
+            lt: Some(lt),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn gender(gender: models::GenderDemographic) -> Self {
+        models::GenderDemographicFilter {
+            one_of: Some(vec![gender]),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn app_type(app_type: models::AppTypeDemographic) -> Self {
+        models::AppTypeDemographicFilter {
+            one_of: Some(vec![app_type]),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Matches users in any of `areas`.
+    pub fn areas(areas: impl IntoIterator<Item = models::AreaDemographic>) -> Self {
+        models::AreaDemographicFilter {
+            one_of: Some(areas.into_iter().collect()),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Matches users who have been friends for at least `gte` and less than `lt`.
+    pub fn subscription_period_between(
+        gte: models::SubscriptionPeriodDemographic,
+        lt: models::SubscriptionPeriodDemographic,
+    ) -> Self {
+        models::SubscriptionPeriodDemographicFilter {
+            gte: Some(gte),
+            lt: Some(lt),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn subscription_period_at_least(gte: models::SubscriptionPeriodDemographic) -> Self {
+        models::SubscriptionPeriodDemographicFilter {
+            gte: Some(gte),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    pub fn subscription_period_under(lt: models::SubscriptionPeriodDemographic) -> Self {
+        models::SubscriptionPeriodDemographicFilter {
+            lt: Some(lt),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// Checks that operators aren't nested deeper than [`Self::MAX_DEPTH`].
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if self.depth() > Self::MAX_DEPTH {
+            return Err(models::ValidationError::FilterTooDeep {
+                max: Self::MAX_DEPTH,
+            });
+        }
+        Ok(())
+    }
+
+    /// Number of nested operator levels, 0 for a single condition.
+    fn depth(&self) -> usize {
+        let operator = match self {
+            DemographicFilter::OperatorDemographicFilter(operator) => operator,
+            _ => return 0,
+        };
+        let children = operator.and.iter().chain(operator.or.iter()).flatten();
+        let deepest = children
+            .chain(operator.not.as_deref())
+            .map(DemographicFilter::depth)
+            .max()
+            .unwrap_or(0);
+        deepest + 1
+    }
+}
@@ -29,4 +29,16 @@ impl FlexBlockStyle {
             separator_color: None,
         }
     }
+
+    /// Sets the color of the block background.
+    pub fn with_background_color(mut self, background_color: models::FlexColor) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    /// Sets the color of the separator above the block.
+    pub fn with_separator_color(mut self, separator_color: models::FlexColor) -> Self {
+        self.separator_color = Some(separator_color.into());
+        self
+    }
 }
@@ -104,6 +104,92 @@ impl FlexBox {
             background: None,
         }
     }
+
+    /// Creates a `box` that stacks its contents vertically.
+    pub fn vertical(contents: Vec<models::FlexComponent>) -> FlexBox {
+        FlexBox::builder(Layout::Vertical, contents).build()
+    }
+
+    /// Creates a `box` that lays out its contents horizontally.
+    pub fn horizontal(contents: Vec<models::FlexComponent>) -> FlexBox {
+        FlexBox::builder(Layout::Horizontal, contents).build()
+    }
+
+    /// Creates a `box` that aligns its contents on a shared baseline.
+    pub fn baseline(contents: Vec<models::FlexComponent>) -> FlexBox {
+        FlexBox::builder(Layout::Baseline, contents).build()
+    }
+
+    /// Returns a [`FlexBoxBuilder`] for a `box` with the given layout and contents.
+    pub fn builder(layout: Layout, contents: Vec<models::FlexComponent>) -> FlexBoxBuilder {
+        FlexBoxBuilder {
+            flex_box: FlexBox::new(layout, contents),
+        }
+    }
+
+    /// Sets the color of the box background.
+    pub fn with_background_color(mut self, background_color: models::FlexColor) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    /// Sets the color of the box border.
+    pub fn with_border_color(mut self, border_color: models::FlexColor) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+}
+
+/// Builder for the common styling fields of [`FlexBox`].
+///
+/// The color setters take a [`FlexColor`](models::FlexColor) as well as a plain string.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FlexBoxBuilder {
+    flex_box: FlexBox,
+}
+
+impl FlexBoxBuilder {
+    pub fn flex(mut self, flex: i32) -> Self {
+        self.flex_box.flex = Some(flex);
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<String>) -> Self {
+        self.flex_box.spacing = Some(spacing.into());
+        self
+    }
+
+    pub fn margin(mut self, margin: impl Into<String>) -> Self {
+        self.flex_box.margin = Some(margin.into());
+        self
+    }
+
+    pub fn padding_all(mut self, padding_all: impl Into<String>) -> Self {
+        self.flex_box.padding_all = Some(padding_all.into());
+        self
+    }
+
+    pub fn background_color(mut self, background_color: impl Into<String>) -> Self {
+        self.flex_box.background_color = Some(background_color.into());
+        self
+    }
+
+    pub fn border_color(mut self, border_color: impl Into<String>) -> Self {
+        self.flex_box.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn corner_radius(mut self, corner_radius: impl Into<String>) -> Self {
+        self.flex_box.corner_radius = Some(corner_radius.into());
+        self
+    }
+
+    pub fn build(self) -> FlexBox {
+        FlexBox {
+            r#type: Some("box".to_string()),
+            ..self.flex_box
+        }
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
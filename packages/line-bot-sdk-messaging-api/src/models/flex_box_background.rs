@@ -10,16 +10,37 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// FlexBoxBackground enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FlexBoxBackground {
     FlexBoxLinearGradient(Box<models::FlexBoxLinearGradient>),
 }
 
+impl<'de> Deserialize<'de> for FlexBoxBackground {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "linearGradient" => {
+                FlexBoxBackground::FlexBoxLinearGradient(Box::new(from_value(value)?))
+            }
+            other => {
+                return Err(D::Error::custom(format!(
+                    "unknown box background type `{other}`"
+                )))
+            }
+        })
+    }
+}
+
 impl Default for FlexBoxBackground {
     fn default() -> Self {
         Self::FlexBoxLinearGradient(Box::new(Default::default()))
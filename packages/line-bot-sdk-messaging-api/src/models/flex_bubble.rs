@@ -47,6 +47,75 @@ impl FlexBubble {
             action: None,
         }
     }
+
+    /// Returns a [`FlexBubbleBuilder`] with every optional field unset.
+    pub fn builder() -> FlexBubbleBuilder {
+        FlexBubbleBuilder::default()
+    }
+}
+
+/// Builder for [`FlexBubble`] that boxes its children and fills in the `bubble` type.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct FlexBubbleBuilder {
+    bubble: FlexBubble,
+}
+
+impl FlexBubbleBuilder {
+    /// Sets the text direction of the bubble.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.bubble.direction = Some(direction);
+        self
+    }
+
+    /// Sets the styles of the bubble's blocks.
+    pub fn styles(mut self, styles: models::FlexBubbleStyles) -> Self {
+        self.bubble.styles = Some(Box::new(styles));
+        self
+    }
+
+    /// Sets the header block.
+    pub fn header(mut self, header: models::FlexBox) -> Self {
+        self.bubble.header = Some(Box::new(header));
+        self
+    }
+
+    /// Sets the hero block, usually an image or video.
+    pub fn hero(mut self, hero: impl Into<models::FlexComponent>) -> Self {
+        self.bubble.hero = Some(Box::new(hero.into()));
+        self
+    }
+
+    /// Sets the body block.
+    pub fn body(mut self, body: models::FlexBox) -> Self {
+        self.bubble.body = Some(Box::new(body));
+        self
+    }
+
+    /// Sets the footer block.
+    pub fn footer(mut self, footer: models::FlexBox) -> Self {
+        self.bubble.footer = Some(Box::new(footer));
+        self
+    }
+
+    /// Sets the bubble size.
+    pub fn size(mut self, size: Size) -> Self {
+        self.bubble.size = Some(size);
+        self
+    }
+
+    /// Sets the action run when the bubble is tapped.
+    pub fn action(mut self, action: impl Into<models::Action>) -> Self {
+        self.bubble.action = Some(Box::new(action.into()));
+        self
+    }
+
+    /// Returns the bubble with its type set to `bubble`.
+    pub fn build(self) -> FlexBubble {
+        FlexBubble {
+            r#type: "bubble".to_string(),
+            ..self.bubble
+        }
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -65,6 +65,12 @@ impl FlexButton {
             scaling: None,
         }
     }
+
+    /// Sets the color of the button.
+    pub fn with_color(mut self, color: models::FlexColor) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -20,10 +20,47 @@ pub struct FlexCarousel {
 }
 
 impl FlexCarousel {
+    /// Creates a `carousel` of `contents`, which must hold 1 to
+    /// [`FlexContainer::MAX_CAROUSEL_BUBBLES`](models::FlexContainer::MAX_CAROUSEL_BUBBLES)
+    /// bubbles; see [`validate`](Self::validate).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{
+    ///     FlexBubble, FlexCarousel, FlexContainer, FlexValidationError,
+    /// };
+    ///
+    /// let carousel = FlexCarousel::new(vec![FlexBubble::builder().build(); 3]);
+    /// assert!(carousel.validate().is_ok());
+    /// let container: FlexContainer = carousel.into();
+    /// assert_eq!(serde_json::to_value(&container).unwrap()["type"], "carousel");
+    ///
+    /// let carousel = FlexCarousel::new(vec![FlexBubble::builder().build(); 13]);
+    /// assert_eq!(
+    ///     carousel.validate(),
+    ///     Err(FlexValidationError::TooManyBubbles { max: 12, actual: 13 })
+    /// );
+    /// ```
     pub fn new(contents: Vec<models::FlexBubble>) -> FlexCarousel {
         FlexCarousel {
-            r#type: None,
+            r#type: Some("carousel".to_string()),
             contents,
         }
     }
+
+    /// Checks that the carousel holds at least one bubble and at most
+    /// [`FlexContainer::MAX_CAROUSEL_BUBBLES`](models::FlexContainer::MAX_CAROUSEL_BUBBLES).
+    pub fn validate(&self) -> Result<(), models::FlexValidationError> {
+        if self.contents.is_empty() {
+            return Err(models::FlexValidationError::EmptyCarousel);
+        }
+        if self.contents.len() > models::FlexContainer::MAX_CAROUSEL_BUBBLES {
+            return Err(models::FlexValidationError::TooManyBubbles {
+                max: models::FlexContainer::MAX_CAROUSEL_BUBBLES,
+                actual: self.contents.len(),
+            });
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,90 @@
+//! Hex colors for the color fields of flex components.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A color in the `#RRGGBB` or `#RRGGBBAA` format LINE accepts for flex
+/// components.
+///
+/// The color fields of the flex models stay plain strings so any JSON
+/// deserializes, but the `with_*_color` setters take a `FlexColor`, which
+/// catches typos that LINE would otherwise render as the default color.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::models::{ColorError, FlexColor};
+///
+/// assert_eq!(FlexColor::parse("#1DB446").unwrap().as_str(), "#1DB446");
+/// assert_eq!(FlexColor::parse("#1DB44680").unwrap().as_str(), "#1DB44680");
+/// assert_eq!(FlexColor::parse("#GGG"), Err(ColorError::InvalidLength(4)));
+/// assert_eq!(FlexColor::parse("1DB446"), Err(ColorError::MissingHash));
+/// assert_eq!(FlexColor::parse("#1DB44G"), Err(ColorError::InvalidDigit('G')));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlexColor(String);
+
+impl FlexColor {
+    /// Checks that `value` is `#` followed by six or eight hex digits.
+    pub fn parse(value: &str) -> Result<FlexColor, ColorError> {
+        let digits = value.strip_prefix('#').ok_or(ColorError::MissingHash)?;
+        if digits.len() != 6 && digits.len() != 8 {
+            return Err(ColorError::InvalidLength(value.chars().count()));
+        }
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorError::InvalidDigit(c));
+        }
+        Ok(FlexColor(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for FlexColor {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FlexColor::parse(s)
+    }
+}
+
+impl fmt::Display for FlexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<FlexColor> for String {
+    fn from(color: FlexColor) -> Self {
+        color.0
+    }
+}
+
+/// Why a string isn't a valid [`FlexColor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    /// The value doesn't start with `#`.
+    MissingHash,
+    /// The value isn't 7 or 9 characters long, including the `#`.
+    InvalidLength(usize),
+    /// The value contains a character that isn't a hex digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::MissingHash => write!(f, "color must start with `#`"),
+            ColorError::InvalidLength(length) => write!(
+                f,
+                "color must be `#RRGGBB` or `#RRGGBBAA`, got {} characters",
+                length
+            ),
+            ColorError::InvalidDigit(c) => write!(f, "`{}` is not a hex digit", c),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
@@ -10,11 +10,12 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// FlexComponent enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FlexComponent {
     FlexBox(Box<models::FlexBox>),
@@ -28,6 +29,32 @@ pub enum FlexComponent {
     FlexFiller(Box<models::FlexFiller>),
 }
 
+impl<'de> Deserialize<'de> for FlexComponent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "box" => FlexComponent::FlexBox(Box::new(from_value(value)?)),
+            "button" => FlexComponent::FlexButton(Box::new(from_value(value)?)),
+            "image" => FlexComponent::FlexImage(Box::new(from_value(value)?)),
+            "video" => FlexComponent::FlexVideo(Box::new(from_value(value)?)),
+            "icon" => FlexComponent::FlexIcon(Box::new(from_value(value)?)),
+            "text" => FlexComponent::FlexText(Box::new(from_value(value)?)),
+            "span" => FlexComponent::FlexSpan(Box::new(from_value(value)?)),
+            "separator" => FlexComponent::FlexSeparator(Box::new(from_value(value)?)),
+            "filler" => FlexComponent::FlexFiller(Box::new(from_value(value)?)),
+            other => {
+                return Err(D::Error::custom(format!(
+                    "unknown flex component type `{other}`"
+                )))
+            }
+        })
+    }
+}
+
 impl Default for FlexComponent {
     fn default() -> Self {
         Self::FlexBox(Box::new(Default::default()))
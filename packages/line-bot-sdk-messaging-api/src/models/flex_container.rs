@@ -10,17 +10,37 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// FlexContainer enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FlexContainer {
     FlexBubble(Box<models::FlexBubble>),
     FlexCarousel(Box<models::FlexCarousel>),
 }
 
+impl<'de> Deserialize<'de> for FlexContainer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "bubble" => FlexContainer::FlexBubble(Box::new(from_value(value)?)),
+            "carousel" => FlexContainer::FlexCarousel(Box::new(from_value(value)?)),
+            other => {
+                return Err(D::Error::custom(format!(
+                    "unknown flex container type `{other}`"
+                )))
+            }
+        })
+    }
+}
+
 impl Default for FlexContainer {
     fn default() -> Self {
         Self::FlexBubble(Box::new(Default::default()))
@@ -39,3 +59,144 @@ impl From<models::FlexCarousel> for FlexContainer {
         FlexContainer::FlexCarousel(Box::new(value))
     }
 }
+
+impl FlexContainer {
+    /// Maximum number of bubbles in a carousel.
+    pub const MAX_CAROUSEL_BUBBLES: usize = 12;
+    /// Maximum nesting depth of boxes within a bubble section.
+    pub const MAX_BOX_DEPTH: usize = 10;
+    /// Maximum number of components in a container.
+    pub const MAX_COMPONENTS: usize = 500;
+
+    /// Parses a container exported from the Flex Message Simulator.
+    ///
+    /// Properties this SDK doesn't model are ignored rather than rejected.
+    pub fn from_simulator_json(json: &str) -> Result<FlexContainer, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Pretty-printed JSON that can be pasted into the Flex Message Simulator.
+    pub fn to_simulator_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Walks the component tree and checks it against the structural limits
+    /// LINE enforces: bubble count, box nesting depth, total component count
+    /// the components allowed inside baseline boxes, the bubble sizes that
+    /// may have a video hero and texts setting both `text` and spans.
+    ///
+    /// Errors name the offending component by path, e.g. `body > box[2] > box[0]`.
+    pub fn validate_structure(&self) -> Result<(), models::FlexValidationError> {
+        let mut count = 0;
+        match self {
+            FlexContainer::FlexBubble(bubble) => validate_bubble(bubble, "", &mut count)?,
+            FlexContainer::FlexCarousel(carousel) => {
+                carousel.validate()?;
+                for (i, bubble) in carousel.contents.iter().enumerate() {
+                    validate_bubble(bubble, &format!("bubble[{}] > ", i), &mut count)?;
+                }
+            }
+        }
+        if count > Self::MAX_COMPONENTS {
+            return Err(models::FlexValidationError::TooManyComponents {
+                max: Self::MAX_COMPONENTS,
+                actual: count,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn validate_bubble(
+    bubble: &models::FlexBubble,
+    prefix: &str,
+    count: &mut usize,
+) -> Result<(), models::FlexValidationError> {
+    let sections = [
+        ("header", bubble.header.as_deref()),
+        ("body", bubble.body.as_deref()),
+        ("footer", bubble.footer.as_deref()),
+    ];
+    if let Some(hero) = bubble.hero.as_deref() {
+        if matches!(hero, models::FlexComponent::FlexVideo(_))
+            && matches!(
+                bubble.size,
+                Some(models::flex_bubble::Size::Nano | models::flex_bubble::Size::Micro)
+            )
+        {
+            return Err(models::FlexValidationError::VideoBubbleTooSmall {
+                path: format!("{}hero", prefix),
+            });
+        }
+        validate_component(hero, format!("{}hero", prefix), 0, count)?;
+    }
+    for (name, section) in sections {
+        if let Some(section) = section {
+            validate_box(section, format!("{}{}", prefix, name), 1, count)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_component(
+    component: &models::FlexComponent,
+    path: String,
+    depth: usize,
+    count: &mut usize,
+) -> Result<(), models::FlexValidationError> {
+    match component {
+        models::FlexComponent::FlexBox(flex_box) => validate_box(flex_box, path, depth + 1, count),
+        models::FlexComponent::FlexText(text) if text.text.is_some() && text.contents.is_some() => {
+            Err(models::FlexValidationError::TextWithSpans { path })
+        }
+        _ => {
+            *count += 1;
+            Ok(())
+        }
+    }
+}
+
+fn validate_box(
+    flex_box: &models::FlexBox,
+    path: String,
+    depth: usize,
+    count: &mut usize,
+) -> Result<(), models::FlexValidationError> {
+    *count += 1;
+    if depth > FlexContainer::MAX_BOX_DEPTH {
+        return Err(models::FlexValidationError::BoxTooDeep {
+            path,
+            max: FlexContainer::MAX_BOX_DEPTH,
+        });
+    }
+    for (i, child) in flex_box.contents.iter().enumerate() {
+        let child_path = format!("{} > {}[{}]", path, component_kind(child), i);
+        if flex_box.layout == models::flex_box::Layout::Baseline
+            && !matches!(
+                child,
+                models::FlexComponent::FlexIcon(_)
+                    | models::FlexComponent::FlexText(_)
+                    | models::FlexComponent::FlexSpan(_)
+                    | models::FlexComponent::FlexFiller(_)
+            )
+        {
+            return Err(models::FlexValidationError::InvalidBaselineContent { path: child_path });
+        }
+        validate_component(child, child_path, depth, count)?;
+    }
+    Ok(())
+}
+
+fn component_kind(component: &models::FlexComponent) -> &'static str {
+    match component {
+        models::FlexComponent::FlexBox(_) => "box",
+        models::FlexComponent::FlexButton(_) => "button",
+        models::FlexComponent::FlexImage(_) => "image",
+        models::FlexComponent::FlexVideo(_) => "video",
+        models::FlexComponent::FlexIcon(_) => "icon",
+        models::FlexComponent::FlexText(_) => "text",
+        models::FlexComponent::FlexSpan(_) => "span",
+        models::FlexComponent::FlexSeparator(_) => "separator",
+        models::FlexComponent::FlexFiller(_) => "filler",
+    }
+}
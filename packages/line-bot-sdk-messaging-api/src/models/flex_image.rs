@@ -86,6 +86,12 @@ impl FlexImage {
             animated: None,
         }
     }
+
+    /// Sets the color of the image background.
+    pub fn with_background_color(mut self, background_color: models::FlexColor) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
 }
 /// Reference for offsetTop, offsetBottom, offsetStart, and offsetEnd. Specify one of the following values:  `relative`: Use the previous box as reference. `absolute`: Use the top left of parent element as reference. The default value is relative.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
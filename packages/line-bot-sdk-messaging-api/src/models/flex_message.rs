@@ -36,4 +36,28 @@ impl FlexMessage {
             contents: Box::new(contents),
         }
     }
+
+    /// Maximum length of `alt_text`, in characters.
+    pub const MAX_ALT_TEXT_LENGTH: usize = 400;
+
+    /// Checks that `alt_text` is non-empty and at most [`Self::MAX_ALT_TEXT_LENGTH`] characters.
+    pub fn validate(&self) -> Result<(), models::FlexValidationError> {
+        let length = self.alt_text.chars().count();
+        if length == 0 {
+            return Err(models::FlexValidationError::EmptyAltText);
+        }
+        if length > Self::MAX_ALT_TEXT_LENGTH {
+            return Err(models::FlexValidationError::AltTextTooLong {
+                max: Self::MAX_ALT_TEXT_LENGTH,
+                actual: length,
+            });
+        }
+        Ok(())
+    }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
@@ -29,4 +29,10 @@ impl FlexSeparator {
             color: None,
         }
     }
+
+    /// Sets the color of the separator line.
+    pub fn with_color(mut self, color: models::FlexColor) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
@@ -41,6 +41,12 @@ impl FlexSpan {
             decoration: None,
         }
     }
+
+    /// Sets the color of the span.
+    pub fn with_color(mut self, color: models::FlexColor) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -89,6 +89,49 @@ impl FlexText {
             scaling: None,
         }
     }
+
+    /// Replaces the text with `spans`, each styled on its own.
+    ///
+    /// LINE ignores `text` once `contents` is set, so it is cleared here and
+    /// [`FlexContainer::validate_structure`](models::FlexContainer::validate_structure)
+    /// rejects a text with both.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{FlexColor, FlexSpan, FlexText};
+    ///
+    /// let span = |text: &str, color: &str| {
+    ///     FlexSpan {
+    ///         text: Some(text.to_string()),
+    ///         ..FlexSpan::new("span".to_string())
+    ///     }
+    ///     .with_color(FlexColor::parse(color).unwrap())
+    /// };
+    /// let text = FlexText::new("text".to_string())
+    ///     .with_spans(vec![span("red ", "#FF0000"), span("blue", "#0000FF")]);
+    ///
+    /// let json = serde_json::to_value(&text).unwrap();
+    /// assert!(json.get("text").is_none());
+    /// assert_eq!(
+    ///     json["contents"],
+    ///     serde_json::json!([
+    ///         {"type": "span", "text": "red ", "color": "#FF0000"},
+    ///         {"type": "span", "text": "blue", "color": "#0000FF"},
+    ///     ])
+    /// );
+    /// ```
+    pub fn with_spans(mut self, spans: Vec<models::FlexSpan>) -> Self {
+        self.text = None;
+        self.contents = Some(spans);
+        self
+    }
+
+    /// Sets the color of the text.
+    pub fn with_color(mut self, color: models::FlexColor) -> Self {
+        self.color = Some(color.into());
+        self
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -0,0 +1,74 @@
+//! Client-side checks for the limits LINE places on flex messages.
+
+use std::fmt;
+
+/// A flex message that the LINE Platform would reject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlexValidationError {
+    /// `altText` is empty.
+    EmptyAltText,
+    /// `altText` is longer than LINE allows.
+    AltTextTooLong { max: usize, actual: usize },
+    /// A carousel has no bubbles.
+    EmptyCarousel,
+    /// A carousel has more bubbles than LINE allows.
+    TooManyBubbles { max: usize, actual: usize },
+    /// A container has more components than LINE allows.
+    TooManyComponents { max: usize, actual: usize },
+    /// The box at `path` is nested deeper than LINE allows.
+    BoxTooDeep { path: String, max: usize },
+    /// The component at `path` isn't allowed inside a baseline box.
+    InvalidBaselineContent { path: String },
+    /// The video at `path` is the hero of a `nano` or `micro` bubble.
+    VideoBubbleTooSmall { path: String },
+    /// The text at `path` has both `text` and span `contents`.
+    TextWithSpans { path: String },
+}
+
+impl fmt::Display for FlexValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlexValidationError::EmptyAltText => write!(f, "`altText` must not be empty"),
+            FlexValidationError::AltTextTooLong { max, actual } => write!(
+                f,
+                "`altText` must be at most {} characters, got {}",
+                max, actual
+            ),
+            FlexValidationError::EmptyCarousel => {
+                write!(f, "a carousel must contain at least one bubble")
+            }
+            FlexValidationError::TooManyBubbles { max, actual } => write!(
+                f,
+                "a carousel may contain at most {} bubbles, got {}",
+                max, actual
+            ),
+            FlexValidationError::TooManyComponents { max, actual } => write!(
+                f,
+                "a flex container may contain at most {} components, got {}",
+                max, actual
+            ),
+            FlexValidationError::BoxTooDeep { path, max } => write!(
+                f,
+                "box at `{}` is nested more than {} levels deep",
+                path, max
+            ),
+            FlexValidationError::InvalidBaselineContent { path } => write!(
+                f,
+                "baseline boxes may only contain icon, text, span and filler components, found `{}`",
+                path
+            ),
+            FlexValidationError::VideoBubbleTooSmall { path } => write!(
+                f,
+                "video at `{}` is not supported in nano or micro bubbles",
+                path
+            ),
+            FlexValidationError::TextWithSpans { path } => write!(
+                f,
+                "text at `{}` must not set both `text` and `contents`",
+                path
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FlexValidationError {}
@@ -28,9 +28,31 @@ pub struct FlexVideo {
 }
 
 impl FlexVideo {
+    /// Creates a `video` component, which LINE only accepts as the hero of a
+    /// bubble; `nano` and `micro` bubbles can't show videos.
+    ///
+    /// `alt_content` is shown instead on clients that can't play the video.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{FlexBubble, FlexContainer, FlexImage, FlexVideo};
+    ///
+    /// let video = FlexVideo::new(
+    ///     "https://example.com/video.mp4".to_string(),
+    ///     "https://example.com/preview.png".to_string(),
+    ///     FlexImage::new("image".to_string(), "https://example.com/preview.png".to_string()).into(),
+    /// );
+    /// let bubble: FlexContainer = FlexBubble::builder().hero(video).build().into();
+    /// assert!(bubble.validate_structure().is_ok());
+    ///
+    /// let json = serde_json::to_value(&bubble).unwrap();
+    /// assert_eq!(json["hero"]["type"], "video");
+    /// assert_eq!(json["hero"]["altContent"]["type"], "image");
+    /// ```
     pub fn new(url: String, preview_url: String, alt_content: models::FlexComponent) -> FlexVideo {
         FlexVideo {
-            r#type: None,
+            r#type: Some("video".to_string()),
             url,
             preview_url,
             alt_content: Box::new(alt_content),
@@ -0,0 +1,187 @@
+//! Walking the component tree of a flex container.
+
+use crate::models::{self, FlexComponent, FlexContainer};
+
+/// Callbacks for each kind of flex component, called by [`FlexContainer::visit`].
+///
+/// Every method defaults to doing nothing. Components are visited in document
+/// order: a bubble's header, hero, body and footer, a box before its contents,
+/// a text before its spans, and a video before its alternative content.
+pub trait FlexVisitor {
+    fn visit_bubble(&mut self, _bubble: &models::FlexBubble) {}
+    fn visit_box(&mut self, _flex_box: &models::FlexBox) {}
+    fn visit_button(&mut self, _button: &models::FlexButton) {}
+    fn visit_image(&mut self, _image: &models::FlexImage) {}
+    fn visit_video(&mut self, _video: &models::FlexVideo) {}
+    fn visit_icon(&mut self, _icon: &models::FlexIcon) {}
+    fn visit_text(&mut self, _text: &models::FlexText) {}
+    fn visit_span(&mut self, _span: &models::FlexSpan) {}
+    fn visit_separator(&mut self, _separator: &models::FlexSeparator) {}
+    fn visit_filler(&mut self, _filler: &models::FlexFiller) {}
+}
+
+/// Like [`FlexVisitor`], but with mutable access for in-place edits, called by
+/// [`FlexContainer::visit_mut`].
+///
+/// Children are visited after their parent's callback returns, so changes made
+/// to a box's `contents` are reflected in what gets visited next.
+pub trait FlexVisitorMut {
+    fn visit_bubble(&mut self, _bubble: &mut models::FlexBubble) {}
+    fn visit_box(&mut self, _flex_box: &mut models::FlexBox) {}
+    fn visit_button(&mut self, _button: &mut models::FlexButton) {}
+    fn visit_image(&mut self, _image: &mut models::FlexImage) {}
+    fn visit_video(&mut self, _video: &mut models::FlexVideo) {}
+    fn visit_icon(&mut self, _icon: &mut models::FlexIcon) {}
+    fn visit_text(&mut self, _text: &mut models::FlexText) {}
+    fn visit_span(&mut self, _span: &mut models::FlexSpan) {}
+    fn visit_separator(&mut self, _separator: &mut models::FlexSeparator) {}
+    fn visit_filler(&mut self, _filler: &mut models::FlexFiller) {}
+}
+
+/// Collects the URLs of every image and icon in a container.
+///
+/// ```
+/// use line_bot_sdk_messaging_api::models::{
+///     FlexBubble, FlexContainer, FlexImage, ImageUrlCollector,
+/// };
+///
+/// let mut bubble = FlexBubble::new("bubble".to_string());
+/// bubble.hero = Some(Box::new(
+///     FlexImage::new("image".to_string(), "https://example.com/hero.png".to_string()).into(),
+/// ));
+/// let container: FlexContainer = bubble.into();
+///
+/// let mut collector = ImageUrlCollector::default();
+/// container.visit(&mut collector);
+/// assert_eq!(collector.urls, ["https://example.com/hero.png"]);
+/// ```
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ImageUrlCollector {
+    pub urls: Vec<String>,
+}
+
+impl FlexVisitor for ImageUrlCollector {
+    fn visit_image(&mut self, image: &models::FlexImage) {
+        self.urls.push(image.url.clone());
+    }
+
+    fn visit_icon(&mut self, icon: &models::FlexIcon) {
+        self.urls.push(icon.url.clone());
+    }
+}
+
+impl FlexContainer {
+    /// Calls `visitor` for every bubble and component in the container.
+    pub fn visit<V: FlexVisitor>(&self, visitor: &mut V) {
+        match self {
+            FlexContainer::FlexBubble(bubble) => visit_bubble(bubble, visitor),
+            FlexContainer::FlexCarousel(carousel) => {
+                for bubble in &carousel.contents {
+                    visit_bubble(bubble, visitor);
+                }
+            }
+        }
+    }
+
+    /// Calls `visitor` with mutable access to every bubble and component in the container.
+    pub fn visit_mut<V: FlexVisitorMut>(&mut self, visitor: &mut V) {
+        match self {
+            FlexContainer::FlexBubble(bubble) => visit_bubble_mut(bubble, visitor),
+            FlexContainer::FlexCarousel(carousel) => {
+                for bubble in &mut carousel.contents {
+                    visit_bubble_mut(bubble, visitor);
+                }
+            }
+        }
+    }
+}
+
+fn visit_bubble<V: FlexVisitor>(bubble: &models::FlexBubble, visitor: &mut V) {
+    visitor.visit_bubble(bubble);
+    if let Some(header) = bubble.header.as_deref() {
+        visit_box(header, visitor);
+    }
+    if let Some(hero) = bubble.hero.as_deref() {
+        visit_component(hero, visitor);
+    }
+    if let Some(body) = bubble.body.as_deref() {
+        visit_box(body, visitor);
+    }
+    if let Some(footer) = bubble.footer.as_deref() {
+        visit_box(footer, visitor);
+    }
+}
+
+fn visit_box<V: FlexVisitor>(flex_box: &models::FlexBox, visitor: &mut V) {
+    visitor.visit_box(flex_box);
+    for child in &flex_box.contents {
+        visit_component(child, visitor);
+    }
+}
+
+fn visit_component<V: FlexVisitor>(component: &FlexComponent, visitor: &mut V) {
+    match component {
+        FlexComponent::FlexBox(flex_box) => visit_box(flex_box, visitor),
+        FlexComponent::FlexButton(button) => visitor.visit_button(button),
+        FlexComponent::FlexImage(image) => visitor.visit_image(image),
+        FlexComponent::FlexVideo(video) => {
+            visitor.visit_video(video);
+            visit_component(&video.alt_content, visitor);
+        }
+        FlexComponent::FlexIcon(icon) => visitor.visit_icon(icon),
+        FlexComponent::FlexText(text) => {
+            visitor.visit_text(text);
+            for span in text.contents.iter().flatten() {
+                visitor.visit_span(span);
+            }
+        }
+        FlexComponent::FlexSpan(span) => visitor.visit_span(span),
+        FlexComponent::FlexSeparator(separator) => visitor.visit_separator(separator),
+        FlexComponent::FlexFiller(filler) => visitor.visit_filler(filler),
+    }
+}
+
+fn visit_bubble_mut<V: FlexVisitorMut>(bubble: &mut models::FlexBubble, visitor: &mut V) {
+    visitor.visit_bubble(bubble);
+    if let Some(header) = bubble.header.as_deref_mut() {
+        visit_box_mut(header, visitor);
+    }
+    if let Some(hero) = bubble.hero.as_deref_mut() {
+        visit_component_mut(hero, visitor);
+    }
+    if let Some(body) = bubble.body.as_deref_mut() {
+        visit_box_mut(body, visitor);
+    }
+    if let Some(footer) = bubble.footer.as_deref_mut() {
+        visit_box_mut(footer, visitor);
+    }
+}
+
+fn visit_box_mut<V: FlexVisitorMut>(flex_box: &mut models::FlexBox, visitor: &mut V) {
+    visitor.visit_box(flex_box);
+    for child in &mut flex_box.contents {
+        visit_component_mut(child, visitor);
+    }
+}
+
+fn visit_component_mut<V: FlexVisitorMut>(component: &mut FlexComponent, visitor: &mut V) {
+    match component {
+        FlexComponent::FlexBox(flex_box) => visit_box_mut(flex_box, visitor),
+        FlexComponent::FlexButton(button) => visitor.visit_button(button),
+        FlexComponent::FlexImage(image) => visitor.visit_image(image),
+        FlexComponent::FlexVideo(video) => {
+            visitor.visit_video(video);
+            visit_component_mut(&mut video.alt_content, visitor);
+        }
+        FlexComponent::FlexIcon(icon) => visitor.visit_icon(icon),
+        FlexComponent::FlexText(text) => {
+            visitor.visit_text(text);
+            for span in text.contents.iter_mut().flatten() {
+                visitor.visit_span(span);
+            }
+        }
+        FlexComponent::FlexSpan(span) => visitor.visit_span(span),
+        FlexComponent::FlexSeparator(separator) => visitor.visit_separator(separator),
+        FlexComponent::FlexFiller(filler) => visitor.visit_filler(filler),
+    }
+}
@@ -22,27 +22,43 @@ pub enum ImagemapAction {
     ClipboardImagemapAction(Box<models::ClipboardImagemapAction>),
 }
 
+impl ImagemapAction {
+    /// The tappable area of the action.
+    pub fn area(&self) -> Option<&models::ImagemapArea> {
+        match self {
+            ImagemapAction::MessageImagemapAction(action) => action.area.as_deref(),
+            ImagemapAction::UriImagemapAction(action) => action.area.as_deref(),
+            ImagemapAction::ClipboardImagemapAction(action) => action.area.as_deref(),
+        }
+    }
+}
+
 impl Default for ImagemapAction {
     fn default() -> Self {
         Self::MessageImagemapAction(Box::new(Default::default()))
     }
 }
 
-// Conversion methods from struct types to enum variants
+// Conversion methods from struct types to enum variants.
+// The enum is untagged, so each conversion fills in the `type` discriminator
+// when the struct was built without one.
 impl From<models::MessageImagemapAction> for ImagemapAction {
-    fn from(value: models::MessageImagemapAction) -> Self {
+    fn from(mut value: models::MessageImagemapAction) -> Self {
+        value.r#type.get_or_insert_with(|| "message".to_string());
         ImagemapAction::MessageImagemapAction(Box::new(value))
     }
 }
 
 impl From<models::UriImagemapAction> for ImagemapAction {
-    fn from(value: models::UriImagemapAction) -> Self {
+    fn from(mut value: models::UriImagemapAction) -> Self {
+        value.r#type.get_or_insert_with(|| "uri".to_string());
         ImagemapAction::UriImagemapAction(Box::new(value))
     }
 }
 
 impl From<models::ClipboardImagemapAction> for ImagemapAction {
-    fn from(value: models::ClipboardImagemapAction) -> Self {
+    fn from(mut value: models::ClipboardImagemapAction) -> Self {
+        value.r#type.get_or_insert_with(|| "clipboard".to_string());
         ImagemapAction::ClipboardImagemapAction(Box::new(value))
     }
 }
@@ -50,4 +50,121 @@ impl ImagemapMessage {
             video: None,
         }
     }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
+
+    /// Returns an empty [`ImagemapMessageBuilder`].
+    pub fn builder() -> ImagemapMessageBuilder {
+        ImagemapMessageBuilder::default()
+    }
+
+    /// Width of the base image, in pixels, that LINE requires.
+    pub const BASE_WIDTH: i32 = 1040;
+    /// Maximum number of actions in an imagemap message.
+    pub const MAX_ACTIONS: usize = 50;
+
+    /// Checks that `base_size` is [`Self::BASE_WIDTH`] wide with a positive
+    /// height, that there are 1 to [`Self::MAX_ACTIONS`] actions, and that
+    /// every action area lies within `base_size`.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        let base = &self.base_size;
+        if base.width != Self::BASE_WIDTH || base.height <= 0 {
+            return Err(models::ValidationError::InvalidImagemapBaseSize {
+                width: base.width,
+                height: base.height,
+            });
+        }
+        models::validation_error::validate_action_count(&self.actions, 1, Self::MAX_ACTIONS)?;
+        for (index, action) in self.actions.iter().enumerate() {
+            if let Some(area) = action.area() {
+                if area.x < 0
+                    || area.y < 0
+                    || area.width <= 0
+                    || area.height <= 0
+                    || area.x + area.width > base.width
+                    || area.y + area.height > base.height
+                {
+                    return Err(models::ValidationError::ImagemapAreaOutOfBounds { index });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`ImagemapMessage`] that fills in the `imagemap` type.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ImagemapMessageBuilder {
+    message: ImagemapMessage,
+}
+
+impl ImagemapMessageBuilder {
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.message.base_url = base_url.into();
+        self
+    }
+
+    pub fn alt_text(mut self, alt_text: impl Into<String>) -> Self {
+        self.message.alt_text = alt_text.into();
+        self
+    }
+
+    pub fn base_size(mut self, width: i32, height: i32) -> Self {
+        self.message.base_size = Box::new(models::ImagemapBaseSize { height, width });
+        self
+    }
+
+    /// Adds an area that opens `link_uri` when tapped.
+    pub fn uri_area(
+        self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        link_uri: impl Into<String>,
+    ) -> Self {
+        self.action(models::UriImagemapAction {
+            area: Some(Box::new(models::ImagemapArea::new(x, y, width, height))),
+            ..models::UriImagemapAction::new(link_uri.into())
+        })
+    }
+
+    /// Adds an area that sends `text` as a message from the user when tapped.
+    pub fn message_area(
+        self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        text: impl Into<String>,
+    ) -> Self {
+        self.action(models::MessageImagemapAction {
+            area: Some(Box::new(models::ImagemapArea::new(x, y, width, height))),
+            ..models::MessageImagemapAction::new(text.into())
+        })
+    }
+
+    pub fn action(mut self, action: impl Into<models::ImagemapAction>) -> Self {
+        self.message.actions.push(action.into());
+        self
+    }
+
+    pub fn video(mut self, video: models::ImagemapVideo) -> Self {
+        self.message.video = Some(Box::new(video));
+        self
+    }
+
+    /// Builds the message, checking it with [`ImagemapMessage::validate`].
+    pub fn build(self) -> Result<ImagemapMessage, models::ValidationError> {
+        let message = ImagemapMessage {
+            r#type: Some("imagemap".to_string()),
+            ..self.message
+        };
+        message.validate()?;
+        Ok(message)
+    }
 }
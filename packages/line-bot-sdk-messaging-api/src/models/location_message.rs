@@ -31,15 +31,68 @@ pub struct LocationMessage {
 }
 
 impl LocationMessage {
-    pub fn new(title: String, address: String, latitude: f64, longitude: f64) -> LocationMessage {
+    pub fn new(
+        title: impl Into<String>,
+        address: impl Into<String>,
+        latitude: f64,
+        longitude: f64,
+    ) -> LocationMessage {
         LocationMessage {
-            r#type: None,
+            r#type: Some("location".to_string()),
             quick_reply: None,
             sender: None,
-            title,
-            address,
+            title: title.into(),
+            address: address.into(),
             latitude,
             longitude,
         }
     }
+
+    /// Creates a location message and checks it with [`LocationMessage::validate`].
+    pub fn try_new(
+        title: impl Into<String>,
+        address: impl Into<String>,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<LocationMessage, models::ValidationError> {
+        let message = LocationMessage::new(title, address, latitude, longitude);
+        message.validate()?;
+        Ok(message)
+    }
+
+    /// Maximum length of `title`, in characters.
+    pub const MAX_TITLE_LENGTH: usize = 100;
+    /// Maximum length of `address`, in characters.
+    pub const MAX_ADDRESS_LENGTH: usize = 100;
+
+    /// Checks that `title` and `address` fit LINE's length limits, `latitude`
+    /// is within `[-90, 90]` and `longitude` within `[-180, 180]`.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        for (field, value, max) in [
+            ("title", &self.title, Self::MAX_TITLE_LENGTH),
+            ("address", &self.address, Self::MAX_ADDRESS_LENGTH),
+        ] {
+            let length = value.chars().count();
+            if length > max {
+                return Err(models::ValidationError::TextTooLong {
+                    field,
+                    max,
+                    actual: length,
+                });
+            }
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) || !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(models::ValidationError::CoordinatesOutOfRange {
+                latitude: self.latitude,
+                longitude: self.longitude,
+            });
+        }
+        Ok(())
+    }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
@@ -30,75 +30,122 @@ pub enum Message {
     CouponMessage(Box<models::CouponMessage>),
 }
 
+impl Message {
+    /// Runs the client-side checks available for this kind of message.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if let Some(sender) = self.sender() {
+            sender.validate()?;
+        }
+        match self {
+            Message::FlexMessage(message) => Ok(message.validate()?),
+            Message::LocationMessage(message) => message.validate(),
+            Message::TemplateMessage(message) => message.template.validate(),
+            Message::ImagemapMessage(message) => message.validate(),
+            Message::AudioMessage(message) => message.validate(),
+            Message::VideoMessage(message) => message.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    fn sender(&self) -> Option<&models::Sender> {
+        match self {
+            Message::TextMessage(message) => message.sender.as_deref(),
+            Message::TextMessageV2(message) => message.sender.as_deref(),
+            Message::StickerMessage(message) => message.sender.as_deref(),
+            Message::ImageMessage(message) => message.sender.as_deref(),
+            Message::VideoMessage(message) => message.sender.as_deref(),
+            Message::AudioMessage(message) => message.sender.as_deref(),
+            Message::LocationMessage(message) => message.sender.as_deref(),
+            Message::ImagemapMessage(message) => message.sender.as_deref(),
+            Message::TemplateMessage(message) => message.sender.as_deref(),
+            Message::FlexMessage(message) => message.sender.as_deref(),
+            Message::CouponMessage(message) => message.sender.as_deref(),
+        }
+    }
+}
+
 impl Default for Message {
     fn default() -> Self {
         Self::TextMessage(Box::new(Default::default()))
     }
 }
 
-// Conversion methods from struct types to enum variants
+// Conversion methods from struct types to enum variants.
+// The enum is untagged, so each conversion fills in the `type` discriminator
+// when the struct was built without one.
 impl From<models::TextMessage> for Message {
-    fn from(value: models::TextMessage) -> Self {
+    fn from(mut value: models::TextMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "text".to_string());
         Message::TextMessage(Box::new(value))
     }
 }
 
 impl From<models::TextMessageV2> for Message {
-    fn from(value: models::TextMessageV2) -> Self {
+    fn from(mut value: models::TextMessageV2) -> Self {
+        value.r#type.get_or_insert_with(|| "textV2".to_string());
         Message::TextMessageV2(Box::new(value))
     }
 }
 
 impl From<models::StickerMessage> for Message {
-    fn from(value: models::StickerMessage) -> Self {
+    fn from(mut value: models::StickerMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "sticker".to_string());
         Message::StickerMessage(Box::new(value))
     }
 }
 
 impl From<models::ImageMessage> for Message {
-    fn from(value: models::ImageMessage) -> Self {
+    fn from(mut value: models::ImageMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "image".to_string());
         Message::ImageMessage(Box::new(value))
     }
 }
 
 impl From<models::VideoMessage> for Message {
-    fn from(value: models::VideoMessage) -> Self {
+    fn from(mut value: models::VideoMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "video".to_string());
         Message::VideoMessage(Box::new(value))
     }
 }
 
 impl From<models::AudioMessage> for Message {
-    fn from(value: models::AudioMessage) -> Self {
+    fn from(mut value: models::AudioMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "audio".to_string());
         Message::AudioMessage(Box::new(value))
     }
 }
 
 impl From<models::LocationMessage> for Message {
-    fn from(value: models::LocationMessage) -> Self {
+    fn from(mut value: models::LocationMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "location".to_string());
         Message::LocationMessage(Box::new(value))
     }
 }
 
 impl From<models::ImagemapMessage> for Message {
-    fn from(value: models::ImagemapMessage) -> Self {
+    fn from(mut value: models::ImagemapMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "imagemap".to_string());
         Message::ImagemapMessage(Box::new(value))
     }
 }
 
 impl From<models::TemplateMessage> for Message {
-    fn from(value: models::TemplateMessage) -> Self {
+    fn from(mut value: models::TemplateMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "template".to_string());
         Message::TemplateMessage(Box::new(value))
     }
 }
 
 impl From<models::FlexMessage> for Message {
-    fn from(value: models::FlexMessage) -> Self {
+    fn from(mut value: models::FlexMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "flex".to_string());
         Message::FlexMessage(Box::new(value))
     }
 }
 
 impl From<models::CouponMessage> for Message {
-    fn from(value: models::CouponMessage) -> Self {
+    fn from(mut value: models::CouponMessage) -> Self {
+        value.r#type.get_or_insert_with(|| "coupon".to_string());
         Message::CouponMessage(Box::new(value))
     }
 }
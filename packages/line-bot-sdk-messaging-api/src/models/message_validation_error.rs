@@ -0,0 +1,106 @@
+//! The `400 Bad Request` body returned by the message validation endpoints.
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Why a `validate_*` endpoint rejected the messages of a request.
+///
+/// Deserializes from LINE's `{ message, details }` error body, and locates
+/// each rejected property within the `messages` of the request.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_messaging_api::models::MessageValidationError;
+///
+/// let error: MessageValidationError = serde_json::from_str(
+///     r#"{
+///         "message": "The request body has 1 error(s)",
+///         "details": [{"message": "must be specified", "property": "messages[1].text"}]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(error.details[0].message_index, Some(1));
+/// assert_eq!(error.details[0].property.as_deref(), Some("messages[1].text"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "models::ErrorResponse", into = "models::ErrorResponse")]
+pub struct MessageValidationError {
+    /// Summary of the errors, e.g. `The request body has 2 error(s)`.
+    pub message: String,
+    /// One entry per rejected property.
+    pub details: Vec<InvalidMessageProperty>,
+}
+
+/// A property of a message rejected by a `validate_*` endpoint.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InvalidMessageProperty {
+    /// Index of the rejected message within `messages`, when `property` names one.
+    pub message_index: Option<usize>,
+    /// JSON path of the rejected property, e.g. `messages[0].contents.body.contents[0].text`.
+    pub property: Option<String>,
+    /// Why the property was rejected.
+    pub message: Option<String>,
+}
+
+impl From<models::ErrorResponse> for MessageValidationError {
+    fn from(body: models::ErrorResponse) -> Self {
+        let details = body
+            .details
+            .unwrap_or_default()
+            .into_iter()
+            .map(|detail| InvalidMessageProperty {
+                message_index: detail.property.as_deref().and_then(message_index),
+                property: detail.property,
+                message: detail.message,
+            })
+            .collect();
+        MessageValidationError {
+            message: body.message,
+            details,
+        }
+    }
+}
+
+impl From<MessageValidationError> for models::ErrorResponse {
+    fn from(error: MessageValidationError) -> Self {
+        let details = error
+            .details
+            .into_iter()
+            .map(|detail| models::ErrorDetail {
+                message: detail.message,
+                property: detail.property,
+            })
+            .collect::<Vec<_>>();
+        models::ErrorResponse {
+            details: (!details.is_empty()).then_some(details),
+            ..models::ErrorResponse::new(error.message)
+        }
+    }
+}
+
+/// Parses the `N` of a property path starting with `messages[N]`.
+fn message_index(property: &str) -> Option<usize> {
+    let rest = property.strip_prefix("messages[")?;
+    let end = rest.find(']')?;
+    rest[..end].parse().ok()
+}
+
+impl fmt::Display for MessageValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for detail in &self.details {
+            write!(
+                f,
+                "; {}: {}",
+                detail.property.as_deref().unwrap_or("<unknown property>"),
+                detail.message.as_deref().unwrap_or("invalid")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MessageValidationError {}
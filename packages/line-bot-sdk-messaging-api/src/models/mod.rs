@@ -148,6 +148,8 @@ pub mod flex_button;
 pub use self::flex_button::FlexButton;
 pub mod flex_carousel;
 pub use self::flex_carousel::FlexCarousel;
+pub mod flex_color;
+pub use self::flex_color::{ColorError, FlexColor};
 pub mod flex_component;
 pub use self::flex_component::FlexComponent;
 pub mod flex_container;
@@ -176,10 +178,14 @@ pub mod flex_span_size;
 pub use self::flex_span_size::FlexSpanSize;
 pub mod flex_text;
 pub use self::flex_text::FlexText;
+pub mod flex_validation_error;
+pub use self::flex_validation_error::FlexValidationError;
 pub mod flex_text_font_size;
 pub use self::flex_text_font_size::FlexTextFontSize;
 pub mod flex_video;
 pub use self::flex_video::FlexVideo;
+pub mod flex_visitor;
+pub use self::flex_visitor::{FlexVisitor, FlexVisitorMut, ImageUrlCollector};
 pub mod gender_demographic;
 pub use self::gender_demographic::GenderDemographic;
 pub mod gender_demographic_filter;
@@ -254,6 +260,8 @@ pub mod message_action;
 pub use self::message_action::MessageAction;
 pub mod message_imagemap_action;
 pub use self::message_imagemap_action::MessageImagemapAction;
+pub mod message_validation_error;
+pub use self::message_validation_error::{InvalidMessageProperty, MessageValidationError};
 pub mod message_quota_response;
 pub use self::message_quota_response::MessageQuotaResponse;
 pub mod messaging_api_pager_coupon_list_response;
@@ -392,5 +400,7 @@ pub mod user_profile_response;
 pub use self::user_profile_response::UserProfileResponse;
 pub mod validate_message_request;
 pub use self::validate_message_request::ValidateMessageRequest;
+pub mod validation_error;
+pub use self::validation_error::ValidationError;
 pub mod video_message;
 pub use self::video_message::VideoMessage;
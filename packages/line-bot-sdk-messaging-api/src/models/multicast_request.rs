@@ -42,4 +42,25 @@ impl MulticastRequest {
             custom_aggregation_units: None,
         }
     }
+
+    /// Maximum number of user IDs in a single multicast request.
+    pub const MAX_RECIPIENTS: usize = 500;
+
+    /// Checks that `to` holds between 1 and [`Self::MAX_RECIPIENTS`] user IDs, and the
+    /// aggregation units if any.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if self.to.is_empty() {
+            return Err(models::ValidationError::EmptyRecipients);
+        }
+        if self.to.len() > Self::MAX_RECIPIENTS {
+            return Err(models::ValidationError::TooManyRecipients {
+                max: Self::MAX_RECIPIENTS,
+                actual: self.to.len(),
+            });
+        }
+        if let Some(ref units) = self.custom_aggregation_units {
+            models::validation_error::validate_aggregation_units(units)?;
+        }
+        Ok(())
+    }
 }
@@ -40,4 +40,19 @@ impl NarrowcastRequest {
             notification_disabled: None,
         }
     }
+
+    /// Validates every message and the demographic filter before the request is sent.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        self.messages
+            .iter()
+            .try_for_each(models::Message::validate)?;
+        if let Some(demographic) = self
+            .filter
+            .as_ref()
+            .and_then(|filter| filter.demographic.as_deref())
+        {
+            demographic.validate()?;
+        }
+        Ok(())
+    }
 }
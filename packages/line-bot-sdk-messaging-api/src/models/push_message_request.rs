@@ -42,4 +42,31 @@ impl PushMessageRequest {
             custom_aggregation_units: None,
         }
     }
+
+    /// Maximum number of messages in a single request.
+    pub const MAX_MESSAGES: usize = 5;
+
+    /// Sends the messages without a push notification when `true`.
+    pub fn with_notification_disabled(mut self, notification_disabled: bool) -> Self {
+        self.notification_disabled = Some(notification_disabled);
+        self
+    }
+
+    /// Counts the messages under the given aggregation units in the statistics.
+    ///
+    /// LINE accepts a single unit per request, named with at most 30
+    /// alphanumeric characters or underscores; see [`validate`](Self::validate).
+    pub fn with_custom_aggregation_units(mut self, units: Vec<String>) -> Self {
+        self.custom_aggregation_units = Some(units);
+        self
+    }
+
+    /// Validates the messages and the aggregation units before the request is sent.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_messages(&self.messages, Self::MAX_MESSAGES)?;
+        if let Some(ref units) = self.custom_aggregation_units {
+            models::validation_error::validate_aggregation_units(units)?;
+        }
+        Ok(())
+    }
 }
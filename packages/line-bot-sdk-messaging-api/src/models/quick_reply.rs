@@ -24,4 +24,79 @@ impl QuickReply {
     pub fn new() -> QuickReply {
         QuickReply { items: None }
     }
+
+    /// Maximum number of buttons in a quick reply.
+    pub const MAX_ITEMS: usize = 13;
+
+    /// Returns an empty [`QuickReplyBuilder`].
+    pub fn builder() -> QuickReplyBuilder {
+        QuickReplyBuilder::default()
+    }
+}
+
+/// Builder for [`QuickReply`] that wraps each action in a [`models::QuickReplyItem`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct QuickReplyBuilder {
+    items: Vec<models::QuickReplyItem>,
+}
+
+impl QuickReplyBuilder {
+    /// Adds a button for an arbitrary action.
+    pub fn button(mut self, action: impl Into<models::Action>) -> Self {
+        self.items.push(models::QuickReplyItem {
+            image_url: None,
+            action: Some(Box::new(action.into())),
+            r#type: Some("action".to_string()),
+        });
+        self
+    }
+
+    /// Adds a button that sends `text` as a message from the user.
+    pub fn message_button(self, label: impl Into<String>, text: impl Into<String>) -> Self {
+        self.button(models::Action::message(label, text))
+    }
+
+    /// Adds a button that opens `uri`.
+    pub fn uri_button(self, label: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.button(models::Action::uri(label, uri))
+    }
+
+    /// Adds a button that returns `data` in a postback event.
+    pub fn postback_button(
+        self,
+        label: impl Into<String>,
+        data: impl Into<String>,
+        display_text: Option<String>,
+    ) -> Self {
+        self.button(models::PostbackAction {
+            r#type: Some("postback".to_string()),
+            label: Some(label.into()),
+            data: Some(data.into()),
+            display_text,
+            ..Default::default()
+        })
+    }
+
+    /// Adds a button that opens the camera.
+    pub fn camera_button(self, label: impl Into<String>) -> Self {
+        self.button(models::Action::camera(label))
+    }
+
+    /// Adds a button that opens the location screen.
+    pub fn location_button(self, label: impl Into<String>) -> Self {
+        self.button(models::Action::location(label))
+    }
+
+    /// Builds the quick reply, rejecting more than [`QuickReply::MAX_ITEMS`] buttons.
+    pub fn build(self) -> Result<QuickReply, models::ValidationError> {
+        if self.items.len() > QuickReply::MAX_ITEMS {
+            return Err(models::ValidationError::TooManyQuickReplyItems {
+                max: QuickReply::MAX_ITEMS,
+                actual: self.items.len(),
+            });
+        }
+        Ok(QuickReply {
+            items: Some(self.items),
+        })
+    }
 }
@@ -28,21 +28,26 @@ impl Default for Recipient {
     }
 }
 
-// Conversion methods from struct types to enum variants
+// Conversion methods from struct types to enum variants.
+// The enum is untagged, so each conversion fills in the `type` discriminator
+// when the struct was built without one.
 impl From<models::OperatorRecipient> for Recipient {
-    fn from(value: models::OperatorRecipient) -> Self {
+    fn from(mut value: models::OperatorRecipient) -> Self {
+        value.r#type.get_or_insert_with(|| "operator".to_string());
         Recipient::OperatorRecipient(Box::new(value))
     }
 }
 
 impl From<models::AudienceRecipient> for Recipient {
-    fn from(value: models::AudienceRecipient) -> Self {
+    fn from(mut value: models::AudienceRecipient) -> Self {
+        value.r#type.get_or_insert_with(|| "audience".to_string());
         Recipient::AudienceRecipient(Box::new(value))
     }
 }
 
 impl From<models::RedeliveryRecipient> for Recipient {
-    fn from(value: models::RedeliveryRecipient) -> Self {
+    fn from(mut value: models::RedeliveryRecipient) -> Self {
+        value.r#type.get_or_insert_with(|| "redelivery".to_string());
         Recipient::RedeliveryRecipient(Box::new(value))
     }
 }
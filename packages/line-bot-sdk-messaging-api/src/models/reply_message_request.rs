@@ -9,6 +9,7 @@
  */
 
 use crate::models;
+use line_bot_sdk_utils::reply_token::ReplyToken;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -35,4 +36,49 @@ impl ReplyMessageRequest {
             notification_disabled: None,
         }
     }
+
+    /// Builds a request for a tracked reply token, failing if the token is
+    /// likely to have expired already.
+    pub fn from_reply_token(
+        reply_token: &ReplyToken,
+        messages: Vec<models::Message>,
+    ) -> Result<ReplyMessageRequest, models::ValidationError> {
+        if reply_token.is_likely_expired() {
+            return Err(models::ValidationError::ReplyTokenLikelyExpired {
+                age: reply_token.age(),
+                ttl: reply_token.ttl(),
+            });
+        }
+        Ok(ReplyMessageRequest::new(
+            reply_token.as_str().to_owned(),
+            messages,
+        ))
+    }
+
+    /// Sends the messages without a push notification when `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{ReplyMessageRequest, TextMessage};
+    ///
+    /// let reply = ReplyMessageRequest::new("token".to_string(), vec![TextMessage::new("hi").into()]);
+    /// let json = serde_json::to_value(&reply).unwrap();
+    /// assert!(json.get("notificationDisabled").is_none());
+    ///
+    /// let json = serde_json::to_value(reply.with_notification_disabled(true)).unwrap();
+    /// assert_eq!(json["notificationDisabled"], true);
+    /// ```
+    pub fn with_notification_disabled(mut self, notification_disabled: bool) -> Self {
+        self.notification_disabled = Some(notification_disabled);
+        self
+    }
+
+    /// Maximum number of messages in a single request.
+    pub const MAX_MESSAGES: usize = 5;
+
+    /// Validates the number of messages and every message in the request before it is sent.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_messages(&self.messages, Self::MAX_MESSAGES)
+    }
 }
@@ -28,4 +28,21 @@ impl RichMenuBulkLinkRequest {
             user_ids,
         }
     }
+
+    /// Maximum number of user IDs in a single request.
+    pub const MAX_USER_IDS: usize = 500;
+
+    /// Checks that `user_ids` holds between 1 and [`Self::MAX_USER_IDS`] user IDs.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if self.user_ids.is_empty() {
+            return Err(models::ValidationError::EmptyRecipients);
+        }
+        if self.user_ids.len() > Self::MAX_USER_IDS {
+            return Err(models::ValidationError::TooManyRecipients {
+                max: Self::MAX_USER_IDS,
+                actual: self.user_ids.len(),
+            });
+        }
+        Ok(())
+    }
 }
@@ -23,11 +23,61 @@ pub struct Sender {
 }
 
 impl Sender {
-    /// Change icon and display name
-    pub fn new() -> Sender {
-        Sender {
-            name: None,
-            icon_url: None,
+    /// Maximum length of `name`, in characters.
+    pub const MAX_NAME_LENGTH: usize = 20;
+
+    /// Change icon and display name, checked with [`Sender::validate`].
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{Sender, ValidationError};
+    ///
+    /// let sender = Sender::new(
+    ///     Some("Cony".to_string()),
+    ///     Some("https://example.com/cony.png".to_string()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(sender.name.as_deref(), Some("Cony"));
+    ///
+    /// assert!(matches!(
+    ///     Sender::new(Some("LINE Support".to_string()), None),
+    ///     Err(ValidationError::ReservedSenderName(_))
+    /// ));
+    /// assert!(matches!(
+    ///     Sender::new(None, Some("http://example.com/cony.png".to_string())),
+    ///     Err(ValidationError::InsecureUrl(_))
+    /// ));
+    /// ```
+    pub fn new(
+        name: Option<String>,
+        icon_url: Option<String>,
+    ) -> Result<Sender, models::ValidationError> {
+        let sender = Sender { name, icon_url };
+        sender.validate()?;
+        Ok(sender)
+    }
+
+    /// Checks that `name` is at most [`Self::MAX_NAME_LENGTH`] characters and
+    /// doesn't contain the word `LINE`, and that `icon_url` is an `https://` URL.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        if let Some(ref name) = self.name {
+            let length = name.chars().count();
+            if length > Self::MAX_NAME_LENGTH {
+                return Err(models::ValidationError::SenderNameTooLong {
+                    max: Self::MAX_NAME_LENGTH,
+                    actual: length,
+                });
+            }
+            // Matched as a whole word, so names like "Caroline" are still allowed.
+            if name
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|word| word.eq_ignore_ascii_case("line"))
+            {
+                return Err(models::ValidationError::ReservedSenderName(name.clone()));
+            }
+        }
+        if let Some(ref icon_url) = self.icon_url {
+            models::validation_error::validate_https(icon_url)?;
         }
+        Ok(())
     }
 }
@@ -28,4 +28,24 @@ impl ShowLoadingAnimationRequest {
             loading_seconds: None,
         }
     }
+
+    /// Shortest loading animation LINE accepts, in seconds.
+    pub const MIN_LOADING_SECONDS: i32 = 5;
+    /// Longest loading animation LINE accepts, in seconds.
+    pub const MAX_LOADING_SECONDS: i32 = 60;
+    /// Duration LINE uses when `loading_seconds` is omitted.
+    pub const DEFAULT_LOADING_SECONDS: i32 = 20;
+
+    /// Checks that `loading_seconds`, if set, is a multiple of 5 between 5 and 60.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        match self.loading_seconds {
+            Some(seconds)
+                if !(Self::MIN_LOADING_SECONDS..=Self::MAX_LOADING_SECONDS).contains(&seconds)
+                    || seconds % 5 != 0 =>
+            {
+                Err(models::ValidationError::InvalidLoadingSeconds(seconds))
+            }
+            _ => Ok(()),
+        }
+    }
 }
@@ -30,14 +30,36 @@ pub struct StickerMessage {
 }
 
 impl StickerMessage {
-    pub fn new(package_id: String, sticker_id: String) -> StickerMessage {
+    pub fn new(package_id: impl Into<String>, sticker_id: impl Into<String>) -> StickerMessage {
         StickerMessage {
-            r#type: None,
+            r#type: Some("sticker".to_string()),
             quick_reply: None,
             sender: None,
-            package_id,
-            sticker_id,
+            package_id: package_id.into(),
+            sticker_id: sticker_id.into(),
             quote_token: None,
         }
     }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
+}
+
+/// Sticker packages that bots can send.
+///
+/// See <https://developers.line.biz/en/docs/messaging-api/sticker-list/> for the
+/// sticker IDs in each package.
+pub mod stickers {
+    /// Brown & Cony & Sally: Animated Special.
+    pub const BROWN_CONY_SALLY_PACKAGE_ID: &str = "11537";
+    /// CHOCO & Friends: Animated Special.
+    pub const CHOCO_AND_FRIENDS_PACKAGE_ID: &str = "11538";
+    /// UNIVERSTAR BT21: Animated Special.
+    pub const UNIVERSTAR_BT21_PACKAGE_ID: &str = "11539";
+
+    /// `(package_id, sticker_id)` of the sticker used in the Messaging API reference examples.
+    pub const REFERENCE_EXAMPLE: (&str, &str) = ("446", "1988");
 }
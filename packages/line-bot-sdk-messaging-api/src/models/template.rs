@@ -10,11 +10,12 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// Template enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Template {
     ButtonsTemplate(Box<models::ButtonsTemplate>),
@@ -23,33 +24,73 @@ pub enum Template {
     ImageCarouselTemplate(Box<models::ImageCarouselTemplate>),
 }
 
+// A confirm template also has the `text` and `actions` of a buttons template,
+// so an untagged match would read it as one. Dispatch on the `type`
+// discriminator instead.
+impl<'de> Deserialize<'de> for Template {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "buttons" => Template::ButtonsTemplate(Box::new(from_value(value)?)),
+            "confirm" => Template::ConfirmTemplate(Box::new(from_value(value)?)),
+            "carousel" => Template::CarouselTemplate(Box::new(from_value(value)?)),
+            "image_carousel" => Template::ImageCarouselTemplate(Box::new(from_value(value)?)),
+            other => return Err(D::Error::custom(format!("unknown template type `{other}`"))),
+        })
+    }
+}
+
+impl Template {
+    /// Checks the action and column limits of the template.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        match self {
+            Template::ButtonsTemplate(template) => template.validate(),
+            Template::ConfirmTemplate(template) => template.validate(),
+            Template::CarouselTemplate(template) => template.validate(),
+            Template::ImageCarouselTemplate(_) => Ok(()),
+        }
+    }
+}
+
 impl Default for Template {
     fn default() -> Self {
         Self::ButtonsTemplate(Box::new(Default::default()))
     }
 }
 
-// Conversion methods from struct types to enum variants
+// Conversion methods from struct types to enum variants.
+// The enum is untagged, so each conversion fills in the `type` discriminator
+// when the struct was built without one.
 impl From<models::ButtonsTemplate> for Template {
-    fn from(value: models::ButtonsTemplate) -> Self {
+    fn from(mut value: models::ButtonsTemplate) -> Self {
+        value.r#type.get_or_insert_with(|| "buttons".to_string());
         Template::ButtonsTemplate(Box::new(value))
     }
 }
 
 impl From<models::ConfirmTemplate> for Template {
-    fn from(value: models::ConfirmTemplate) -> Self {
+    fn from(mut value: models::ConfirmTemplate) -> Self {
+        value.r#type.get_or_insert_with(|| "confirm".to_string());
         Template::ConfirmTemplate(Box::new(value))
     }
 }
 
 impl From<models::CarouselTemplate> for Template {
-    fn from(value: models::CarouselTemplate) -> Self {
+    fn from(mut value: models::CarouselTemplate) -> Self {
+        value.r#type.get_or_insert_with(|| "carousel".to_string());
         Template::CarouselTemplate(Box::new(value))
     }
 }
 
 impl From<models::ImageCarouselTemplate> for Template {
-    fn from(value: models::ImageCarouselTemplate) -> Self {
+    fn from(mut value: models::ImageCarouselTemplate) -> Self {
+        value
+            .r#type
+            .get_or_insert_with(|| "image_carousel".to_string());
         Template::ImageCarouselTemplate(Box::new(value))
     }
 }
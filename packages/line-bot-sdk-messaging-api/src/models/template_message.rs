@@ -36,4 +36,10 @@ impl TemplateMessage {
             template: Box::new(template),
         }
     }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
@@ -30,14 +30,144 @@ pub struct TextMessage {
 }
 
 impl TextMessage {
-    pub fn new(text: String) -> TextMessage {
+    pub fn new(text: impl Into<String>) -> TextMessage {
         TextMessage {
-            r#type: None,
+            r#type: Some("text".to_string()),
             quick_reply: None,
             sender: None,
-            text,
+            text: text.into(),
             emojis: None,
             quote_token: None,
         }
     }
+
+    /// Attaches quick reply buttons to the message.
+    pub fn with_quick_reply(mut self, quick_reply: models::QuickReply) -> Self {
+        self.quick_reply = Some(Box::new(quick_reply));
+        self
+    }
+
+    /// Quotes the message identified by `quote_token`.
+    pub fn with_quote_token(mut self, quote_token: impl Into<String>) -> Self {
+        self.quote_token = Some(quote_token.into());
+        self
+    }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
+
+    /// Returns a [`TextMessageBuilder`] with empty text.
+    pub fn builder() -> TextMessageBuilder {
+        TextMessageBuilder::default()
+    }
+}
+
+/// A piece of a text message built with [`TextMessageBuilder::text_with_emoji`].
+///
+/// Strings convert into [`TextSegment::Text`] and `(product_id, emoji_id)`
+/// tuples into [`TextSegment::Emoji`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextSegment {
+    Text(String),
+    /// A LINE emoji, rendered in place of a `$` placeholder.
+    Emoji {
+        product_id: String,
+        emoji_id: String,
+    },
+}
+
+impl From<&str> for TextSegment {
+    fn from(value: &str) -> Self {
+        TextSegment::Text(value.to_string())
+    }
+}
+
+impl From<String> for TextSegment {
+    fn from(value: String) -> Self {
+        TextSegment::Text(value)
+    }
+}
+
+impl<P: Into<String>, E: Into<String>> From<(P, E)> for TextSegment {
+    fn from((product_id, emoji_id): (P, E)) -> Self {
+        TextSegment::Emoji {
+            product_id: product_id.into(),
+            emoji_id: emoji_id.into(),
+        }
+    }
+}
+
+/// Builder for [`TextMessage`] that fills in the `text` type.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TextMessageBuilder {
+    message: TextMessage,
+}
+
+impl TextMessageBuilder {
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.message.text = text.into();
+        self.message.emojis = None;
+        self
+    }
+
+    /// Sets the text from plain strings and LINE emojis.
+    ///
+    /// Each emoji becomes a `$` placeholder in the text, and its `index` is
+    /// the placeholder's position in UTF-16 code units, as LINE expects.
+    pub fn text_with_emoji<S: Into<TextSegment>>(
+        mut self,
+        segments: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let mut text = String::new();
+        let mut index = 0;
+        let mut emojis = Vec::new();
+        for segment in segments {
+            match segment.into() {
+                TextSegment::Text(value) => {
+                    index += value.encode_utf16().count();
+                    text.push_str(&value);
+                }
+                TextSegment::Emoji {
+                    product_id,
+                    emoji_id,
+                } => {
+                    emojis.push(models::Emoji {
+                        index: Some(index as i32),
+                        product_id: Some(product_id),
+                        emoji_id: Some(emoji_id),
+                    });
+                    index += 1;
+                    text.push('$');
+                }
+            }
+        }
+        self.message.text = text;
+        self.message.emojis = Some(emojis).filter(|emojis| !emojis.is_empty());
+        self
+    }
+
+    pub fn quick_reply(mut self, quick_reply: models::QuickReply) -> Self {
+        self.message.quick_reply = Some(Box::new(quick_reply));
+        self
+    }
+
+    pub fn sender(mut self, sender: models::Sender) -> Self {
+        self.message.sender = Some(Box::new(sender));
+        self
+    }
+
+    pub fn quote_token(mut self, quote_token: impl Into<String>) -> Self {
+        self.message.quote_token = Some(quote_token.into());
+        self
+    }
+
+    pub fn build(self) -> TextMessage {
+        TextMessage {
+            r#type: Some("text".to_string()),
+            ..self.message
+        }
+    }
 }
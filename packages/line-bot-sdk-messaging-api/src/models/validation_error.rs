@@ -0,0 +1,363 @@
+//! Client-side checks for the request limits documented by the LINE Messaging API.
+//!
+//! Requests that break these limits are rejected before any HTTP call is made.
+
+use crate::models;
+use std::fmt;
+use std::time::Duration;
+
+/// A request that the LINE Platform would reject.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// The request has no recipients.
+    EmptyRecipients,
+    /// The request has no messages.
+    EmptyMessages,
+    /// The request has more messages than the endpoint accepts.
+    TooManyMessages { max: usize, actual: usize },
+    /// The request has more recipients than the endpoint accepts.
+    TooManyRecipients { max: usize, actual: usize },
+    /// The content type isn't one the endpoint accepts.
+    UnsupportedContentType(String),
+    /// The uploaded content is larger than the endpoint accepts.
+    ContentTooLarge {
+        max_bytes: usize,
+        actual_bytes: usize,
+    },
+    /// A date parameter isn't in `yyyyMMdd` format.
+    InvalidDate(String),
+    /// A loading animation duration isn't a multiple of 5 between 5 and 60 seconds.
+    InvalidLoadingSeconds(i32),
+    /// A sender name is longer than LINE allows.
+    SenderNameTooLong { max: usize, actual: usize },
+    /// A sender name contains a word LINE reserves, such as `LINE`.
+    ReservedSenderName(String),
+    /// A URL that must use `https` doesn't.
+    InsecureUrl(String),
+    /// An audio duration isn't a positive number of milliseconds.
+    InvalidDuration(i64),
+    /// A latitude outside `[-90, 90]` or a longitude outside `[-180, 180]`.
+    CoordinatesOutOfRange { latitude: f64, longitude: f64 },
+    /// A text field is longer than LINE allows.
+    TextTooLong {
+        field: &'static str,
+        max: usize,
+        actual: usize,
+    },
+    /// A template or column has a number of actions LINE doesn't accept.
+    InvalidActionCount {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+    /// A carousel has a number of columns LINE doesn't accept.
+    InvalidColumnCount {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+    /// An imagemap base size isn't 1040 pixels wide with a positive height.
+    InvalidImagemapBaseSize { width: i32, height: i32 },
+    /// The area of the imagemap action at `index` extends beyond the base image.
+    ImagemapAreaOutOfBounds { index: usize },
+    /// A narrowcast demographic filter nests operators deeper than LINE allows.
+    FilterTooDeep { max: usize },
+    /// A reply token was received too long ago to still be valid.
+    ReplyTokenLikelyExpired { age: Duration, ttl: Duration },
+    /// The request has more custom aggregation units than LINE allows.
+    TooManyAggregationUnits { max: usize, actual: usize },
+    /// An aggregation unit name is too long or has characters other than
+    /// alphanumerics and underscores.
+    InvalidAggregationUnit(String),
+    /// A quick reply has more buttons than LINE allows.
+    TooManyQuickReplyItems { max: usize, actual: usize },
+    /// The `data` of an action is longer than LINE allows.
+    ActionDataTooLong { max: usize, actual: usize },
+    /// A datetime picker value doesn't match the format of the picker's mode.
+    InvalidDatetime {
+        mode: models::datetime_picker_action::Mode,
+        value: String,
+    },
+    /// A flex message in the request is invalid.
+    Flex(models::FlexValidationError),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::EmptyRecipients => {
+                write!(f, "at least one recipient is required")
+            }
+            ValidationError::EmptyMessages => write!(f, "at least one message is required"),
+            ValidationError::TooManyMessages { max, actual } => {
+                write!(f, "at most {} messages are allowed, got {}", max, actual)
+            }
+            ValidationError::TooManyRecipients { max, actual } => {
+                write!(f, "at most {} recipients are allowed, got {}", max, actual)
+            }
+            ValidationError::UnsupportedContentType(content_type) => {
+                write!(f, "unsupported content type `{}`", content_type)
+            }
+            ValidationError::ContentTooLarge {
+                max_bytes,
+                actual_bytes,
+            } => write!(
+                f,
+                "content must be at most {} bytes, got {}",
+                max_bytes, actual_bytes
+            ),
+            ValidationError::InvalidDate(date) => {
+                write!(f, "date `{}` must be in yyyyMMdd format", date)
+            }
+            ValidationError::InvalidLoadingSeconds(seconds) => write!(
+                f,
+                "loading seconds must be a multiple of 5 between 5 and 60, got {}",
+                seconds
+            ),
+            ValidationError::SenderNameTooLong { max, actual } => write!(
+                f,
+                "sender name must be at most {} characters, got {}",
+                max, actual
+            ),
+            ValidationError::ReservedSenderName(name) => {
+                write!(f, "sender name `{}` must not contain the word `LINE`", name)
+            }
+            ValidationError::InsecureUrl(url) => write!(f, "URL `{}` must use https", url),
+            ValidationError::InvalidDuration(duration) => write!(
+                f,
+                "duration must be a positive number of milliseconds, got {}",
+                duration
+            ),
+            ValidationError::CoordinatesOutOfRange {
+                latitude,
+                longitude,
+            } => write!(
+                f,
+                "coordinates ({}, {}) are out of range",
+                latitude, longitude
+            ),
+            ValidationError::TextTooLong { field, max, actual } => write!(
+                f,
+                "`{}` must be at most {} characters, got {}",
+                field, max, actual
+            ),
+            ValidationError::InvalidActionCount { min, max, actual } => write!(
+                f,
+                "between {} and {} actions are required, got {}",
+                min, max, actual
+            ),
+            ValidationError::InvalidColumnCount { min, max, actual } => write!(
+                f,
+                "between {} and {} columns are required, got {}",
+                min, max, actual
+            ),
+            ValidationError::InvalidImagemapBaseSize { width, height } => write!(
+                f,
+                "imagemap base size must be 1040 pixels wide with a positive height, got {}x{}",
+                width, height
+            ),
+            ValidationError::ImagemapAreaOutOfBounds { index } => write!(
+                f,
+                "area of imagemap action {} extends beyond the base image",
+                index
+            ),
+            ValidationError::FilterTooDeep { max } => write!(
+                f,
+                "demographic filter operators must be nested at most {} levels deep",
+                max
+            ),
+            ValidationError::ReplyTokenLikelyExpired { age, ttl } => write!(
+                f,
+                "reply token was received {:?} ago and is likely expired after {:?}",
+                age, ttl
+            ),
+            ValidationError::TooManyAggregationUnits { max, actual } => write!(
+                f,
+                "at most {} aggregation units are allowed, got {}",
+                max, actual
+            ),
+            ValidationError::InvalidAggregationUnit(unit) => write!(
+                f,
+                "aggregation unit `{}` must be 1 to 30 alphanumeric characters or underscores",
+                unit
+            ),
+            ValidationError::TooManyQuickReplyItems { max, actual } => write!(
+                f,
+                "at most {} quick reply items are allowed, got {}",
+                max, actual
+            ),
+            ValidationError::ActionDataTooLong { max, actual } => write!(
+                f,
+                "action data must be at most {} characters, got {}",
+                max, actual
+            ),
+            ValidationError::InvalidDatetime { mode, value } => write!(
+                f,
+                "`{}` must be in {} format for a {:?} picker",
+                value,
+                mode.format(),
+                mode
+            ),
+            ValidationError::Flex(e) => write!(f, "invalid flex message: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::Flex(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<models::FlexValidationError> for ValidationError {
+    fn from(e: models::FlexValidationError) -> Self {
+        ValidationError::Flex(e)
+    }
+}
+
+/// Checks that `date` is a `yyyyMMdd` date, as the statistics endpoints expect.
+pub(crate) fn validate_date(date: &str) -> Result<(), ValidationError> {
+    let invalid = || ValidationError::InvalidDate(date.to_string());
+    if date.len() != 8 || !date.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let month: u32 = date[4..6].parse().map_err(|_| invalid())?;
+    let day: u32 = date[6..8].parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Checks that the `data` of an action is at most [`models::Action::MAX_DATA_LENGTH`] characters.
+pub(crate) fn validate_action_data(data: &str) -> Result<(), ValidationError> {
+    let length = data.chars().count();
+    if length > models::Action::MAX_DATA_LENGTH {
+        return Err(ValidationError::ActionDataTooLong {
+            max: models::Action::MAX_DATA_LENGTH,
+            actual: length,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a request has between 1 and `max` messages, and that each is valid.
+pub(crate) fn validate_messages(
+    messages: &[models::Message],
+    max: usize,
+) -> Result<(), ValidationError> {
+    if messages.is_empty() {
+        return Err(ValidationError::EmptyMessages);
+    }
+    if messages.len() > max {
+        return Err(ValidationError::TooManyMessages {
+            max,
+            actual: messages.len(),
+        });
+    }
+    messages.iter().try_for_each(models::Message::validate)
+}
+
+/// Checks the `customAggregationUnits` of a push or multicast request.
+pub(crate) fn validate_aggregation_units(units: &[String]) -> Result<(), ValidationError> {
+    const MAX_UNITS: usize = 1;
+    const MAX_NAME_LENGTH: usize = 30;
+
+    if units.len() > MAX_UNITS {
+        return Err(ValidationError::TooManyAggregationUnits {
+            max: MAX_UNITS,
+            actual: units.len(),
+        });
+    }
+    for unit in units {
+        let valid_chars = unit.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if unit.is_empty() || unit.len() > MAX_NAME_LENGTH || !valid_chars {
+            return Err(ValidationError::InvalidAggregationUnit(unit.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `url` is an `https://` URL, as LINE requires for message content.
+pub(crate) fn validate_https(url: &str) -> Result<(), ValidationError> {
+    if !url.starts_with("https://") {
+        return Err(ValidationError::InsecureUrl(url.to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that `value` is in the format of a datetime picker in `mode`.
+pub(crate) fn validate_datetime(
+    mode: models::datetime_picker_action::Mode,
+    value: &str,
+) -> Result<(), ValidationError> {
+    use models::datetime_picker_action::Mode;
+
+    let valid = match mode {
+        Mode::Date => is_date(value),
+        Mode::Time => is_time(value),
+        // LINE accepts a lowercase separator as well.
+        Mode::Datetime => match value.split_once(['T', 't']) {
+            Some((date, time)) => is_date(date) && is_time(time),
+            None => false,
+        },
+    };
+    if !valid {
+        return Err(ValidationError::InvalidDatetime {
+            mode,
+            value: value.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Parses `value` as `N` ASCII digits, so signs and whitespace are rejected.
+fn parse_digits<const N: usize>(value: &str) -> Option<u32> {
+    if value.len() != N || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Whether `value` is a `yyyy-MM-dd` date.
+fn is_date(value: &str) -> bool {
+    let mut parts = value.split('-');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(year), Some(month), Some(day), None) => {
+            parse_digits::<4>(year).is_some()
+                && parse_digits::<2>(month).is_some_and(|m| (1..=12).contains(&m))
+                && parse_digits::<2>(day).is_some_and(|d| (1..=31).contains(&d))
+        }
+        _ => false,
+    }
+}
+
+/// Whether `value` is an `HH:mm` time.
+fn is_time(value: &str) -> bool {
+    match value.split_once(':') {
+        Some((hour, minute)) => {
+            parse_digits::<2>(hour).is_some_and(|h| h < 24)
+                && parse_digits::<2>(minute).is_some_and(|m| m < 60)
+        }
+        None => false,
+    }
+}
+
+/// Checks that a template or column has between `min` and `max` actions.
+pub(crate) fn validate_action_count<T>(
+    actions: &[T],
+    min: usize,
+    max: usize,
+) -> Result<(), ValidationError> {
+    if !(min..=max).contains(&actions.len()) {
+        return Err(ValidationError::InvalidActionCount {
+            min,
+            max,
+            actual: actions.len(),
+        });
+    }
+    Ok(())
+}
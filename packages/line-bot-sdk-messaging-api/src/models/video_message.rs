@@ -29,6 +29,32 @@ pub struct VideoMessage {
 }
 
 impl VideoMessage {
+    /// Creates a video message playing the file at `original_content_url`,
+    /// showing the image at `preview_image_url` until it starts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_messaging_api::models::{Message, ValidationError, VideoMessage};
+    ///
+    /// let message: Message = VideoMessage::new(
+    ///     "https://example.com/v.mp4".to_string(),
+    ///     "https://example.com/v.jpg".to_string(),
+    /// )
+    /// .with_tracking_id("campaign-1")
+    /// .into();
+    /// assert!(message.validate().is_ok());
+    /// assert_eq!(serde_json::to_value(&message).unwrap()["trackingId"], "campaign-1");
+    ///
+    /// let message = VideoMessage::new(
+    ///     "https://example.com/v.mp4".to_string(),
+    ///     "http://example.com/v.jpg".to_string(),
+    /// );
+    /// assert_eq!(
+    ///     message.validate(),
+    ///     Err(ValidationError::InsecureUrl("http://example.com/v.jpg".to_string()))
+    /// );
+    /// ```
     pub fn new(original_content_url: String, preview_image_url: String) -> VideoMessage {
         VideoMessage {
             r#type: None,
@@ -39,4 +65,23 @@ impl VideoMessage {
             tracking_id: None,
         }
     }
+
+    /// Checks that `original_content_url` and `preview_image_url` use https.
+    pub fn validate(&self) -> Result<(), models::ValidationError> {
+        models::validation_error::validate_https(&self.original_content_url)?;
+        models::validation_error::validate_https(&self.preview_image_url)
+    }
+
+    /// Sets the ID reported back in the `videoPlayComplete` webhook event
+    /// once a user has watched the video to the end.
+    pub fn with_tracking_id(mut self, tracking_id: impl Into<String>) -> Self {
+        self.tracking_id = Some(tracking_id.into());
+        self
+    }
+
+    /// Sends the message with the display name and icon of `sender`.
+    pub fn with_sender(mut self, sender: models::Sender) -> Self {
+        self.sender = Some(Box::new(sender));
+        self
+    }
 }
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{reply, StubTransport};
+use futures_util::TryStreamExt;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_aggregation_unit_name_list, get_aggregation_unit_name_list_stream,
+    get_aggregation_unit_usage,
+};
+
+#[tokio::test]
+async fn get_aggregation_unit_usage_reads_the_count() {
+    let transport = StubTransport::new([reply(200, r#"{"numOfCustomAggregationUnits":22}"#)]);
+
+    let usage = get_aggregation_unit_usage(&transport.configuration())
+        .await
+        .unwrap();
+
+    assert_eq!(usage.num_of_custom_aggregation_units, 22);
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url.path(), "/v2/bot/message/aggregation/info");
+}
+
+#[tokio::test]
+async fn get_aggregation_unit_name_list_returns_the_next_token() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{"customAggregationUnits":["promotion_a","promotion_b"],"next":"jxEWCEEP"}"#,
+    )]);
+
+    let page = get_aggregation_unit_name_list(&transport.configuration(), Some("2"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        page.custom_aggregation_units,
+        ["promotion_a", "promotion_b"]
+    );
+    assert_eq!(page.next.as_deref(), Some("jxEWCEEP"));
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.path(), "/v2/bot/message/aggregation/list");
+    assert_eq!(requests[0].url.query(), Some("limit=2"));
+}
+
+#[tokio::test]
+async fn get_aggregation_unit_name_list_stream_follows_the_next_token() {
+    let transport = StubTransport::new([
+        reply(
+            200,
+            r#"{"customAggregationUnits":["a","b"],"next":"token"}"#,
+        ),
+        reply(200, r#"{"customAggregationUnits":["c"]}"#),
+    ]);
+    let configuration = transport.configuration();
+
+    let names: Vec<String> = get_aggregation_unit_name_list_stream(&configuration, Some("2"))
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(names, ["a", "b", "c"]);
+    let queries: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| request.url.query().map(str::to_owned))
+        .collect();
+    assert_eq!(
+        queries,
+        [
+            Some("limit=2".to_string()),
+            Some("limit=2&start=token".to_string())
+        ]
+    );
+}
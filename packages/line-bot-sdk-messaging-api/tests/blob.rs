@@ -0,0 +1,212 @@
+mod common;
+
+use common::{reply, reply_bytes, StubTransport};
+use futures_util::TryStreamExt;
+use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::get_bot_info;
+use line_bot_sdk_messaging_api::apis::messaging_api_blob_api::{
+    get_message_content_bytes, get_message_content_stream, get_rich_menu_image_bytes,
+    set_rich_menu_image_bytes, GetMessageContentError, RICH_MENU_IMAGE_MAX_BYTES,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::ValidationError;
+
+/// A 1x1 transparent PNG.
+const PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x00, 0x01, 0x00, 0x00,
+    0x05, 0x00, 0x01, 0x0d, 0x0a, 0x2d, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae,
+    0x42, 0x60, 0x82,
+];
+
+const JPEG: &[u8] = &[
+    0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0x00,
+];
+
+#[tokio::test]
+async fn get_message_content_bytes_downloads_from_the_data_host() {
+    let transport = StubTransport::new([reply_bytes(200, "image/jpeg", JPEG)]);
+
+    let content = get_message_content_bytes(&transport.configuration(), "325708")
+        .await
+        .unwrap();
+
+    assert_eq!(content, JPEG);
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api-data.line.me/v2/bot/message/325708/content"
+    );
+}
+
+#[tokio::test]
+async fn content_calls_follow_data_base_path_and_others_base_path() {
+    let transport = StubTransport::new([
+        reply(
+            200,
+            r#"{"userId":"U123","basicId":"@123","displayName":"bot","chatMode":"bot","markAsReadMode":"auto"}"#,
+        ),
+        reply_bytes(200, "image/jpeg", JPEG),
+    ]);
+    let configuration = Configuration {
+        base_path: "http://api.mock".to_string(),
+        data_base_path: "http://api-data.mock".to_string(),
+        ..transport.configuration()
+    };
+
+    get_bot_info(&configuration).await.unwrap();
+    let content = get_message_content_bytes(&configuration, "325708")
+        .await
+        .unwrap();
+
+    assert_eq!(content, JPEG);
+    let urls: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| request.url.to_string())
+        .collect();
+    assert_eq!(
+        urls,
+        [
+            "http://api.mock/v2/bot/info",
+            "http://api-data.mock/v2/bot/message/325708/content",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn get_message_content_bytes_reports_content_not_ready() {
+    let transport = StubTransport::new([reply(202, "")]);
+
+    let err = get_message_content_bytes(&transport.configuration(), "325708")
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => {
+            assert_eq!(content.status, 202);
+            assert!(matches!(
+                content.entity,
+                Some(GetMessageContentError::ContentNotReady)
+            ));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[tokio::test]
+async fn get_message_content_bytes_surfaces_a_missing_message() {
+    let transport = StubTransport::new([reply(404, r#"{"message":"Not found"}"#)]);
+
+    let err = get_message_content_bytes(&transport.configuration(), "325708")
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => {
+            assert_eq!(content.status, 404);
+            assert!(matches!(
+                content.entity,
+                Some(GetMessageContentError::UnknownValue(_))
+            ));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[tokio::test]
+async fn get_message_content_stream_yields_the_whole_content() {
+    let video: Vec<u8> = (0..=255u8).cycle().take(64 * 1024).collect();
+    let transport = StubTransport::new([reply_bytes(200, "video/mp4", &video)]);
+
+    let stream = get_message_content_stream(&transport.configuration(), "325708")
+        .await
+        .unwrap();
+    let chunks: Result<Vec<_>, Error<GetMessageContentError>> = stream.try_collect().await;
+
+    assert_eq!(chunks.unwrap().concat(), video);
+}
+
+#[tokio::test]
+async fn get_message_content_stream_reports_content_not_ready() {
+    let transport = StubTransport::new([reply(202, "")]);
+
+    let Err(err) = get_message_content_stream(&transport.configuration(), "325708").await else {
+        panic!("a 202 must not yield a stream");
+    };
+
+    assert!(matches!(
+        err,
+        Error::ResponseError(content)
+            if matches!(content.entity, Some(GetMessageContentError::ContentNotReady))
+    ));
+}
+
+#[tokio::test]
+async fn rich_menu_image_round_trips_through_memory() {
+    let transport = StubTransport::new([reply(200, "{}"), reply_bytes(200, "image/png", PNG)]);
+    let configuration = transport.configuration();
+
+    set_rich_menu_image_bytes(
+        &configuration,
+        "richmenu-8dfdfc5",
+        PNG.to_vec(),
+        "image/png",
+    )
+    .await
+    .unwrap();
+    let downloaded = get_rich_menu_image_bytes(&configuration, "richmenu-8dfdfc5")
+        .await
+        .unwrap();
+
+    assert_eq!(downloaded, PNG);
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api-data.line.me/v2/bot/richmenu/richmenu-8dfdfc5/content"
+    );
+    assert_eq!(requests[0].header("content-type"), Some("image/png"));
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer YOUR_CHANNEL_ACCESS_TOKEN")
+    );
+    assert_eq!(requests[0].body, PNG);
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(requests[1].url, requests[0].url);
+}
+
+#[tokio::test]
+async fn set_rich_menu_image_bytes_rejects_invalid_images_without_sending() {
+    let transport = StubTransport::new([]);
+    let configuration = transport.configuration();
+
+    let err = set_rich_menu_image_bytes(
+        &configuration,
+        "richmenu-8dfdfc5",
+        PNG.to_vec(),
+        "image/gif",
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::UnsupportedContentType(content_type))
+            if content_type == "image/gif"
+    ));
+
+    let oversized = vec![0; RICH_MENU_IMAGE_MAX_BYTES + 1];
+    let err = set_rich_menu_image_bytes(&configuration, "richmenu-8dfdfc5", oversized, "image/png")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::ContentTooLarge { actual_bytes, .. })
+            if actual_bytes == RICH_MENU_IMAGE_MAX_BYTES + 1
+    ));
+
+    assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::get_bot_info;
+use line_bot_sdk_messaging_api::models::bot_info_response::{ChatMode, MarkAsReadMode};
+
+#[tokio::test]
+async fn get_bot_info_parses_a_premium_id() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{
+            "userId": "Ub9952f8...",
+            "basicId": "@216ru...",
+            "premiumId": "@linedevelopers",
+            "displayName": "Example name",
+            "pictureUrl": "https://profile.line-scdn.net/abcdefghijklmn",
+            "chatMode": "chat",
+            "markAsReadMode": "manual"
+        }"#,
+    )]);
+
+    let bot_info = get_bot_info(&transport.configuration()).await.unwrap();
+
+    assert_eq!(bot_info.user_id, "Ub9952f8...");
+    assert_eq!(bot_info.basic_id, "@216ru...");
+    assert_eq!(bot_info.premium_id.as_deref(), Some("@linedevelopers"));
+    assert_eq!(bot_info.display_name, "Example name");
+    assert_eq!(
+        bot_info.picture_url.as_deref(),
+        Some("https://profile.line-scdn.net/abcdefghijklmn")
+    );
+    assert_eq!(bot_info.chat_mode, ChatMode::Chat);
+    assert_eq!(bot_info.mark_as_read_mode, MarkAsReadMode::Manual);
+    assert_eq!(
+        transport.requests()[0].url.as_str(),
+        "https://api.line.me/v2/bot/info"
+    );
+}
+
+#[tokio::test]
+async fn get_bot_info_without_a_premium_id() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{
+            "userId": "Ub9952f8...",
+            "basicId": "@216ru...",
+            "displayName": "Example name",
+            "chatMode": "bot",
+            "markAsReadMode": "auto"
+        }"#,
+    )]);
+
+    let bot_info = get_bot_info(&transport.configuration()).await.unwrap();
+
+    assert_eq!(bot_info.premium_id, None);
+    assert_eq!(bot_info.picture_url, None);
+    assert_eq!(bot_info.chat_mode, ChatMode::Bot);
+    assert_eq!(bot_info.mark_as_read_mode, MarkAsReadMode::Auto);
+}
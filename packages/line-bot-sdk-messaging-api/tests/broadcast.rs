@@ -0,0 +1,76 @@
+mod common;
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{broadcast, broadcast_with_http_info};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{BroadcastRequest, TextMessage};
+
+fn request() -> BroadcastRequest {
+    BroadcastRequest::new(vec![TextMessage::new("Hello, friends").into()])
+}
+
+#[tokio::test]
+async fn broadcast_posts_the_messages_with_the_retry_key() {
+    let transport = StubTransport::new([reply_with_headers(
+        200,
+        &[("x-line-request-id", "broadcast-request-id")],
+        "{}",
+    )]);
+
+    let response = broadcast_with_http_info(
+        &transport.configuration(),
+        request(),
+        Some("123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.request_id(), Some("broadcast-request-id"));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/broadcast"
+    );
+    assert_eq!(
+        requests[0].header("x-line-retry-key"),
+        Some("123e4567-e89b-12d3-a456-426614174000")
+    );
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({"messages": [{"type": "text", "text": "Hello, friends"}]})
+    );
+}
+
+#[tokio::test]
+async fn broadcast_without_a_retry_key_sends_no_header() {
+    let transport = StubTransport::new([reply(200, "{}")]);
+
+    broadcast(&transport.configuration(), request(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(transport.requests()[0].header("x-line-retry-key"), None);
+}
+
+#[tokio::test]
+async fn broadcast_surfaces_the_error_response() {
+    let transport = StubTransport::new([reply_with_headers(
+        429,
+        &[("x-line-request-id", "rejected-request-id")],
+        r#"{"message":"You have reached your monthly limit."}"#,
+    )]);
+
+    let err = broadcast(&transport.configuration(), request(), None)
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => {
+            assert_eq!(content.status, 429);
+            assert_eq!(content.request_id.as_deref(), Some("rejected-request-id"));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::server::serve;
+use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::get_followers;
+
+/// `{"userIds":["U1","U2"]}`, gzip encoded.
+#[cfg(feature = "gzip")]
+const GZIPPED_FOLLOWERS: [u8; 43] = [
+    31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 86, 42, 45, 78, 45, 242, 76, 41, 86, 178, 138, 86, 10,
+    53, 84, 210, 81, 10, 53, 82, 138, 173, 5, 0, 215, 61, 33, 147, 23, 0, 0, 0,
+];
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn default_client_decodes_gzip_responses() {
+    let mut reply = common::reply_bytes(200, "application/json", &GZIPPED_FOLLOWERS);
+    reply
+        .headers_mut()
+        .insert("content-encoding", http::HeaderValue::from_static("gzip"));
+    let (base_path, server) = serve(vec![reply]).await;
+
+    let configuration = Configuration::builder().base_path(base_path).build();
+    let followers = get_followers(&configuration, None, None).await.unwrap();
+
+    assert_eq!(followers.user_ids, ["U1", "U2"]);
+    let received = server.await.unwrap();
+    assert!(received[0]
+        .header("accept-encoding")
+        .unwrap()
+        .contains("gzip"));
+}
+
+#[tokio::test]
+async fn high_throughput_configuration_sends_requests() {
+    let (base_path, server) = serve(vec![
+        common::reply(200, r#"{"userIds":["U1"]}"#),
+        common::reply(200, r#"{"userIds":["U2"]}"#),
+    ])
+    .await;
+    let configuration = Configuration {
+        base_path,
+        bearer_access_token: Some("YOUR_CHANNEL_ACCESS_TOKEN".to_string()),
+        ..Configuration::high_throughput()
+    };
+
+    let first = get_followers(&configuration, None, None).await.unwrap();
+    let second = get_followers(&configuration, None, None).await.unwrap();
+
+    assert_eq!(first.user_ids, ["U1"]);
+    assert_eq!(second.user_ids, ["U2"]);
+    let received = server.await.unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].method, "GET");
+    assert_eq!(received[0].target, "/v2/bot/followers/ids");
+    assert_eq!(
+        received[0].header("authorization"),
+        Some("Bearer YOUR_CHANNEL_ACCESS_TOKEN")
+    );
+}
@@ -0,0 +1,138 @@
+//! A scripted [`HttpTransport`] for calling the API functions without a network.
+
+#![allow(dead_code)]
+
+pub mod server;
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+use line_bot_sdk_messaging_api::apis::transport::{HttpTransport, TransportFuture};
+
+/// A request received by [`StubTransport`].
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: reqwest::Method,
+    pub url: reqwest::Url,
+    pub headers: reqwest::header::HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap()
+    }
+}
+
+/// Answers requests with the given replies, in order, and records them.
+#[derive(Debug, Default)]
+pub struct StubTransport {
+    replies: Mutex<VecDeque<http::Response<Vec<u8>>>>,
+    requests: Mutex<Vec<Recorded>>,
+    connect_failures: AtomicUsize,
+}
+
+impl StubTransport {
+    pub fn new(replies: impl IntoIterator<Item = http::Response<Vec<u8>>>) -> Arc<StubTransport> {
+        Arc::new(StubTransport {
+            replies: Mutex::new(replies.into_iter().collect()),
+            requests: Mutex::default(),
+            connect_failures: AtomicUsize::new(0),
+        })
+    }
+
+    /// Fails the next `times` requests with a connection error before replying.
+    pub fn fail_to_connect(&self, times: usize) {
+        self.connect_failures.store(times, Ordering::SeqCst);
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A configuration sending its requests through this transport.
+    pub fn configuration(self: &Arc<Self>) -> Configuration {
+        Configuration::builder()
+            .channel_access_token("YOUR_CHANNEL_ACCESS_TOKEN")
+            .transport(self.clone())
+            .build()
+    }
+}
+
+impl HttpTransport for StubTransport {
+    fn execute(&self, req: reqwest::Request) -> TransportFuture<'_> {
+        self.requests.lock().unwrap().push(Recorded {
+            method: req.method().clone(),
+            url: req.url().clone(),
+            headers: req.headers().clone(),
+            body: req
+                .body()
+                .and_then(|body| body.as_bytes())
+                .unwrap_or_default()
+                .to_vec(),
+        });
+        let failing = self
+            .connect_failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if failing {
+            return Box::pin(async { Err(connection_refused().await) });
+        }
+        let reply = self
+            .replies
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("no reply left for the request");
+        Box::pin(async move { Ok(reqwest::Response::from(reply)) })
+    }
+}
+
+/// The error of a request to a local port nobody listens on.
+async fn connection_refused() -> reqwest::Error {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    reqwest::Client::new()
+        .get(format!("http://{addr}/"))
+        .send()
+        .await
+        .unwrap_err()
+}
+
+/// A JSON reply.
+pub fn reply(status: u16, body: &str) -> http::Response<Vec<u8>> {
+    reply_with_headers(status, &[], body)
+}
+
+/// A binary reply with the given content type.
+pub fn reply_bytes(status: u16, content_type: &str, body: &[u8]) -> http::Response<Vec<u8>> {
+    http::Response::builder()
+        .status(status)
+        .header("content-type", content_type)
+        .body(body.to_vec())
+        .unwrap()
+}
+
+/// A JSON reply with extra headers.
+pub fn reply_with_headers(
+    status: u16,
+    headers: &[(&str, &str)],
+    body: &str,
+) -> http::Response<Vec<u8>> {
+    let mut builder = http::Response::builder()
+        .status(status)
+        .header("content-type", "application/json");
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    builder.body(body.as_bytes().to_vec()).unwrap()
+}
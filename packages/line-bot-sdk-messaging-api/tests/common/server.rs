@@ -0,0 +1,87 @@
+//! A local HTTP server, for exercising the real `reqwest` client end to end.
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// The request line and headers of a request received by [`serve`].
+#[derive(Debug, Clone)]
+pub struct Received {
+    pub method: String,
+    /// The path and query, e.g. `/v2/bot/followers/ids?limit=2`.
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Received {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Answers one connection per reply, in order, closing it afterwards.
+///
+/// Returns the base path to configure and a handle resolving to the
+/// requests received once every reply has been sent.
+pub async fn serve(replies: Vec<http::Response<Vec<u8>>>) -> (String, JoinHandle<Vec<Received>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_path = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let mut received = vec![];
+        for reply in replies {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            received.push(read_request(&mut stream).await);
+
+            let mut head = format!("HTTP/1.1 {}\r\n", reply.status());
+            for (name, value) in reply.headers() {
+                head.push_str(&format!("{}: {}\r\n", name, value.to_str().unwrap()));
+            }
+            head.push_str(&format!(
+                "content-length: {}\r\nconnection: close\r\n\r\n",
+                reply.body().len()
+            ));
+            let stream = stream.get_mut();
+            stream.write_all(head.as_bytes()).await.unwrap();
+            stream.write_all(reply.body()).await.unwrap();
+        }
+        received
+    });
+    (base_path, handle)
+}
+
+async fn read_request(stream: &mut BufReader<TcpStream>) -> Received {
+    let mut line = String::new();
+    stream.read_line(&mut line).await.unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap().to_string();
+    let target = parts.next().unwrap().to_string();
+
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':').unwrap();
+        headers.push((name.to_ascii_lowercase(), value.trim().to_string()));
+    }
+
+    let length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .map_or(0, |(_, value)| value.parse().unwrap());
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).await.unwrap();
+
+    Received {
+        method,
+        target,
+        headers,
+    }
+}
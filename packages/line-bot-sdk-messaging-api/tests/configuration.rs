@@ -0,0 +1,55 @@
+use std::env::VarError;
+use std::time::Duration;
+
+use line_bot_sdk_messaging_api::apis::configuration::{Configuration, RetryPolicy};
+
+#[test]
+fn builder_sets_the_token_and_base_path() {
+    let configuration = Configuration::builder()
+        .channel_access_token("YOUR_CHANNEL_ACCESS_TOKEN")
+        .base_path("http://localhost:8080")
+        .build();
+
+    assert_eq!(
+        configuration.bearer_access_token.as_deref(),
+        Some("YOUR_CHANNEL_ACCESS_TOKEN")
+    );
+    assert_eq!(configuration.base_path, "http://localhost:8080");
+    assert_eq!(configuration.data_base_path, "https://api-data.line.me");
+    assert!(configuration.retry_policy.is_none());
+    assert!(configuration.timeout.is_none());
+}
+
+#[test]
+fn builder_keeps_the_defaults_it_is_not_told_to_change() {
+    let configuration = Configuration::builder()
+        .retry_policy(RetryPolicy::default())
+        .timeout(Duration::from_secs(5))
+        .build();
+    let default = Configuration::default();
+
+    assert_eq!(configuration.base_path, default.base_path);
+    assert_eq!(configuration.user_agent, default.user_agent);
+    assert_eq!(configuration.bearer_access_token, None);
+    assert_eq!(configuration.retry_policy, Some(RetryPolicy::default()));
+    assert_eq!(configuration.timeout, Some(Duration::from_secs(5)));
+}
+
+// The environment is shared by every test in this binary, so both cases run in
+// one test instead of racing each other.
+#[test]
+fn from_env_reads_the_channel_access_token() {
+    std::env::set_var("CHANNEL_ACCESS_TOKEN", "token-from-env");
+    let configuration = Configuration::from_env().unwrap();
+    assert_eq!(
+        configuration.bearer_access_token.as_deref(),
+        Some("token-from-env")
+    );
+    assert_eq!(configuration.base_path, "https://api.line.me");
+
+    std::env::remove_var("CHANNEL_ACCESS_TOKEN");
+    assert!(matches!(
+        Configuration::from_env(),
+        Err(VarError::NotPresent)
+    ));
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::{reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::push_message;
+use line_bot_sdk_messaging_api::apis::{Error, LineApiError, ResponseContent};
+use line_bot_sdk_messaging_api::models::{ErrorDetail, PushMessageRequest, TextMessage};
+
+fn detail(message: &str, property: &str) -> ErrorDetail {
+    ErrorDetail {
+        message: Some(message.to_string()),
+        property: Some(property.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn line_error_parses_the_message_and_details() {
+    let transport = StubTransport::new([reply_with_headers(
+        400,
+        &[("x-line-request-id", "f70dd685-499a-4231-a441-f24b8d4fba21")],
+        r#"{
+            "message": "The request body has 2 error(s)",
+            "details": [
+                {"message": "May not be empty", "property": "messages[0].text"},
+                {"message": "Must be one of the following values: [text, image, video, audio, location, sticker, template, imagemap]", "property": "messages[1].type"}
+            ]
+        }"#,
+    )]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("").into()]);
+
+    let err = push_message(&transport.configuration(), request, None)
+        .await
+        .unwrap_err();
+
+    let Error::ResponseError(content) = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(
+        content.line_error(),
+        Some(LineApiError {
+            status: reqwest::StatusCode::BAD_REQUEST,
+            message: "The request body has 2 error(s)".to_string(),
+            details: vec![
+                detail("May not be empty", "messages[0].text"),
+                detail(
+                    "Must be one of the following values: [text, image, video, audio, location, sticker, template, imagemap]",
+                    "messages[1].type"
+                ),
+            ],
+            request_id: Some("f70dd685-499a-4231-a441-f24b8d4fba21".to_string()),
+        })
+    );
+}
+
+#[test]
+fn line_error_without_details() {
+    let content: ResponseContent<()> = ResponseContent::new(
+        reqwest::StatusCode::UNAUTHORIZED,
+        r#"{"message":"Authentication failed. Confirm that the access token in the authorization header is valid."}"#,
+        None,
+    )
+    .with_request_id("request-id");
+
+    let error = content.line_error().unwrap();
+
+    assert_eq!(error.status, 401);
+    assert!(error.details.is_empty());
+    assert_eq!(error.request_id.as_deref(), Some("request-id"));
+    assert_eq!(
+        error.to_string(),
+        "Authentication failed. Confirm that the access token in the authorization header is valid. (status code 401 Unauthorized)"
+    );
+}
+
+#[test]
+fn line_error_is_none_for_a_non_json_body() {
+    let content: ResponseContent<()> = ResponseContent::new(
+        reqwest::StatusCode::BAD_GATEWAY,
+        "<html>Bad Gateway</html>",
+        None,
+    );
+
+    assert_eq!(content.line_error(), None);
+}
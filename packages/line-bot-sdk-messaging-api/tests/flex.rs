@@ -0,0 +1,427 @@
+use line_bot_sdk_messaging_api::models::flex_box::Layout;
+use line_bot_sdk_messaging_api::models::flex_button::{Height, Style};
+use line_bot_sdk_messaging_api::models::flex_image::AspectMode;
+use line_bot_sdk_messaging_api::models::flex_text::Weight;
+use line_bot_sdk_messaging_api::models::{
+    Action, FlexBox, FlexBubble, FlexButton, FlexComponent, FlexContainer, FlexFiller, FlexIcon,
+    FlexImage, FlexSeparator, FlexSpan, FlexText, FlexVideo, FlexVisitorMut, ImageUrlCollector,
+    UriAction,
+};
+use serde_json::json;
+
+fn flex_text(text: &str, flex: i32, color: &str) -> FlexText {
+    FlexText {
+        flex: Some(flex),
+        text: Some(text.to_string()),
+        size: Some("sm".to_string()),
+        color: Some(color.to_string()),
+        ..FlexText::new("text".to_string())
+    }
+}
+
+fn text(text: &str, flex: i32, color: &str) -> FlexComponent {
+    flex_text(text, flex, color).into()
+}
+
+fn star(color: &str) -> FlexComponent {
+    FlexIcon {
+        r#type: Some("icon".to_string()),
+        size: Some("sm".to_string()),
+        ..FlexIcon::new(format!(
+            "https://developers-resource.landpress.line.me/fx/img/review_{color}_star_28.png"
+        ))
+    }
+    .into()
+}
+
+/// The rating box of the push-flex-message example, spelled out field by field.
+fn verbose_rating_box(contents: Vec<FlexComponent>) -> FlexBox {
+    FlexBox {
+        r#type: Some("box".to_string()),
+        layout: Layout::Baseline,
+        flex: None,
+        contents,
+        spacing: None,
+        margin: Some("md".to_string()),
+        position: None,
+        offset_top: None,
+        offset_bottom: None,
+        offset_start: None,
+        offset_end: None,
+        background_color: None,
+        border_color: None,
+        border_width: None,
+        corner_radius: None,
+        width: None,
+        max_width: None,
+        height: None,
+        max_height: None,
+        padding_all: None,
+        padding_top: None,
+        padding_bottom: None,
+        padding_start: None,
+        padding_end: None,
+        action: None,
+        justify_content: None,
+        align_items: None,
+        background: None,
+    }
+}
+
+fn uri_action(label: Option<&str>) -> Action {
+    UriAction {
+        r#type: Some("uri".to_string()),
+        label: label.map(str::to_string),
+        uri: Some("https://line.me/".to_string()),
+        alt_uri: None,
+    }
+    .into()
+}
+
+fn cafe_row(label: &str, value: &str) -> FlexComponent {
+    let value = FlexText {
+        wrap: Some(true),
+        ..flex_text(value, 5, "#666666")
+    };
+    FlexBox::builder(
+        Layout::Baseline,
+        vec![text(label, 1, "#aaaaaa"), value.into()],
+    )
+    .spacing("sm")
+    .build()
+    .into()
+}
+
+fn cafe_button(label: &str) -> FlexComponent {
+    FlexButton {
+        r#type: Some("button".to_string()),
+        style: Some(Style::Link),
+        height: Some(Height::Sm),
+        ..FlexButton::new(uri_action(Some(label)))
+    }
+    .into()
+}
+
+/// The hero, body and footer of the Brown Cafe bubble from the push-flex-message example.
+fn cafe_blocks() -> (FlexComponent, FlexBox, FlexBox) {
+    let hero = FlexImage {
+        size: Some("full".to_string()),
+        aspect_ratio: Some("20:13".to_string()),
+        aspect_mode: Some(AspectMode::Cover),
+        action: Some(Box::new(uri_action(None))),
+        ..FlexImage::new(
+            "image".to_string(),
+            "https://developers-resource.landpress.line.me/fx/img/01_1_cafe.png".to_string(),
+        )
+    };
+    let title = FlexText {
+        text: Some("Brown Cafe".to_string()),
+        size: Some("xl".to_string()),
+        weight: Some(Weight::Bold),
+        ..FlexText::new("text".to_string())
+    };
+    let rating = FlexText {
+        margin: Some("md".to_string()),
+        ..flex_text("4.0", 0, "#999999")
+    };
+    let rating_box = FlexBox::builder(
+        Layout::Baseline,
+        vec![
+            star("gold"),
+            star("gold"),
+            star("gold"),
+            star("gold"),
+            star("gray"),
+            rating.into(),
+        ],
+    )
+    .margin("md")
+    .build();
+    let info_box = FlexBox::builder(
+        Layout::Vertical,
+        vec![
+            cafe_row("Place", "Flex Tower, 7-7-4 Midori-ku, Tokyo"),
+            cafe_row("Time", "10:00 - 23:00"),
+        ],
+    )
+    .spacing("sm")
+    .margin("lg")
+    .build();
+    let body = FlexBox::vertical(vec![title.into(), rating_box.into(), info_box.into()]);
+    let footer = FlexBox::builder(
+        Layout::Vertical,
+        vec![
+            cafe_button("CALL"),
+            cafe_button("WEBSITE"),
+            FlexBox::builder(Layout::Vertical, vec![])
+                .margin("sm")
+                .build()
+                .into(),
+        ],
+    )
+    .flex(0)
+    .spacing("sm")
+    .build();
+
+    (hero.into(), body, footer)
+}
+
+#[test]
+fn flex_box_builder_matches_verbose_rating_box() {
+    let contents = vec![
+        star("gold"),
+        star("gold"),
+        star("gray"),
+        text("4.0", 0, "#999999"),
+    ];
+    let built = FlexBox::builder(Layout::Baseline, contents.clone())
+        .margin("md")
+        .build();
+
+    assert_eq!(built, verbose_rating_box(contents.clone()));
+    assert_eq!(
+        serde_json::to_value(&built).unwrap(),
+        serde_json::to_value(verbose_rating_box(contents)).unwrap()
+    );
+}
+
+#[test]
+fn flex_box_builder_matches_info_box_json() {
+    let row = |label: &str, value: &str| -> FlexComponent {
+        FlexBox::builder(
+            Layout::Baseline,
+            vec![text(label, 1, "#aaaaaa"), text(value, 5, "#666666")],
+        )
+        .spacing("sm")
+        .build()
+        .into()
+    };
+    let info_box = FlexBox::builder(
+        Layout::Vertical,
+        vec![row("Place", "Flex Tower"), row("Time", "10:00 - 23:00")],
+    )
+    .spacing("sm")
+    .margin("lg")
+    .build();
+
+    let row_json = |label: &str, value: &str| {
+        json!({
+            "type": "box",
+            "layout": "baseline",
+            "spacing": "sm",
+            "contents": [
+                {"type": "text", "text": label, "flex": 1, "size": "sm", "color": "#aaaaaa"},
+                {"type": "text", "text": value, "flex": 5, "size": "sm", "color": "#666666"},
+            ],
+        })
+    };
+    assert_eq!(
+        serde_json::to_value(&info_box).unwrap(),
+        json!({
+            "type": "box",
+            "layout": "vertical",
+            "spacing": "sm",
+            "margin": "lg",
+            "contents": [row_json("Place", "Flex Tower"), row_json("Time", "10:00 - 23:00")],
+        })
+    );
+}
+
+#[test]
+fn flex_box_layout_constructors() {
+    for (flex_box, layout) in [
+        (FlexBox::vertical(vec![]), "vertical"),
+        (FlexBox::horizontal(vec![]), "horizontal"),
+        (FlexBox::baseline(vec![]), "baseline"),
+    ] {
+        assert_eq!(
+            serde_json::to_value(&flex_box).unwrap(),
+            json!({"type": "box", "layout": layout, "contents": []})
+        );
+    }
+}
+
+#[test]
+fn flex_box_builder_styling_round_trips() {
+    let styled = FlexBox::builder(Layout::Horizontal, vec![text("Hi", 1, "#000000")])
+        .flex(0)
+        .spacing("md")
+        .margin("sm")
+        .padding_all("12px")
+        .background_color("#FFFFFF")
+        .corner_radius("8px")
+        .build();
+
+    let json = serde_json::to_string(&styled).unwrap();
+    assert_eq!(serde_json::from_str::<FlexBox>(&json).unwrap(), styled);
+    assert_eq!(
+        serde_json::to_value(&styled).unwrap()["paddingAll"],
+        json!("12px")
+    );
+}
+
+#[test]
+fn flex_bubble_builder_matches_hand_written_cafe_bubble() {
+    let (hero, body, footer) = cafe_blocks();
+    let built = FlexBubble::builder()
+        .hero(hero.clone())
+        .body(body.clone())
+        .footer(footer.clone())
+        .build();
+    let hand_written = FlexBubble {
+        r#type: "bubble".to_string(),
+        direction: None,
+        styles: None,
+        header: None,
+        hero: Some(Box::new(hero)),
+        body: Some(Box::new(body)),
+        footer: Some(Box::new(footer)),
+        size: None,
+        action: None,
+    };
+
+    assert_eq!(built, hand_written);
+    let json = serde_json::to_string(&built).unwrap();
+    assert_eq!(
+        serde_json::from_str::<FlexBubble>(&json).unwrap(),
+        hand_written
+    );
+    assert_eq!(
+        serde_json::to_value(&built).unwrap(),
+        serde_json::to_value(&hand_written).unwrap()
+    );
+}
+
+fn cafe_bubble() -> FlexContainer {
+    let (hero, body, footer) = cafe_blocks();
+    FlexBubble::builder()
+        .hero(hero)
+        .body(body)
+        .footer(footer)
+        .build()
+        .into()
+}
+
+#[test]
+fn image_url_collector_finds_every_url_of_the_cafe_bubble() {
+    let mut collector = ImageUrlCollector::default();
+    cafe_bubble().visit(&mut collector);
+
+    let star = |color: &str| {
+        format!("https://developers-resource.landpress.line.me/fx/img/review_{color}_star_28.png")
+    };
+    assert_eq!(
+        collector.urls,
+        [
+            "https://developers-resource.landpress.line.me/fx/img/01_1_cafe.png".to_string(),
+            star("gold"),
+            star("gold"),
+            star("gold"),
+            star("gold"),
+            star("gray"),
+        ]
+    );
+}
+
+/// Replaces one text colour with another, wherever it is used.
+struct Recolor {
+    from: &'static str,
+    to: &'static str,
+}
+
+impl FlexVisitorMut for Recolor {
+    fn visit_text(&mut self, text: &mut FlexText) {
+        if text.color.as_deref() == Some(self.from) {
+            text.color = Some(self.to.to_string());
+        }
+    }
+}
+
+#[test]
+fn visit_mut_edits_the_cafe_bubble_in_place() {
+    let mut container = cafe_bubble();
+    container.visit_mut(&mut Recolor {
+        from: "#666666",
+        to: "#111111",
+    });
+
+    let json = serde_json::to_value(&container).unwrap();
+    let info_rows = &json["body"]["contents"][2]["contents"];
+    for row in info_rows.as_array().unwrap() {
+        assert_eq!(row["contents"][0]["color"], "#aaaaaa");
+        assert_eq!(row["contents"][1]["color"], "#111111");
+    }
+    assert_eq!(
+        json["body"]["contents"][1]["contents"][5]["color"],
+        "#999999"
+    );
+}
+
+#[test]
+fn components_convert_into_their_variant() {
+    let button = FlexButton {
+        r#type: Some("button".to_string()),
+        ..FlexButton::new(Action::message("Yes", "yes"))
+    };
+    let image = FlexImage::new("image".to_string(), "https://example.com/a.png".to_string());
+    let icon = FlexIcon {
+        r#type: Some("icon".to_string()),
+        ..FlexIcon::new("https://example.com/star.png".to_string())
+    };
+    let video = FlexVideo::new(
+        "https://example.com/a.mp4".to_string(),
+        "https://example.com/a.png".to_string(),
+        image.clone().into(),
+    );
+    let span = FlexSpan {
+        text: Some("span".to_string()),
+        ..FlexSpan::new("span".to_string())
+    };
+    let cases: Vec<(FlexComponent, &str)> = vec![
+        (FlexBox::vertical(vec![]).into(), "box"),
+        (button.into(), "button"),
+        (image.into(), "image"),
+        (video.into(), "video"),
+        (icon.into(), "icon"),
+        (text("Hi", 1, "#000000"), "text"),
+        (span.into(), "span"),
+        (
+            FlexSeparator::new("separator".to_string()).into(),
+            "separator",
+        ),
+        (FlexFiller::new("filler".to_string()).into(), "filler"),
+    ];
+
+    for (component, r#type) in cases {
+        let variant = match &component {
+            FlexComponent::FlexBox(_) => "box",
+            FlexComponent::FlexButton(_) => "button",
+            FlexComponent::FlexImage(_) => "image",
+            FlexComponent::FlexVideo(_) => "video",
+            FlexComponent::FlexIcon(_) => "icon",
+            FlexComponent::FlexText(_) => "text",
+            FlexComponent::FlexSpan(_) => "span",
+            FlexComponent::FlexSeparator(_) => "separator",
+            FlexComponent::FlexFiller(_) => "filler",
+        };
+        assert_eq!(variant, r#type);
+
+        let json = serde_json::to_value(&component).unwrap();
+        assert_eq!(json["type"], r#type);
+        assert_eq!(
+            serde_json::from_value::<FlexComponent>(json).unwrap(),
+            component
+        );
+    }
+}
+
+#[test]
+fn simulator_export_round_trips_byte_for_byte() {
+    let export = include_str!("fixtures/simulator_export.json");
+
+    let container = FlexContainer::from_simulator_json(export).unwrap();
+    assert!(matches!(container, FlexContainer::FlexBubble(_)));
+    assert!(container.validate_structure().is_ok());
+
+    assert_eq!(container.to_simulator_json().unwrap(), export.trim_end());
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::StubTransport;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::push_message;
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::flex_bubble::Size;
+use line_bot_sdk_messaging_api::models::{
+    FlexBox, FlexBubble, FlexCarousel, FlexComponent, FlexContainer, FlexImage, FlexMessage,
+    FlexText, FlexValidationError, FlexVideo, PushMessageRequest, ValidationError,
+};
+
+fn flex_message(alt_text: String) -> FlexMessage {
+    FlexMessage::new(alt_text, FlexBubble::builder().build().into())
+}
+
+#[test]
+fn alt_text_must_not_be_empty() {
+    assert_eq!(
+        flex_message(String::new()).validate(),
+        Err(FlexValidationError::EmptyAltText)
+    );
+}
+
+#[test]
+fn alt_text_of_exactly_400_characters_is_accepted() {
+    // Characters, not bytes: 400 multi-byte characters are still within the limit.
+    assert_eq!(flex_message("あ".repeat(400)).validate(), Ok(()));
+}
+
+#[test]
+fn alt_text_of_401_characters_is_rejected() {
+    assert_eq!(
+        flex_message("a".repeat(401)).validate(),
+        Err(FlexValidationError::AltTextTooLong {
+            max: 400,
+            actual: 401
+        })
+    );
+}
+
+#[tokio::test]
+async fn push_message_rejects_an_invalid_flex_message_without_sending() {
+    let transport = StubTransport::new([]);
+    let request =
+        PushMessageRequest::new("U1".to_string(), vec![flex_message("a".repeat(401)).into()]);
+
+    let err = push_message(&transport.configuration(), request, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::Flex(
+            FlexValidationError::AltTextTooLong { .. }
+        ))
+    ));
+    assert!(transport.requests().is_empty());
+}
+
+fn text(text: &str) -> FlexComponent {
+    FlexText {
+        text: Some(text.to_string()),
+        ..FlexText::new("text".to_string())
+    }
+    .into()
+}
+
+/// A body box with `levels` boxes nested below it, the innermost holding a text.
+fn nested_body(levels: usize) -> FlexBox {
+    (0..levels).fold(FlexBox::vertical(vec![text("deep")]), |inner, _| {
+        FlexBox::vertical(vec![inner.into()])
+    })
+}
+
+fn bubble(body: FlexBox) -> FlexContainer {
+    FlexBubble::builder().body(body).build().into()
+}
+
+#[test]
+fn valid_bubble_passes() {
+    let body = FlexBox::vertical(vec![
+        text("Brown Cafe"),
+        FlexBox::baseline(vec![text("Place"), text("Flex Tower")]).into(),
+        nested_body(3).into(),
+    ]);
+
+    assert_eq!(bubble(body).validate_structure(), Ok(()));
+}
+
+#[test]
+fn boxes_nested_up_to_the_limit_pass() {
+    // The body itself is the first level.
+    assert_eq!(
+        bubble(nested_body(FlexContainer::MAX_BOX_DEPTH - 1)).validate_structure(),
+        Ok(())
+    );
+}
+
+#[test]
+fn over_nested_box_is_reported_with_its_path() {
+    let err = bubble(nested_body(FlexContainer::MAX_BOX_DEPTH))
+        .validate_structure()
+        .unwrap_err();
+
+    let path = format!("body{}", " > box[0]".repeat(FlexContainer::MAX_BOX_DEPTH));
+    assert_eq!(err, FlexValidationError::BoxTooDeep { path, max: 10 });
+}
+
+#[test]
+fn baseline_box_rejects_images() {
+    let body = FlexBox::vertical(vec![
+        text("title"),
+        text("subtitle"),
+        FlexBox::baseline(vec![
+            text("Place"),
+            FlexImage::new("image".to_string(), "https://example.com/a.png".to_string()).into(),
+        ])
+        .into(),
+    ]);
+
+    assert_eq!(
+        bubble(body).validate_structure(),
+        Err(FlexValidationError::InvalidBaselineContent {
+            path: "body > box[2] > image[1]".to_string()
+        })
+    );
+}
+
+#[test]
+fn too_many_components_are_rejected() {
+    let contents = (0..FlexContainer::MAX_COMPONENTS)
+        .map(|_| text("x"))
+        .collect();
+
+    // The body box counts as a component too.
+    assert_eq!(
+        bubble(FlexBox::vertical(contents)).validate_structure(),
+        Err(FlexValidationError::TooManyComponents {
+            max: 500,
+            actual: 501
+        })
+    );
+}
+
+#[test]
+fn carousel_paths_name_the_bubble() {
+    let carousel: FlexContainer = FlexCarousel::new(vec![
+        FlexBubble::builder().body(nested_body(1)).build(),
+        FlexBubble::builder()
+            .body(FlexBox::baseline(vec![FlexBox::vertical(vec![]).into()]))
+            .build(),
+    ])
+    .into();
+
+    assert_eq!(
+        carousel.validate_structure(),
+        Err(FlexValidationError::InvalidBaselineContent {
+            path: "bubble[1] > body > box[0]".to_string()
+        })
+    );
+}
+
+#[test]
+fn empty_carousel_is_rejected() {
+    let carousel: FlexContainer = FlexCarousel::new(vec![]).into();
+
+    assert_eq!(
+        carousel.validate_structure(),
+        Err(FlexValidationError::EmptyCarousel)
+    );
+}
+
+#[test]
+fn video_hero_is_rejected_in_micro_bubbles() {
+    let video = FlexVideo::new(
+        "https://example.com/a.mp4".to_string(),
+        "https://example.com/a.png".to_string(),
+        FlexImage::new("image".to_string(), "https://example.com/a.png".to_string()).into(),
+    );
+    let micro: FlexContainer = FlexBubble::builder()
+        .size(Size::Micro)
+        .hero(video.clone())
+        .build()
+        .into();
+    let err = micro.validate_structure().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "video at `hero` is not supported in nano or micro bubbles"
+    );
+
+    let hecto: FlexContainer = FlexBubble::builder()
+        .size(Size::Hecto)
+        .hero(video)
+        .build()
+        .into();
+    assert_eq!(hecto.validate_structure(), Ok(()));
+}
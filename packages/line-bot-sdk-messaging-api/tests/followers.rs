@@ -0,0 +1,79 @@
+mod common;
+
+use common::{reply, StubTransport};
+use futures_util::StreamExt;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_followers, get_followers_stream, GetFollowersError,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+
+const FORBIDDEN: &str = r#"{"message":"Access to this API is not available for your account"}"#;
+
+#[tokio::test]
+async fn get_followers_stream_surfaces_a_403_after_two_pages() {
+    let transport = StubTransport::new([
+        reply(200, r#"{"userIds":["U1","U2"],"next":"page-2"}"#),
+        reply(200, r#"{"userIds":["U3"],"next":"page-3"}"#),
+        reply(403, FORBIDDEN),
+    ]);
+    let configuration = transport.configuration();
+
+    let items: Vec<_> = get_followers_stream(&configuration, Some(2))
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 4);
+    let user_ids: Vec<_> = items[..3]
+        .iter()
+        .map(|item| item.as_ref().unwrap().as_str())
+        .collect();
+    assert_eq!(user_ids, ["U1", "U2", "U3"]);
+    match &items[3] {
+        Err(Error::ResponseError(content)) => {
+            assert_eq!(content.status, 403);
+            assert!(matches!(content.entity, Some(GetFollowersError::Forbidden)));
+        }
+        other => panic!("unexpected item: {other:?}"),
+    }
+
+    let starts: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| {
+            request
+                .url
+                .query_pairs()
+                .find(|(key, _)| key == "start")
+                .map(|(_, value)| value.into_owned())
+        })
+        .collect();
+    assert_eq!(
+        starts,
+        [None, Some("page-2".to_string()), Some("page-3".to_string())]
+    );
+    assert!(transport.requests().iter().all(|request| request
+        .url
+        .query_pairs()
+        .any(|(key, value)| key == "limit" && value == "2")));
+}
+
+#[tokio::test]
+async fn get_followers_maps_403_to_forbidden() {
+    let transport = StubTransport::new([reply(403, FORBIDDEN)]);
+
+    let err = get_followers(&transport.configuration(), None, None)
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => {
+            assert_eq!(content.status, 403);
+            assert!(matches!(content.entity, Some(GetFollowersError::Forbidden)));
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert_eq!(
+        transport.requests()[0].url.as_str(),
+        "https://api.line.me/v2/bot/followers/ids"
+    );
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::{reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    broadcast_with_http_info, multicast_with_http_info, push_message_with_http_info,
+    reply_message_with_http_info,
+};
+use line_bot_sdk_messaging_api::apis::RateLimit;
+use line_bot_sdk_messaging_api::models::{
+    BroadcastRequest, MulticastRequest, PushMessageRequest, ReplyMessageRequest, TextMessage,
+};
+
+const HEADERS: &[(&str, &str)] = &[
+    ("x-line-request-id", "7f6e5d4c-1111-2222-3333-444455556666"),
+    ("x-ratelimit-limit", "2000"),
+    ("x-ratelimit-remaining", "1999"),
+    ("x-ratelimit-reset", "42"),
+];
+
+fn expected_rate_limit() -> RateLimit {
+    RateLimit {
+        limit: Some(2000),
+        remaining: Some(1999),
+        reset: Some(42),
+    }
+}
+
+#[tokio::test]
+async fn push_message_with_http_info_parses_headers() {
+    let transport = StubTransport::new([reply_with_headers(
+        200,
+        HEADERS,
+        r#"{"sentMessages":[{"id":"461230966842064897","quoteToken":"IStG5h1Tz7b"}]}"#,
+    )]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    let response = push_message_with_http_info(&transport.configuration(), request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.request_id(),
+        Some("7f6e5d4c-1111-2222-3333-444455556666")
+    );
+    assert_eq!(response.rate_limit, expected_rate_limit());
+    assert_eq!(response.accepted_request_id(), None);
+    assert!(!response.deduplicated);
+    assert_eq!(response.entity.sent_messages[0].id, "461230966842064897");
+}
+
+#[tokio::test]
+async fn reply_multicast_and_broadcast_with_http_info_parse_headers() {
+    let transport = StubTransport::new([
+        reply_with_headers(200, HEADERS, r#"{"sentMessages":[]}"#),
+        reply_with_headers(200, HEADERS, "{}"),
+        reply_with_headers(200, HEADERS, "{}"),
+    ]);
+    let configuration = transport.configuration();
+    let messages = || vec![TextMessage::new("Hi").into()];
+
+    let reply = reply_message_with_http_info(
+        &configuration,
+        ReplyMessageRequest::new("reply-token".to_string(), messages()),
+    )
+    .await
+    .unwrap();
+    let multicast = multicast_with_http_info(
+        &configuration,
+        MulticastRequest::new(messages(), vec!["U1".to_string()]),
+        None,
+    )
+    .await
+    .unwrap();
+    let broadcast =
+        broadcast_with_http_info(&configuration, BroadcastRequest::new(messages()), None)
+            .await
+            .unwrap();
+
+    for (request_id, rate_limit) in [
+        (reply.request_id(), reply.rate_limit),
+        (multicast.request_id(), multicast.rate_limit),
+        (broadcast.request_id(), broadcast.rate_limit),
+    ] {
+        assert_eq!(request_id, Some("7f6e5d4c-1111-2222-3333-444455556666"));
+        assert_eq!(rate_limit, expected_rate_limit());
+    }
+}
+
+#[tokio::test]
+async fn missing_rate_limit_headers_parse_as_none() {
+    let transport = StubTransport::new([reply_with_headers(
+        200,
+        &[("x-ratelimit-limit", "not a number")],
+        r#"{"sentMessages":[]}"#,
+    )]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    let response = push_message_with_http_info(&transport.configuration(), request, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response.request_id(), None);
+    assert_eq!(response.rate_limit, RateLimit::default());
+}
+
+#[tokio::test]
+async fn accepted_request_id_is_parsed_from_a_deduplicated_push() {
+    let transport = StubTransport::new([reply_with_headers(
+        409,
+        &[
+            ("x-line-request-id", "second-request"),
+            ("x-line-accepted-request-id", "first-request"),
+        ],
+        r#"{"message":"The retry key is already accepted"}"#,
+    )]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    let response = push_message_with_http_info(
+        &transport.configuration(),
+        request,
+        Some("123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await
+    .unwrap();
+
+    assert!(response.deduplicated);
+    assert_eq!(response.request_id(), Some("second-request"));
+    assert_eq!(response.accepted_request_id(), Some("first-request"));
+}
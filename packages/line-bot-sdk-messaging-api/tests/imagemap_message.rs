@@ -0,0 +1,112 @@
+use line_bot_sdk_messaging_api::models::imagemap_message::ImagemapMessageBuilder;
+use line_bot_sdk_messaging_api::models::{ImagemapMessage, Message, ValidationError};
+use serde_json::json;
+
+#[test]
+fn builder_serializes_uri_and_message_areas() {
+    let message: Message = ImagemapMessage::builder()
+        .base_url("https://example.com/bot/images/rm001")
+        .alt_text("This is an imagemap")
+        .base_size(1040, 1040)
+        .uri_area(0, 0, 520, 1040, "https://example.com/")
+        .message_area(520, 0, 520, 1040, "Hello")
+        .build()
+        .unwrap()
+        .into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "type": "imagemap",
+            "baseUrl": "https://example.com/bot/images/rm001",
+            "altText": "This is an imagemap",
+            "baseSize": {"width": 1040, "height": 1040},
+            "actions": [
+                {
+                    "type": "uri",
+                    "linkUri": "https://example.com/",
+                    "area": {"x": 0, "y": 0, "width": 520, "height": 1040}
+                },
+                {
+                    "type": "message",
+                    "text": "Hello",
+                    "area": {"x": 520, "y": 0, "width": 520, "height": 1040}
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn build_rejects_an_area_beyond_the_base_image() {
+    let err = ImagemapMessage::builder()
+        .base_url("https://example.com/bot/images/rm001")
+        .alt_text("Imagemap")
+        .base_size(1040, 700)
+        .message_area(0, 0, 1040, 700, "fits")
+        .uri_area(0, 350, 1040, 351, "https://example.com/")
+        .build()
+        .unwrap_err();
+
+    assert_eq!(err, ValidationError::ImagemapAreaOutOfBounds { index: 1 });
+}
+
+#[test]
+fn build_rejects_negative_and_empty_areas() {
+    for (x, y, width, height) in [
+        (-1, 0, 10, 10),
+        (0, -1, 10, 10),
+        (0, 0, 0, 10),
+        (0, 0, 10, 0),
+    ] {
+        let err = ImagemapMessage::builder()
+            .base_size(1040, 1040)
+            .message_area(x, y, width, height, "tap")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err, ValidationError::ImagemapAreaOutOfBounds { index: 0 });
+    }
+}
+
+#[test]
+fn build_limits_the_number_of_actions() {
+    let area = |builder: ImagemapMessageBuilder, i: i32| {
+        builder.message_area(0, i, 1040, 1, format!("{i}"))
+    };
+    let builder = ImagemapMessage::builder().base_size(1040, 1040);
+
+    assert!((0..50).fold(builder.clone(), area).build().is_ok());
+    assert_eq!(
+        (0..51).fold(builder.clone(), area).build(),
+        Err(ValidationError::InvalidActionCount {
+            min: 1,
+            max: 50,
+            actual: 51
+        })
+    );
+    assert_eq!(
+        builder.build(),
+        Err(ValidationError::InvalidActionCount {
+            min: 1,
+            max: 50,
+            actual: 0
+        })
+    );
+}
+
+#[test]
+fn build_requires_a_1040_pixel_wide_base_size() {
+    for (width, height) in [(1024, 1040), (1040, 0)] {
+        let err = ImagemapMessage::builder()
+            .base_size(width, height)
+            .message_area(0, 0, 1, 1, "tap")
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            ValidationError::InvalidImagemapBaseSize { width, height }
+        );
+    }
+}
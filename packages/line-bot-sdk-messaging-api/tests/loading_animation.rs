@@ -0,0 +1,68 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::show_loading_animation;
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{ShowLoadingAnimationRequest, ValidationError};
+use serde_json::json;
+
+fn request(loading_seconds: Option<i32>) -> ShowLoadingAnimationRequest {
+    ShowLoadingAnimationRequest {
+        loading_seconds,
+        ..ShowLoadingAnimationRequest::new("U4af4980629...".to_string())
+    }
+}
+
+#[tokio::test]
+async fn omitted_duration_leaves_the_default_to_line() {
+    let transport = StubTransport::new([reply(202, "{}")]);
+
+    show_loading_animation(&transport.configuration(), request(None))
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/chat/loading/start"
+    );
+    assert_eq!(requests[0].json(), json!({"chatId": "U4af4980629..."}));
+}
+
+#[tokio::test]
+async fn valid_duration_is_sent() {
+    let transport = StubTransport::new([reply(202, "{}")]);
+
+    show_loading_animation(&transport.configuration(), request(Some(25)))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        transport.requests()[0].json(),
+        json!({"chatId": "U4af4980629...", "loadingSeconds": 25})
+    );
+}
+
+#[tokio::test]
+async fn invalid_duration_is_rejected_without_sending() {
+    let transport = StubTransport::new([]);
+
+    for seconds in [7, 0, 65] {
+        let err = show_loading_animation(&transport.configuration(), request(Some(seconds)))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::InvalidLoadingSeconds(s)) if s == seconds
+        ));
+    }
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn duration_bounds_are_accepted() {
+    for seconds in [5, 20, 60] {
+        assert_eq!(request(Some(seconds)).validate(), Ok(()));
+    }
+    assert_eq!(ShowLoadingAnimationRequest::DEFAULT_LOADING_SECONDS, 20);
+}
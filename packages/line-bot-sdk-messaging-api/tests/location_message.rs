@@ -0,0 +1,79 @@
+use line_bot_sdk_messaging_api::models::{LocationMessage, Message, ValidationError};
+use serde_json::json;
+
+#[test]
+fn try_new_accepts_valid_coordinates() {
+    let message: Message = LocationMessage::try_new(
+        "LINE Corporation",
+        "1-6-1 Yotsuya, Shinjuku-ku, Tokyo",
+        35.687574,
+        139.72922,
+    )
+    .unwrap()
+    .into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "type": "location",
+            "title": "LINE Corporation",
+            "address": "1-6-1 Yotsuya, Shinjuku-ku, Tokyo",
+            "latitude": 35.687574,
+            "longitude": 139.72922
+        })
+    );
+}
+
+#[test]
+fn try_new_accepts_the_range_boundaries() {
+    for (latitude, longitude) in [(90.0, 180.0), (-90.0, -180.0), (0.0, 0.0)] {
+        assert!(LocationMessage::try_new("Edge", "Edge", latitude, longitude).is_ok());
+    }
+}
+
+#[test]
+fn try_new_rejects_out_of_range_coordinates() {
+    for (latitude, longitude) in [(90.1, 0.0), (-90.1, 0.0), (0.0, 180.1), (0.0, -180.1)] {
+        assert_eq!(
+            LocationMessage::try_new("Nowhere", "Nowhere", latitude, longitude).unwrap_err(),
+            ValidationError::CoordinatesOutOfRange {
+                latitude,
+                longitude
+            }
+        );
+    }
+}
+
+#[test]
+fn try_new_rejects_nan_coordinates() {
+    assert!(matches!(
+        LocationMessage::try_new("Nowhere", "Nowhere", f64::NAN, 0.0),
+        Err(ValidationError::CoordinatesOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn validate_limits_the_title_and_address_length() {
+    let at_limit = "東".repeat(LocationMessage::MAX_TITLE_LENGTH);
+    assert!(LocationMessage::new(at_limit.clone(), at_limit, 0.0, 0.0)
+        .validate()
+        .is_ok());
+
+    let too_long = "a".repeat(101);
+    assert_eq!(
+        LocationMessage::new(too_long.clone(), "Tokyo", 0.0, 0.0).validate(),
+        Err(ValidationError::TextTooLong {
+            field: "title",
+            max: 100,
+            actual: 101
+        })
+    );
+    assert_eq!(
+        LocationMessage::new("LINE", too_long, 0.0, 0.0).validate(),
+        Err(ValidationError::TextTooLong {
+            field: "address",
+            max: 100,
+            actual: 101
+        })
+    );
+}
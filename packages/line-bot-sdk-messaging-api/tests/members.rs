@@ -0,0 +1,88 @@
+mod common;
+
+use common::{reply, StubTransport};
+use futures_util::TryStreamExt;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_group_members_ids, get_group_members_ids_stream, get_room_members_ids_stream,
+};
+
+fn two_pages() -> std::sync::Arc<StubTransport> {
+    StubTransport::new([
+        reply(200, r#"{"memberIds":["U1","U2"],"next":"jxEWCEEP..."}"#),
+        reply(200, r#"{"memberIds":["U3"]}"#),
+    ])
+}
+
+#[tokio::test]
+async fn get_group_members_ids_follows_the_next_token() {
+    let transport = two_pages();
+    let configuration = transport.configuration();
+
+    let first = get_group_members_ids(&configuration, "Ca56f94637c...", None)
+        .await
+        .unwrap();
+    assert_eq!(first.member_ids, ["U1", "U2"]);
+    assert_eq!(first.next.as_deref(), Some("jxEWCEEP..."));
+
+    let last = get_group_members_ids(&configuration, "Ca56f94637c...", first.next.as_deref())
+        .await
+        .unwrap();
+    assert_eq!(last.member_ids, ["U3"]);
+    assert_eq!(last.next, None);
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/group/Ca56f94637c.../members/ids"
+    );
+    assert_eq!(
+        requests[1].url.query_pairs().collect::<Vec<_>>(),
+        [("start".into(), "jxEWCEEP...".into())]
+    );
+}
+
+#[tokio::test]
+async fn get_group_members_ids_stream_reads_every_page() {
+    let transport = two_pages();
+    let configuration = transport.configuration();
+
+    let member_ids: Vec<String> = get_group_members_ids_stream(&configuration, "Ca56f94637c...")
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(member_ids, ["U1", "U2", "U3"]);
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/group/Ca56f94637c.../members/ids"
+    );
+    assert_eq!(
+        requests[1].url.as_str(),
+        "https://api.line.me/v2/bot/group/Ca56f94637c.../members/ids?start=jxEWCEEP..."
+    );
+}
+
+#[tokio::test]
+async fn get_room_members_ids_stream_reads_every_page() {
+    let transport = two_pages();
+    let configuration = transport.configuration();
+
+    let member_ids: Vec<String> = get_room_members_ids_stream(&configuration, "Ra8dbf4673c...")
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(member_ids, ["U1", "U2", "U3"]);
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/room/Ra8dbf4673c.../members/ids"
+    );
+    assert_eq!(
+        requests[1].url.as_str(),
+        "https://api.line.me/v2/bot/room/Ra8dbf4673c.../members/ids?start=jxEWCEEP..."
+    );
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{push_message, reply_message};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{
+    Message, PushMessageRequest, ReplyMessageRequest, TextMessage, ValidationError,
+};
+
+const SENT: &str = r#"{"sentMessages":[]}"#;
+
+fn messages(count: usize) -> Vec<Message> {
+    (0..count)
+        .map(|i| TextMessage::new(format!("message {i}")).into())
+        .collect()
+}
+
+fn validation_error<T>(result: Result<T, Error<impl std::fmt::Debug>>) -> ValidationError {
+    match result {
+        Err(Error::Validation(e)) => e,
+        Err(other) => panic!("unexpected error: {other}"),
+        Ok(_) => panic!("expected a validation error"),
+    }
+}
+
+#[tokio::test]
+async fn push_message_sends_five_messages() {
+    let transport = StubTransport::new([reply(200, SENT)]);
+
+    push_message(
+        &transport.configuration(),
+        PushMessageRequest::new("U1".to_string(), messages(5)),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].json()["messages"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn push_message_rejects_zero_and_six_messages_without_sending() {
+    let transport = StubTransport::new([]);
+    let configuration = transport.configuration();
+
+    let empty = push_message(
+        &configuration,
+        PushMessageRequest::new("U1".to_string(), messages(0)),
+        None,
+    )
+    .await;
+    let too_many = push_message(
+        &configuration,
+        PushMessageRequest::new("U1".to_string(), messages(6)),
+        None,
+    )
+    .await;
+
+    assert_eq!(validation_error(empty), ValidationError::EmptyMessages);
+    assert_eq!(
+        validation_error(too_many),
+        ValidationError::TooManyMessages { max: 5, actual: 6 }
+    );
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn reply_message_sends_five_messages() {
+    let transport = StubTransport::new([reply(200, SENT)]);
+
+    reply_message(
+        &transport.configuration(),
+        ReplyMessageRequest::new("reply-token".to_string(), messages(5)),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].json()["messages"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn reply_message_rejects_zero_and_six_messages_without_sending() {
+    let transport = StubTransport::new([]);
+    let configuration = transport.configuration();
+
+    let empty = reply_message(
+        &configuration,
+        ReplyMessageRequest::new("reply-token".to_string(), messages(0)),
+    )
+    .await;
+    let too_many = reply_message(
+        &configuration,
+        ReplyMessageRequest::new("reply-token".to_string(), messages(6)),
+    )
+    .await;
+
+    assert_eq!(validation_error(empty), ValidationError::EmptyMessages);
+    assert_eq!(
+        validation_error(too_many),
+        ValidationError::TooManyMessages { max: 5, actual: 6 }
+    );
+    assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,86 @@
+use line_bot_sdk_messaging_api::models::{
+    Action, AudioMessage, ButtonsTemplate, FlexBox, FlexBubble, FlexMessage, ImageMessage,
+    ImagemapBaseSize, ImagemapMessage, LocationMessage, Message, StickerMessage, TemplateMessage,
+    VideoMessage,
+};
+
+fn message_type(message: impl Into<Message>) -> String {
+    let json = serde_json::to_value(message.into()).unwrap();
+    json["type"].as_str().unwrap().to_owned()
+}
+
+#[test]
+fn image_message_converts_with_type() {
+    let message = ImageMessage::new(
+        "https://example.com/original.jpg".to_string(),
+        "https://example.com/preview.jpg".to_string(),
+    );
+    assert_eq!(message_type(message), "image");
+}
+
+#[test]
+fn video_message_converts_with_type() {
+    let message = VideoMessage::new(
+        "https://example.com/original.mp4".to_string(),
+        "https://example.com/preview.jpg".to_string(),
+    );
+    assert_eq!(message_type(message), "video");
+}
+
+#[test]
+fn audio_message_converts_with_type() {
+    let message = AudioMessage::new("https://example.com/original.m4a".to_string(), 60000);
+    assert_eq!(message_type(message), "audio");
+}
+
+#[test]
+fn location_message_converts_with_type() {
+    let message = LocationMessage::new("LINE", "Tokyo", 35.65910807942215, 139.70372892916203);
+    assert_eq!(message_type(message), "location");
+}
+
+#[test]
+fn sticker_message_converts_with_type() {
+    assert_eq!(message_type(StickerMessage::new("446", "1988")), "sticker");
+}
+
+#[test]
+fn flex_message_converts_with_type() {
+    let bubble = FlexBubble::builder()
+        .body(FlexBox::vertical(vec![]))
+        .build();
+    let message = FlexMessage::new("Flex".to_string(), bubble.into());
+    assert_eq!(message_type(message), "flex");
+}
+
+#[test]
+fn template_message_converts_with_type() {
+    let template = ButtonsTemplate::new("Menu".to_string(), vec![Action::message("Yes", "yes")]);
+    let message = TemplateMessage::new("Menu".to_string(), template.into());
+    assert_eq!(message_type(message), "template");
+}
+
+#[test]
+fn imagemap_message_converts_with_type() {
+    let message = ImagemapMessage::new(
+        "https://example.com/imagemap".to_string(),
+        "Imagemap".to_string(),
+        ImagemapBaseSize::new(1040, 1040),
+        vec![],
+    );
+    assert_eq!(message_type(message), "imagemap");
+}
+
+#[test]
+fn conversion_keeps_an_explicit_type() {
+    let message = StickerMessage {
+        r#type: Some("sticker".to_string()),
+        ..StickerMessage::new("446", "1988")
+    };
+    let message: Message = message.into();
+    assert!(matches!(message, Message::StickerMessage(_)));
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({"type": "sticker", "packageId": "446", "stickerId": "1988"})
+    );
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{multicast, multicast_message_chunked};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{MulticastRequest, TextMessage, ValidationError};
+
+fn user_ids(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("U{i}")).collect()
+}
+
+fn request(to: Vec<String>) -> MulticastRequest {
+    MulticastRequest::new(vec![TextMessage::new("Hello").into()], to)
+}
+
+#[tokio::test]
+async fn multicast_sends_up_to_500_recipients() {
+    let transport = StubTransport::new([reply(200, "{}")]);
+
+    multicast(
+        &transport.configuration(),
+        request(user_ids(500)),
+        Some("123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url.path(), "/v2/bot/message/multicast");
+    assert_eq!(
+        requests[0].header("x-line-retry-key"),
+        Some("123e4567-e89b-12d3-a456-426614174000")
+    );
+    let body = requests[0].json();
+    assert_eq!(body["to"].as_array().unwrap().len(), 500);
+    assert_eq!(
+        body["messages"],
+        serde_json::json!([{"type": "text", "text": "Hello"}])
+    );
+}
+
+#[tokio::test]
+async fn multicast_rejects_more_than_500_recipients_without_sending() {
+    let transport = StubTransport::new([]);
+
+    let err = multicast(&transport.configuration(), request(user_ids(501)), None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::TooManyRecipients {
+            max: 500,
+            actual: 501
+        })
+    ));
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn multicast_rejects_no_recipients_without_sending() {
+    let transport = StubTransport::new([]);
+
+    let err = multicast(&transport.configuration(), request(vec![]), None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::EmptyRecipients)
+    ));
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn multicast_message_chunked_splits_501_recipients_into_two_requests() {
+    let transport = StubTransport::new([reply(200, "{}"), reply(200, "{}")]);
+
+    let results = multicast_message_chunked(
+        &transport.configuration(),
+        user_ids(501),
+        vec![TextMessage::new("Hello").into()],
+        Some(true),
+        false,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].to.len(), 500);
+    assert_eq!(results[1].to, ["U500"]);
+    assert!(results.iter().all(|chunk| chunk.result.is_ok()));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    let sizes: Vec<_> = requests
+        .iter()
+        .map(|request| request.json()["to"].as_array().unwrap().len())
+        .collect();
+    assert_eq!(sizes, [500, 1]);
+    assert_eq!(requests[1].json()["to"], serde_json::json!(["U500"]));
+    assert!(requests
+        .iter()
+        .all(|request| request.json()["notificationDisabled"] == true));
+
+    let retry_keys: Vec<_> = requests
+        .iter()
+        .map(|request| request.header("x-line-retry-key").unwrap())
+        .collect();
+    assert_ne!(retry_keys[0], retry_keys[1]);
+    assert_eq!(retry_keys[0], results[0].retry_key.as_str());
+    assert_eq!(retry_keys[1], results[1].retry_key.as_str());
+}
+
+#[tokio::test]
+async fn multicast_message_chunked_stops_after_a_failed_chunk() {
+    let transport = StubTransport::new([
+        reply(200, "{}"),
+        reply(429, r#"{"message":"Too many requests"}"#),
+    ]);
+
+    let results = multicast_message_chunked(
+        &transport.configuration(),
+        user_ids(1200),
+        vec![TextMessage::new("Hello").into()],
+        None,
+        true,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].result.is_ok());
+    match &results[1].result {
+        Err(Error::ResponseError(content)) => assert_eq!(content.status, 429),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert_eq!(results[1].to.first().map(String::as_str), Some("U500"));
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn multicast_message_chunked_continues_after_a_failed_chunk() {
+    let transport = StubTransport::new([reply(200, "{}"), reply(500, "{}"), reply(200, "{}")]);
+
+    let results = multicast_message_chunked(
+        &transport.configuration(),
+        user_ids(1200),
+        vec![TextMessage::new("Hello").into()],
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let failed: Vec<_> = results.iter().map(|chunk| chunk.result.is_err()).collect();
+    assert_eq!(failed, [false, true, false]);
+    assert_eq!(results[2].to.len(), 200);
+    assert_eq!(transport.requests().len(), 3);
+}
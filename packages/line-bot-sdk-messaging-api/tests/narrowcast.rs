@@ -0,0 +1,146 @@
+mod common;
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_narrowcast_progress, narrowcast_with_http_info, GetNarrowcastProgressError,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::narrowcast_progress_response::Phase;
+use line_bot_sdk_messaging_api::models::{
+    AudienceRecipient, Filter, GenderDemographic, GenderDemographicFilter, NarrowcastRequest,
+    TextMessage,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn narrowcast_to_an_audience_filtered_by_gender() {
+    let transport = StubTransport::new([reply_with_headers(
+        202,
+        &[("x-line-request-id", "narrowcast-request-id")],
+        "{}",
+    )]);
+    let request = NarrowcastRequest {
+        recipient: Some(Box::new(
+            AudienceRecipient {
+                audience_group_id: Some(5614991017776),
+                ..AudienceRecipient::new()
+            }
+            .into(),
+        )),
+        filter: Some(Box::new(Filter {
+            demographic: Some(Box::new(
+                GenderDemographicFilter {
+                    one_of: Some(vec![GenderDemographic::Female]),
+                    ..GenderDemographicFilter::new()
+                }
+                .into(),
+            )),
+        })),
+        ..NarrowcastRequest::new(vec![TextMessage::new("Hello, world").into()])
+    };
+
+    let response = narrowcast_with_http_info(
+        &transport.configuration(),
+        request,
+        Some("123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.status, 202);
+    assert_eq!(response.request_id(), Some("narrowcast-request-id"));
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/narrowcast"
+    );
+    assert_eq!(
+        requests[0].json(),
+        json!({
+            "messages": [{"type": "text", "text": "Hello, world"}],
+            "recipient": {"type": "audience", "audienceGroupId": 5614991017776i64},
+            "filter": {"demographic": {"type": "gender", "oneOf": ["female"]}}
+        })
+    );
+}
+
+#[tokio::test]
+async fn get_narrowcast_progress_parses_each_phase() {
+    let transport = StubTransport::new([
+        reply(
+            200,
+            r#"{"phase":"waiting","acceptedTime":"2020-12-03T10:15:30.121Z"}"#,
+        ),
+        reply(
+            200,
+            r#"{"phase":"sending","acceptedTime":"2020-12-03T10:15:30.121Z","targetCount":100}"#,
+        ),
+        reply(
+            200,
+            r#"{
+                "phase": "succeeded",
+                "successCount": 98,
+                "failureCount": 2,
+                "targetCount": 100,
+                "acceptedTime": "2020-12-03T10:15:30.121Z",
+                "completedTime": "2020-12-03T10:15:30.121Z"
+            }"#,
+        ),
+        reply(
+            200,
+            r#"{
+                "phase": "failed",
+                "failedDescription": "authentication failed",
+                "errorCode": 1,
+                "acceptedTime": "2020-12-03T10:15:30.121Z",
+                "completedTime": "2020-12-03T10:15:30.121Z"
+            }"#,
+        ),
+    ]);
+    let configuration = transport.configuration();
+
+    let mut phases = vec![];
+    for _ in 0..4 {
+        let progress = get_narrowcast_progress(&configuration, "narrowcast-request-id")
+            .await
+            .unwrap();
+        assert_eq!(progress.accepted_time, "2020-12-03T10:15:30.121Z");
+        phases.push(progress);
+    }
+
+    assert_eq!(
+        phases.iter().map(|p| p.phase).collect::<Vec<_>>(),
+        [
+            Phase::Waiting,
+            Phase::Sending,
+            Phase::Succeeded,
+            Phase::Failed
+        ]
+    );
+    assert_eq!(phases[2].success_count, Some(98));
+    assert_eq!(phases[2].failure_count, Some(2));
+    assert_eq!(phases[2].target_count, Some(100));
+    assert_eq!(
+        phases[3].failed_description.as_deref(),
+        Some("authentication failed")
+    );
+    assert_eq!(
+        transport.requests()[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/progress/narrowcast?requestId=narrowcast-request-id"
+    );
+}
+
+#[tokio::test]
+async fn get_narrowcast_progress_reports_an_unknown_request_id() {
+    let transport = StubTransport::new([reply(404, r#"{"message":"Not found"}"#)]);
+
+    let err = get_narrowcast_progress(&transport.configuration(), "unknown")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::ResponseError(content)
+            if matches!(content.entity, Some(GetNarrowcastProgressError::RequestNotFound))
+    ));
+}
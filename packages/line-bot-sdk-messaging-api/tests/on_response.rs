@@ -0,0 +1,108 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::configuration::{Configuration, RetryPolicy};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{get_bot_info, push_message};
+use line_bot_sdk_messaging_api::apis::ResponseMeta;
+use line_bot_sdk_messaging_api::models::{PushMessageRequest, TextMessage};
+
+const BOT_INFO: &str = r#"{"userId":"U123","basicId":"@123","displayName":"bot","chatMode":"bot","markAsReadMode":"auto"}"#;
+
+/// Returns a configuration whose hook records every call into the returned list.
+fn recording(configuration: Configuration) -> (Configuration, Arc<Mutex<Vec<ResponseMeta>>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recorded = calls.clone();
+    let configuration = Configuration {
+        on_response: Some(Arc::new(move |meta: &ResponseMeta| {
+            recorded.lock().unwrap().push(meta.clone())
+        })),
+        ..configuration
+    };
+    (configuration, calls)
+}
+
+#[tokio::test]
+async fn hook_fires_once_with_the_request_id() {
+    let transport = StubTransport::new([reply_with_headers(
+        200,
+        &[("x-line-request-id", "bot-info-request-id")],
+        BOT_INFO,
+    )]);
+    let (configuration, calls) = recording(transport.configuration());
+
+    get_bot_info(&configuration).await.unwrap();
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method, reqwest::Method::GET);
+    assert_eq!(calls[0].path, "/v2/bot/info");
+    assert_eq!(calls[0].status, 200);
+    assert_eq!(calls[0].request_id.as_deref(), Some("bot-info-request-id"));
+    assert_eq!(calls[0].retries, 0);
+}
+
+#[tokio::test]
+async fn hook_fires_once_after_retries_with_the_final_response() {
+    let transport = StubTransport::new([
+        reply_with_headers(500, &[("x-line-request-id", "first-attempt")], "{}"),
+        reply_with_headers(503, &[("x-line-request-id", "second-attempt")], "{}"),
+        reply_with_headers(
+            200,
+            &[("x-line-request-id", "final-attempt")],
+            r#"{"sentMessages":[{"id":"461230966842064897"}]}"#,
+        ),
+    ]);
+    let (configuration, calls) = recording(Configuration {
+        retry_policy: Some(RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            ..RetryPolicy::default()
+        }),
+        ..transport.configuration()
+    });
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    push_message(&configuration, request, None).await.unwrap();
+
+    assert_eq!(transport.requests().len(), 3);
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method, reqwest::Method::POST);
+    assert_eq!(calls[0].path, "/v2/bot/message/push");
+    assert_eq!(calls[0].status, 200);
+    assert_eq!(calls[0].request_id.as_deref(), Some("final-attempt"));
+    assert_eq!(calls[0].retries, 2);
+}
+
+#[tokio::test]
+async fn hook_fires_for_error_responses() {
+    let transport = StubTransport::new([reply_with_headers(
+        400,
+        &[("x-line-request-id", "rejected-request-id")],
+        r#"{"message":"The request body has 1 error(s)"}"#,
+    )]);
+    let (configuration, calls) = recording(transport.configuration());
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    assert!(push_message(&configuration, request, None).await.is_err());
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].status, 400);
+    assert_eq!(calls[0].request_id.as_deref(), Some("rejected-request-id"));
+}
+
+#[tokio::test]
+async fn hook_does_not_fire_for_requests_rejected_locally() {
+    let transport = StubTransport::new([reply(200, "{}")]);
+    let (configuration, calls) = recording(transport.configuration());
+    let request = PushMessageRequest::new("U1".to_string(), vec![]);
+
+    assert!(push_message(&configuration, request, None).await.is_err());
+
+    assert!(calls.lock().unwrap().is_empty());
+    assert!(transport.requests().is_empty());
+}
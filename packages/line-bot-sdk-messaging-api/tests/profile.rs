@@ -0,0 +1,59 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::get_profile;
+
+#[tokio::test]
+async fn get_profile_parses_every_field() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{
+            "displayName": "LINE taro",
+            "userId": "U4af4980629...",
+            "language": "en",
+            "pictureUrl": "https://profile.line-scdn.net/abcdefghijklmn",
+            "statusMessage": "Hello, LINE!"
+        }"#,
+    )]);
+
+    let profile = get_profile(&transport.configuration(), "U4af4980629...")
+        .await
+        .unwrap();
+
+    assert_eq!(profile.display_name, "LINE taro");
+    assert_eq!(profile.user_id, "U4af4980629...");
+    assert_eq!(profile.language.as_deref(), Some("en"));
+    assert_eq!(
+        profile.picture_url.as_deref(),
+        Some("https://profile.line-scdn.net/abcdefghijklmn")
+    );
+    assert_eq!(profile.status_message.as_deref(), Some("Hello, LINE!"));
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/profile/U4af4980629..."
+    );
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer YOUR_CHANNEL_ACCESS_TOKEN")
+    );
+}
+
+#[tokio::test]
+async fn get_profile_leaves_absent_optional_fields_empty() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{"displayName": "LINE taro", "userId": "U4af4980629..."}"#,
+    )]);
+
+    let profile = get_profile(&transport.configuration(), "U4af4980629...")
+        .await
+        .unwrap();
+
+    assert_eq!(profile.display_name, "LINE taro");
+    assert_eq!(profile.language, None);
+    assert_eq!(profile.picture_url, None);
+    assert_eq!(profile.status_message, None);
+}
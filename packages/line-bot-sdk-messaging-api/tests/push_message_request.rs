@@ -0,0 +1,92 @@
+mod common;
+
+use common::StubTransport;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::push_message;
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{PushMessageRequest, TextMessage, ValidationError};
+use serde_json::json;
+
+fn request() -> PushMessageRequest {
+    PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()])
+}
+
+#[test]
+fn optional_fields_are_omitted_unless_set() {
+    assert_eq!(
+        serde_json::to_value(request()).unwrap(),
+        json!({"to": "U1", "messages": [{"type": "text", "text": "Hi"}]})
+    );
+}
+
+#[test]
+fn optional_fields_serialize_when_set() {
+    let request = request()
+        .with_notification_disabled(true)
+        .with_custom_aggregation_units(vec!["promotion_a".to_string()]);
+
+    assert!(request.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(request).unwrap(),
+        json!({
+            "to": "U1",
+            "messages": [{"type": "text", "text": "Hi"}],
+            "notificationDisabled": true,
+            "customAggregationUnits": ["promotion_a"]
+        })
+    );
+}
+
+#[test]
+fn aggregation_unit_names_are_limited_to_30_characters() {
+    let at_limit = "a".repeat(30);
+    assert!(request()
+        .with_custom_aggregation_units(vec![at_limit])
+        .validate()
+        .is_ok());
+
+    let too_long = "a".repeat(31);
+    assert_eq!(
+        request()
+            .with_custom_aggregation_units(vec![too_long.clone()])
+            .validate(),
+        Err(ValidationError::InvalidAggregationUnit(too_long))
+    );
+}
+
+#[test]
+fn aggregation_unit_names_must_be_alphanumeric_or_underscores() {
+    for unit in ["", "promotion-a", "キャンペーン"] {
+        assert_eq!(
+            request()
+                .with_custom_aggregation_units(vec![unit.to_string()])
+                .validate(),
+            Err(ValidationError::InvalidAggregationUnit(unit.to_string()))
+        );
+    }
+}
+
+#[test]
+fn only_one_aggregation_unit_is_allowed() {
+    assert_eq!(
+        request()
+            .with_custom_aggregation_units(vec!["a".to_string(), "b".to_string()])
+            .validate(),
+        Err(ValidationError::TooManyAggregationUnits { max: 1, actual: 2 })
+    );
+}
+
+#[tokio::test]
+async fn push_message_rejects_an_invalid_unit_without_sending() {
+    let transport = StubTransport::new([]);
+    let request = request().with_custom_aggregation_units(vec!["a".repeat(31)]);
+
+    let err = push_message(&transport.configuration(), request, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::InvalidAggregationUnit(_))
+    ));
+    assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,58 @@
+use line_bot_sdk_messaging_api::models::{QuickReply, ValidationError};
+use serde_json::json;
+
+fn with_buttons(count: usize) -> Result<QuickReply, ValidationError> {
+    (0..count)
+        .fold(QuickReply::builder(), |builder, i| {
+            builder.message_button(format!("Option {i}"), format!("option {i}"))
+        })
+        .build()
+}
+
+#[test]
+fn builder_serializes_to_the_quick_reply_schema() {
+    let quick_reply = QuickReply::builder()
+        .message_button("Sushi", "Sushi")
+        .uri_button("Menu", "https://example.com/menu")
+        .postback_button("Buy", "action=buy&itemid=111", Some("Buy".to_string()))
+        .camera_button("Camera")
+        .location_button("Location")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&quick_reply).unwrap(),
+        json!({
+            "items": [
+                {"type": "action", "action": {"type": "message", "label": "Sushi", "text": "Sushi"}},
+                {"type": "action", "action": {"type": "uri", "label": "Menu", "uri": "https://example.com/menu"}},
+                {"type": "action", "action": {
+                    "type": "postback",
+                    "label": "Buy",
+                    "data": "action=buy&itemid=111",
+                    "displayText": "Buy"
+                }},
+                {"type": "action", "action": {"type": "camera", "label": "Camera"}},
+                {"type": "action", "action": {"type": "location", "label": "Location"}}
+            ]
+        })
+    );
+}
+
+#[test]
+fn thirteen_items_are_accepted() {
+    let quick_reply = with_buttons(13).unwrap();
+
+    assert_eq!(quick_reply.items.unwrap().len(), 13);
+}
+
+#[test]
+fn fourteen_items_are_rejected() {
+    assert_eq!(
+        with_buttons(14).unwrap_err(),
+        ValidationError::TooManyQuickReplyItems {
+            max: 13,
+            actual: 14
+        }
+    );
+}
@@ -0,0 +1,35 @@
+use std::time::{Duration, Instant};
+
+use line_bot_sdk_messaging_api::models::{ReplyMessageRequest, TextMessage, ValidationError};
+use line_bot_sdk_utils::reply_token::ReplyToken;
+
+fn received_ago(age: Duration) -> ReplyToken {
+    ReplyToken::received_at("reply-token", Instant::now().checked_sub(age).unwrap())
+}
+
+#[test]
+fn fresh_reply_token_builds_a_request() {
+    let token = received_ago(ReplyToken::DEFAULT_TTL - Duration::from_secs(1));
+
+    let request =
+        ReplyMessageRequest::from_reply_token(&token, vec![TextMessage::new("Hi").into()]).unwrap();
+
+    assert_eq!(request.reply_token, "reply-token");
+    assert_eq!(request.messages.len(), 1);
+}
+
+#[test]
+fn expired_reply_token_is_rejected() {
+    let token = received_ago(Duration::from_secs(90));
+
+    let err = ReplyMessageRequest::from_reply_token(&token, vec![TextMessage::new("Hi").into()])
+        .unwrap_err();
+
+    match err {
+        ValidationError::ReplyTokenLikelyExpired { age, ttl } => {
+            assert!(age >= Duration::from_secs(90));
+            assert_eq!(ttl, ReplyToken::DEFAULT_TTL);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
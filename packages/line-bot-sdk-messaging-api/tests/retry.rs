@@ -0,0 +1,219 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::configuration::{Configuration, RetryPolicy};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_bot_info, link_rich_menu_id_to_user, push_message,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{PushMessageRequest, TextMessage};
+
+const BOT_INFO: &str = r#"{"userId":"U123","basicId":"@123","displayName":"bot","chatMode":"bot","markAsReadMode":"auto"}"#;
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy {
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+        ..RetryPolicy::default()
+    }
+}
+
+fn with_retries(transport: &std::sync::Arc<StubTransport>, policy: RetryPolicy) -> Configuration {
+    Configuration {
+        retry_policy: Some(policy),
+        ..transport.configuration()
+    }
+}
+
+#[tokio::test]
+async fn retries_until_the_request_succeeds() {
+    let transport = StubTransport::new([
+        reply(500, r#"{"message":"Internal server error"}"#),
+        reply(500, r#"{"message":"Internal server error"}"#),
+        reply(200, BOT_INFO),
+    ]);
+
+    let bot_info = get_bot_info(&with_retries(&transport, fast_policy()))
+        .await
+        .unwrap();
+
+    assert_eq!(bot_info.user_id, "U123");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn gives_up_after_max_retries() {
+    let transport = StubTransport::new([reply(503, "{}"), reply(503, "{}"), reply(503, "{}")]);
+    let policy = RetryPolicy {
+        max_retries: 2,
+        ..fast_policy()
+    };
+
+    let err = get_bot_info(&with_retries(&transport, policy))
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => assert_eq!(content.status, 503),
+        other => panic!("unexpected error: {other}"),
+    }
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn does_not_retry_without_a_policy() {
+    let transport = StubTransport::new([reply(500, "{}")]);
+
+    assert!(get_bot_info(&transport.configuration()).await.is_err());
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_at_max_delay() {
+    let transport = StubTransport::new([
+        reply_with_headers(429, &[("retry-after", "3600")], "{}"),
+        reply(200, BOT_INFO),
+    ]);
+
+    let start = Instant::now();
+    let bot_info = tokio::time::timeout(
+        Duration::from_secs(5),
+        get_bot_info(&with_retries(&transport, fast_policy())),
+    )
+    .await
+    .expect("Retry-After should be capped at max_delay")
+    .unwrap();
+
+    assert_eq!(bot_info.user_id, "U123");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_below_max_delay_is_honored() {
+    let transport = StubTransport::new([
+        reply_with_headers(429, &[("retry-after", "1")], "{}"),
+        reply(200, BOT_INFO),
+    ]);
+    let policy = RetryPolicy {
+        max_delay: Duration::from_secs(30),
+        ..fast_policy()
+    };
+
+    let start = Instant::now();
+    get_bot_info(&with_retries(&transport, policy))
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn every_attempt_sends_the_same_retry_key() {
+    let transport = StubTransport::new([
+        reply(500, "{}"),
+        reply(503, "{}"),
+        reply(200, r#"{"sentMessages":[{"id":"461230966842064897"}]}"#),
+    ]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    push_message(
+        &with_retries(&transport, fast_policy()),
+        request,
+        Some("123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    for request in &requests {
+        assert_eq!(
+            request.header("x-line-retry-key"),
+            Some("123e4567-e89b-12d3-a456-426614174000")
+        );
+        assert_eq!(request.body, requests[0].body);
+    }
+}
+
+#[tokio::test]
+async fn retries_generate_one_retry_key_when_none_is_given() {
+    let transport = StubTransport::new([
+        reply(500, "{}"),
+        reply(200, r#"{"sentMessages":[{"id":"461230966842064897"}]}"#),
+    ]);
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    push_message(&with_retries(&transport, fast_policy()), request, None)
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    let retry_key = requests[0].header("x-line-retry-key").unwrap();
+    assert_eq!(retry_key.len(), 36);
+    assert_eq!(requests[1].header("x-line-retry-key"), Some(retry_key));
+}
+
+#[tokio::test]
+async fn retries_connection_errors_of_idempotent_requests() {
+    let transport = StubTransport::new([reply(200, BOT_INFO)]);
+    transport.fail_to_connect(2);
+
+    let bot_info = get_bot_info(&with_retries(&transport, fast_policy()))
+        .await
+        .unwrap();
+
+    assert_eq!(bot_info.user_id, "U123");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn connection_errors_count_against_max_retries() {
+    let transport = StubTransport::new([]);
+    transport.fail_to_connect(3);
+    let policy = RetryPolicy {
+        max_retries: 2,
+        ..fast_policy()
+    };
+
+    let err = get_bot_info(&with_retries(&transport, policy))
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::Reqwest(err) => assert!(err.is_connect()),
+        other => panic!("unexpected error: {other}"),
+    }
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn does_not_retry_connection_errors_of_other_requests() {
+    let transport = StubTransport::new([]);
+    transport.fail_to_connect(1);
+
+    let result =
+        link_rich_menu_id_to_user(&with_retries(&transport, fast_policy()), "U1", "richmenu-1")
+            .await;
+
+    assert!(matches!(result, Err(Error::Reqwest(_))));
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn backoff_doubles_up_to_max_delay() {
+    let policy = RetryPolicy {
+        base_delay: Duration::from_millis(500),
+        max_delay: Duration::from_secs(3),
+        ..RetryPolicy::default()
+    };
+
+    let delays: Vec<_> = (0..5).map(|retry| policy.backoff(retry)).collect();
+    assert_eq!(
+        delays,
+        [500, 1000, 2000, 3000, 3000].map(Duration::from_millis)
+    );
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    multicast_with_http_info, push_message, push_message_with_http_info,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{MulticastRequest, PushMessageRequest, TextMessage};
+
+const RETRY_KEY: &str = "123e4567-e89b-12d3-a456-426614174000";
+const ALREADY_ACCEPTED: &str = r#"{"message":"The retry key is already accepted"}"#;
+
+fn already_accepted() -> http::Response<Vec<u8>> {
+    reply_with_headers(
+        409,
+        &[("x-line-accepted-request-id", "accepted-request-id")],
+        ALREADY_ACCEPTED,
+    )
+}
+
+fn push_request() -> PushMessageRequest {
+    PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hello").into()])
+}
+
+#[tokio::test]
+async fn push_message_treats_an_accepted_retry_key_as_success() {
+    let transport = StubTransport::new([already_accepted()]);
+
+    let response =
+        push_message_with_http_info(&transport.configuration(), push_request(), Some(RETRY_KEY))
+            .await
+            .unwrap();
+
+    assert!(response.deduplicated);
+    assert_eq!(response.status, 409);
+    assert_eq!(response.accepted_request_id(), Some("accepted-request-id"));
+    assert!(response.entity.sent_messages.is_empty());
+    assert_eq!(
+        transport.requests()[0].header("x-line-retry-key"),
+        Some(RETRY_KEY)
+    );
+}
+
+#[tokio::test]
+async fn multicast_treats_an_accepted_retry_key_as_success() {
+    let transport = StubTransport::new([already_accepted()]);
+    let request = MulticastRequest::new(
+        vec![TextMessage::new("Hello").into()],
+        vec!["U1".to_string(), "U2".to_string()],
+    );
+
+    let response = multicast_with_http_info(&transport.configuration(), request, Some(RETRY_KEY))
+        .await
+        .unwrap();
+
+    assert!(response.deduplicated);
+    assert_eq!(response.accepted_request_id(), Some("accepted-request-id"));
+}
+
+#[tokio::test]
+async fn successful_send_is_not_deduplicated() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{"sentMessages":[{"id":"461230966842064897"}]}"#,
+    )]);
+
+    let response =
+        push_message_with_http_info(&transport.configuration(), push_request(), Some(RETRY_KEY))
+            .await
+            .unwrap();
+
+    assert!(!response.deduplicated);
+    assert_eq!(response.accepted_request_id(), None);
+}
+
+#[tokio::test]
+async fn conflict_without_an_accepted_request_id_is_an_error() {
+    let transport = StubTransport::new([reply(409, ALREADY_ACCEPTED)]);
+
+    let err = push_message(&transport.configuration(), push_request(), Some(RETRY_KEY))
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::ResponseError(content) => assert_eq!(content.status, 409),
+        other => panic!("unexpected error: {other}"),
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    create_rich_menu_alias, get_rich_menu_alias_list, set_default_rich_menu_with_alias,
+};
+use line_bot_sdk_messaging_api::models::CreateRichMenuAliasRequest;
+use serde_json::json;
+
+#[tokio::test]
+async fn create_then_list_aliases() {
+    let transport = StubTransport::new([
+        reply(200, "{}"),
+        reply(200, "{}"),
+        reply(
+            200,
+            r#"{"aliases":[
+                {"richMenuAliasId":"richmenu-alias-a","richMenuId":"richmenu-a"},
+                {"richMenuAliasId":"richmenu-alias-b","richMenuId":"richmenu-b"}
+            ]}"#,
+        ),
+    ]);
+    let configuration = transport.configuration();
+
+    for (alias, rich_menu_id) in [
+        ("richmenu-alias-a", "richmenu-a"),
+        ("richmenu-alias-b", "richmenu-b"),
+    ] {
+        let request = CreateRichMenuAliasRequest::new(alias.to_string(), rich_menu_id.to_string());
+        create_rich_menu_alias(&configuration, request)
+            .await
+            .unwrap();
+    }
+    let list = get_rich_menu_alias_list(&configuration).await.unwrap();
+
+    let aliases: Vec<_> = list
+        .aliases
+        .iter()
+        .map(|alias| {
+            (
+                alias.rich_menu_alias_id.as_str(),
+                alias.rich_menu_id.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        aliases,
+        [
+            ("richmenu-alias-a", "richmenu-a"),
+            ("richmenu-alias-b", "richmenu-b")
+        ]
+    );
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url.path(), "/v2/bot/richmenu/alias");
+    assert_eq!(
+        requests[0].json(),
+        json!({"richMenuAliasId": "richmenu-alias-a", "richMenuId": "richmenu-a"})
+    );
+    assert_eq!(requests[2].method, "GET");
+    assert_eq!(requests[2].url.path(), "/v2/bot/richmenu/alias/list");
+}
+
+#[tokio::test]
+async fn set_default_rich_menu_with_alias_resolves_the_alias() {
+    let transport = StubTransport::new([
+        reply(
+            200,
+            r#"{"richMenuAliasId":"richmenu-alias-b","richMenuId":"richmenu-b"}"#,
+        ),
+        reply(200, "{}"),
+    ]);
+
+    set_default_rich_menu_with_alias(&transport.configuration(), "richmenu-alias-b")
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.path(),
+        "/v2/bot/richmenu/alias/richmenu-alias-b"
+    );
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(
+        requests[1].url.path(),
+        "/v2/bot/user/all/richmenu/richmenu-b"
+    );
+}
+
+#[tokio::test]
+async fn set_default_rich_menu_with_an_unknown_alias_fails_without_setting() {
+    let transport = StubTransport::new([reply(404, r#"{"message":"Not found"}"#)]);
+
+    let err = set_default_rich_menu_with_alias(&transport.configuration(), "richmenu-alias-c")
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.line_error().unwrap().status, 404);
+    assert_eq!(transport.requests().len(), 1);
+}
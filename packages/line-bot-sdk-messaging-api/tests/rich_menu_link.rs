@@ -0,0 +1,137 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_rich_menu_id_of_user, link_rich_menu_id_to_user, link_rich_menu_id_to_users,
+    unlink_rich_menu_id_from_user, unlink_rich_menu_id_from_users,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{
+    RichMenuBulkLinkRequest, RichMenuBulkUnlinkRequest, ValidationError,
+};
+
+fn user_ids(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("U{i}")).collect()
+}
+
+#[tokio::test]
+async fn link_and_unlink_a_single_user() {
+    let transport = StubTransport::new([reply(200, "{}"), reply(200, "{}")]);
+    let configuration = transport.configuration();
+
+    link_rich_menu_id_to_user(&configuration, "U1", "richmenu-8dfdfc5")
+        .await
+        .unwrap();
+    unlink_rich_menu_id_from_user(&configuration, "U1")
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/user/U1/richmenu/richmenu-8dfdfc5"
+    );
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(
+        requests[1].url.as_str(),
+        "https://api.line.me/v2/bot/user/U1/richmenu"
+    );
+}
+
+#[tokio::test]
+async fn bulk_link_sends_the_rich_menu_and_user_ids() {
+    let transport = StubTransport::new([reply(202, "{}")]);
+
+    link_rich_menu_id_to_users(
+        &transport.configuration(),
+        RichMenuBulkLinkRequest::new("richmenu-8dfdfc5".to_string(), user_ids(2)),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/richmenu/bulk/link"
+    );
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({"richMenuId": "richmenu-8dfdfc5", "userIds": ["U0", "U1"]})
+    );
+}
+
+#[tokio::test]
+async fn bulk_unlink_sends_the_user_ids() {
+    let transport = StubTransport::new([reply(202, "{}")]);
+
+    unlink_rich_menu_id_from_users(
+        &transport.configuration(),
+        RichMenuBulkUnlinkRequest::new(user_ids(500)),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/richmenu/bulk/unlink"
+    );
+    assert_eq!(requests[0].json()["userIds"].as_array().unwrap().len(), 500);
+}
+
+#[tokio::test]
+async fn bulk_requests_reject_over_limit_user_ids_without_sending() {
+    let transport = StubTransport::new([]);
+    let configuration = transport.configuration();
+
+    let link = link_rich_menu_id_to_users(
+        &configuration,
+        RichMenuBulkLinkRequest::new("richmenu-8dfdfc5".to_string(), user_ids(501)),
+    )
+    .await
+    .unwrap_err();
+    let unlink =
+        unlink_rich_menu_id_from_users(&configuration, RichMenuBulkUnlinkRequest::new(vec![]))
+            .await
+            .unwrap_err();
+
+    assert!(matches!(
+        link,
+        Error::Validation(ValidationError::TooManyRecipients {
+            max: 500,
+            actual: 501
+        })
+    ));
+    assert!(matches!(
+        unlink,
+        Error::Validation(ValidationError::EmptyRecipients)
+    ));
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn get_rich_menu_id_of_a_linked_user() {
+    let transport = StubTransport::new([reply(200, r#"{"richMenuId":"richmenu-a"}"#)]);
+
+    let linked = get_rich_menu_id_of_user(&transport.configuration(), "U1")
+        .await
+        .unwrap();
+
+    assert_eq!(linked.unwrap().rich_menu_id, "richmenu-a");
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url.path(), "/v2/bot/user/U1/richmenu");
+}
+
+#[tokio::test]
+async fn get_rich_menu_id_of_an_unlinked_user_is_none() {
+    let transport = StubTransport::new([reply(404, r#"{"message":"the user has no richmenu"}"#)]);
+
+    let unlinked = get_rich_menu_id_of_user(&transport.configuration(), "U2")
+        .await
+        .unwrap();
+
+    assert!(unlinked.is_none());
+}
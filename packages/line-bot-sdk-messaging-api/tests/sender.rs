@@ -0,0 +1,69 @@
+use line_bot_sdk_messaging_api::models::{Message, Sender, TextMessage, ValidationError};
+use serde_json::json;
+
+fn named(name: &str) -> Result<Sender, ValidationError> {
+    Sender::new(Some(name.to_string()), None)
+}
+
+#[test]
+fn name_of_20_characters_is_accepted() {
+    assert!(named(&"あ".repeat(20)).is_ok());
+}
+
+#[test]
+fn name_of_21_characters_is_rejected() {
+    assert_eq!(
+        named(&"a".repeat(21)),
+        Err(ValidationError::SenderNameTooLong {
+            max: 20,
+            actual: 21
+        })
+    );
+}
+
+#[test]
+fn name_with_the_line_keyword_is_rejected() {
+    for name in ["LINE", "LINE Support", "Official-Line", "line"] {
+        assert_eq!(
+            named(name),
+            Err(ValidationError::ReservedSenderName(name.to_string())),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn line_inside_another_word_is_accepted() {
+    for name in ["Caroline", "Timeline bot", "Airliner"] {
+        assert!(named(name).is_ok(), "{name}");
+    }
+}
+
+#[test]
+fn non_https_icon_url_is_rejected() {
+    assert_eq!(
+        Sender::new(None, Some("http://example.com/icon.png".to_string())),
+        Err(ValidationError::InsecureUrl(
+            "http://example.com/icon.png".to_string()
+        ))
+    );
+}
+
+#[test]
+fn valid_sender_serializes_on_a_message() {
+    let sender = Sender::new(
+        Some("Cony".to_string()),
+        Some("https://example.com/cony.png".to_string()),
+    )
+    .unwrap();
+    let message: Message = TextMessage::new("Hello").with_sender(sender).into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "type": "text",
+            "text": "Hello",
+            "sender": {"name": "Cony", "iconUrl": "https://example.com/cony.png"}
+        })
+    );
+}
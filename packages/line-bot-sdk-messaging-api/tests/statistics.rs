@@ -0,0 +1,79 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_number_of_sent_push_messages, get_number_of_sent_reply_messages, get_pnp_message_statistics,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::number_of_messages_response::Status;
+use line_bot_sdk_messaging_api::models::ValidationError;
+
+#[tokio::test]
+async fn ready_statistics_include_the_count() {
+    let transport = StubTransport::new([reply(200, r#"{"status":"ready","success":10000}"#)]);
+
+    let statistics = get_number_of_sent_reply_messages(&transport.configuration(), "20191231")
+        .await
+        .unwrap();
+
+    assert_eq!(statistics.status, Status::Ready);
+    assert_eq!(statistics.success, Some(10000));
+    assert_eq!(
+        transport.requests()[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/delivery/reply?date=20191231"
+    );
+}
+
+#[tokio::test]
+async fn unready_statistics_have_no_count() {
+    let transport = StubTransport::new([reply(200, r#"{"status":"unready"}"#)]);
+
+    let statistics = get_number_of_sent_push_messages(&transport.configuration(), "20191231")
+        .await
+        .unwrap();
+
+    assert_eq!(statistics.status, Status::Unready);
+    assert_eq!(statistics.success, None);
+}
+
+#[tokio::test]
+async fn malformed_dates_are_rejected_without_sending() {
+    let transport = StubTransport::new([]);
+    let configuration = transport.configuration();
+
+    for date in ["2019-12-31", "2019123", "20191301", "20191200", "2019123a"] {
+        let err = get_number_of_sent_reply_messages(&configuration, date)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(ValidationError::InvalidDate(d)) if d == date),
+            "{date}: {err}"
+        );
+    }
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn pnp_statistics_take_a_yyyymmdd_date() {
+    let transport = StubTransport::new([reply(200, r#"{"status":"ready","success":3}"#)]);
+    let configuration = transport.configuration();
+
+    let err = get_pnp_message_statistics(&configuration, "2019-12-31")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::InvalidDate(_))
+    ));
+
+    let statistics = get_pnp_message_statistics(&configuration, "20191231")
+        .await
+        .unwrap();
+    assert_eq!(statistics.success, Some(3));
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/delivery/pnp?date=20191231"
+    );
+}
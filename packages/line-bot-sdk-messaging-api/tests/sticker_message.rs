@@ -0,0 +1,32 @@
+use line_bot_sdk_messaging_api::models::sticker_message::stickers;
+use line_bot_sdk_messaging_api::models::{Message, StickerMessage};
+use serde_json::json;
+
+#[test]
+fn sticker_message_serializes_package_and_sticker_ids() {
+    let (package_id, sticker_id) = stickers::REFERENCE_EXAMPLE;
+    let message: Message = StickerMessage::new(package_id, sticker_id).into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({"type": "sticker", "packageId": "446", "stickerId": "1988"})
+    );
+}
+
+#[test]
+fn package_constants_match_the_sticker_list() {
+    assert_eq!(stickers::BROWN_CONY_SALLY_PACKAGE_ID, "11537");
+    assert_eq!(stickers::CHOCO_AND_FRIENDS_PACKAGE_ID, "11538");
+    assert_eq!(stickers::UNIVERSTAR_BT21_PACKAGE_ID, "11539");
+}
+
+#[test]
+fn package_constant_serializes_as_the_package_id() {
+    let message: Message =
+        StickerMessage::new(stickers::BROWN_CONY_SALLY_PACKAGE_ID, "52002734").into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({"type": "sticker", "packageId": "11537", "stickerId": "52002734"})
+    );
+}
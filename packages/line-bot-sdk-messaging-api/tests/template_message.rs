@@ -0,0 +1,165 @@
+use line_bot_sdk_messaging_api::models::{
+    Action, ButtonsTemplate, CarouselColumn, CarouselTemplate, ConfirmTemplate,
+    ImageCarouselColumn, ImageCarouselTemplate, Message, Template, TemplateMessage,
+    ValidationError,
+};
+use serde_json::json;
+
+fn round_trip(template: impl Into<Template>, expected: serde_json::Value) {
+    let message: Message = TemplateMessage::new("Template".to_string(), template.into()).into();
+
+    let json = serde_json::to_value(&message).unwrap();
+    assert_eq!(json["template"], expected);
+    let parsed: Message = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, message);
+}
+
+#[test]
+fn buttons_template_round_trips() {
+    let template = ButtonsTemplate::builder()
+        .thumbnail_image_url("https://example.com/bot/images/image.jpg")
+        .title("Menu")
+        .text("Please select")
+        .action(Action::postback("Buy", "action=buy&itemid=123", None).unwrap())
+        .action(Action::uri("View detail", "https://example.com/page/123"))
+        .build()
+        .unwrap();
+
+    round_trip(
+        template,
+        json!({
+            "type": "buttons",
+            "thumbnailImageUrl": "https://example.com/bot/images/image.jpg",
+            "title": "Menu",
+            "text": "Please select",
+            "actions": [
+                {"type": "postback", "label": "Buy", "data": "action=buy&itemid=123"},
+                {"type": "uri", "label": "View detail", "uri": "https://example.com/page/123"}
+            ]
+        }),
+    );
+}
+
+#[test]
+fn confirm_template_round_trips() {
+    let template = ConfirmTemplate::new(
+        "Are you sure?",
+        Action::message("Yes", "yes"),
+        Action::message("No", "no"),
+    );
+    assert!(template.validate().is_ok());
+
+    round_trip(
+        template,
+        json!({
+            "type": "confirm",
+            "text": "Are you sure?",
+            "actions": [
+                {"type": "message", "label": "Yes", "text": "yes"},
+                {"type": "message", "label": "No", "text": "no"}
+            ]
+        }),
+    );
+}
+
+#[test]
+fn carousel_template_round_trips() {
+    let template = CarouselTemplate::new(vec![
+        CarouselColumn::new("First".to_string(), vec![Action::message("One", "1")]),
+        CarouselColumn::new("Second".to_string(), vec![Action::message("Two", "2")]),
+    ]);
+    assert!(template.validate().is_ok());
+
+    round_trip(
+        template,
+        json!({
+            "type": "carousel",
+            "columns": [
+                {"text": "First", "actions": [{"type": "message", "label": "One", "text": "1"}]},
+                {"text": "Second", "actions": [{"type": "message", "label": "Two", "text": "2"}]}
+            ]
+        }),
+    );
+}
+
+#[test]
+fn image_carousel_template_round_trips() {
+    let template = ImageCarouselTemplate::new(vec![ImageCarouselColumn::new(
+        "https://example.com/bot/images/item1.jpg".to_string(),
+        Action::uri("View detail", "https://example.com/page/111"),
+    )]);
+
+    round_trip(
+        template,
+        json!({
+            "type": "image_carousel",
+            "columns": [{
+                "imageUrl": "https://example.com/bot/images/item1.jpg",
+                "action": {"type": "uri", "label": "View detail", "uri": "https://example.com/page/111"}
+            }]
+        }),
+    );
+}
+
+#[test]
+fn buttons_template_allows_at_most_four_actions() {
+    let builder = (0..5).fold(ButtonsTemplate::builder().text("Menu"), |builder, i| {
+        builder.action(Action::message(format!("{i}"), format!("{i}")))
+    });
+
+    assert_eq!(
+        builder.build(),
+        Err(ValidationError::InvalidActionCount {
+            min: 1,
+            max: 4,
+            actual: 5
+        })
+    );
+}
+
+#[test]
+fn carousel_template_requires_one_to_ten_columns() {
+    let column = || CarouselColumn::new("Column".to_string(), vec![Action::message("Go", "go")]);
+
+    assert_eq!(
+        CarouselTemplate::new(vec![]).validate(),
+        Err(ValidationError::InvalidColumnCount {
+            min: 1,
+            max: 10,
+            actual: 0
+        })
+    );
+    assert!(CarouselTemplate::new(vec![column(); 10]).validate().is_ok());
+    assert_eq!(
+        CarouselTemplate::new(vec![column(); 11]).validate(),
+        Err(ValidationError::InvalidColumnCount {
+            min: 1,
+            max: 10,
+            actual: 11
+        })
+    );
+}
+
+#[test]
+fn carousel_columns_allow_at_most_three_actions() {
+    let template = CarouselTemplate::new(vec![CarouselColumn::new(
+        "Column".to_string(),
+        vec![Action::message("Go", "go"); 4],
+    )]);
+
+    assert_eq!(
+        template.validate(),
+        Err(ValidationError::InvalidActionCount {
+            min: 1,
+            max: 3,
+            actual: 4
+        })
+    );
+}
+
+#[test]
+fn template_with_an_unknown_type_is_rejected() {
+    let err = serde_json::from_value::<Template>(json!({"type": "list", "text": "?"})).unwrap_err();
+
+    assert!(err.to_string().contains("unknown template type `list`"));
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{push_text, reply_messages, reply_text};
+use line_bot_sdk_messaging_api::models::text_message::TextSegment;
+use line_bot_sdk_messaging_api::models::{Message, QuickReply, TextMessage};
+use serde_json::json;
+
+#[test]
+fn new_serializes_a_plain_text_message() {
+    let message: Message = TextMessage::new("hi").into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({"type": "text", "text": "hi"})
+    );
+}
+
+#[test]
+fn chainable_setters_add_quick_reply_and_quote_token() {
+    let quick_reply = QuickReply::builder()
+        .message_button("Yes", "yes")
+        .build()
+        .unwrap();
+    let message: Message = TextMessage::new("Are you sure?")
+        .with_quick_reply(quick_reply)
+        .with_quote_token("q3Plxr4AgKd...")
+        .into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "type": "text",
+            "text": "Are you sure?",
+            "quoteToken": "q3Plxr4AgKd...",
+            "quickReply": {
+                "items": [
+                    {"type": "action", "action": {"type": "message", "label": "Yes", "text": "yes"}}
+                ]
+            }
+        })
+    );
+}
+
+#[test]
+fn text_with_emoji_counts_utf16_units_after_non_ascii_text() {
+    let message = TextMessage::builder()
+        .text_with_emoji([
+            TextSegment::from("日本😀"),
+            ("5ac1bfd5040ab15980c9b435", "001").into(),
+            " é ".into(),
+            ("5ac1bfd5040ab15980c9b435", "002").into(),
+        ])
+        .build();
+
+    assert_eq!(message.text, "日本😀$ é $");
+    let indexes: Vec<_> = message
+        .emojis
+        .unwrap()
+        .iter()
+        .map(|emoji| emoji.index)
+        .collect();
+    // "日本" is 2 units, "😀" is a surrogate pair, and " é " is 3 more.
+    assert_eq!(indexes, [Some(4), Some(8)]);
+}
+
+#[test]
+fn text_with_emoji_serializes_the_emojis() {
+    let message: Message = TextMessage::builder()
+        .text_with_emoji([
+            ("5ac1bfd5040ab15980c9b435", "010").into(),
+            TextSegment::from("𠮷野家"),
+            ("5ac1bfd5040ab15980c9b435", "011").into(),
+        ])
+        .build()
+        .into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "type": "text",
+            "text": "$𠮷野家$",
+            "emojis": [
+                {"index": 0, "productId": "5ac1bfd5040ab15980c9b435", "emojiId": "010"},
+                {"index": 5, "productId": "5ac1bfd5040ab15980c9b435", "emojiId": "011"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn text_with_emoji_without_emojis_omits_the_field() {
+    let message = TextMessage::builder()
+        .text_with_emoji(["こんにちは"])
+        .build();
+
+    assert_eq!(message.text, "こんにちは");
+    assert_eq!(message.emojis, None);
+}
+
+#[tokio::test]
+async fn reply_text_sends_one_text_message() {
+    let transport = StubTransport::new([reply(200, r#"{"sentMessages":[]}"#)]);
+
+    reply_text(
+        &transport.configuration(),
+        "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+        "Hello",
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.path(), "/v2/bot/message/reply");
+    assert_eq!(
+        requests[0].json(),
+        json!({
+            "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+            "messages": [{"type": "text", "text": "Hello"}],
+        })
+    );
+}
+
+#[tokio::test]
+async fn reply_messages_sends_the_messages_in_order() {
+    let transport = StubTransport::new([reply(200, r#"{"sentMessages":[]}"#)]);
+
+    reply_messages(
+        &transport.configuration(),
+        "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+        [
+            TextMessage::new("one").into(),
+            TextMessage::new("two").into(),
+        ],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        transport.requests()[0].json(),
+        json!({
+            "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+            "messages": [{"type": "text", "text": "one"}, {"type": "text", "text": "two"}],
+        })
+    );
+}
+
+#[tokio::test]
+async fn push_text_sends_one_text_message() {
+    let transport = StubTransport::new([reply(200, r#"{"sentMessages":[]}"#)]);
+
+    push_text(&transport.configuration(), "U1", "Hello")
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.path(), "/v2/bot/message/push");
+    assert_eq!(
+        requests[0].json(),
+        json!({"to": "U1", "messages": [{"type": "text", "text": "Hello"}]})
+    );
+}
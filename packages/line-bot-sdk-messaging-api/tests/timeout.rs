@@ -0,0 +1,40 @@
+use std::time::{Duration, Instant};
+
+use line_bot_sdk_messaging_api::apis::configuration::Configuration;
+use line_bot_sdk_messaging_api::apis::messaging_api_api::get_bot_info;
+use line_bot_sdk_messaging_api::apis::Error;
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpListener;
+
+/// Accepts a connection and never answers it.
+async fn unresponsive_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_path = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = [0; 1024];
+        while stream.read(&mut buf).await.unwrap_or(0) > 0 {}
+    });
+    base_path
+}
+
+#[tokio::test]
+async fn slow_response_fails_with_a_timeout_error() {
+    let configuration = Configuration::builder()
+        .base_path(unresponsive_server().await)
+        .channel_access_token("YOUR_CHANNEL_ACCESS_TOKEN")
+        .timeout(Duration::from_millis(200))
+        .build();
+
+    let start = Instant::now();
+    let err = tokio::time::timeout(Duration::from_secs(10), get_bot_info(&configuration))
+        .await
+        .expect("the configured timeout should end the request")
+        .unwrap_err();
+
+    match err {
+        Error::Reqwest(e) => assert!(e.is_timeout(), "not a timeout: {e}"),
+        other => panic!("unexpected error: {other}"),
+    }
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{reply, reply_with_headers, StubTransport};
+use line_bot_sdk_messaging_api::apis::configuration::{Configuration, RetryPolicy};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::push_message;
+use line_bot_sdk_messaging_api::models::{PushMessageRequest, TextMessage};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt as _};
+use tracing_subscriber::registry::LookupSpan;
+
+type Fields = BTreeMap<String, String>;
+
+#[derive(Debug, PartialEq)]
+struct Captured {
+    name: String,
+    fields: Fields,
+}
+
+/// Records closed spans and `warn` events along with their fields.
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<Captured>>>,
+    warnings: Arc<Mutex<Vec<Fields>>>,
+}
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Capture {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::new();
+        attrs.record(&mut Visitor(&mut fields));
+        ctx.span(id).unwrap().extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        values.record(&mut Visitor(extensions.get_mut::<Fields>().unwrap()));
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == Level::WARN {
+            let mut fields = Fields::new();
+            event.record(&mut Visitor(&mut fields));
+            self.warnings.lock().unwrap().push(fields);
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let fields = span.extensions_mut().remove::<Fields>().unwrap();
+        self.spans.lock().unwrap().push(Captured {
+            name: span.name().to_string(),
+            fields,
+        });
+    }
+}
+
+fn fields(pairs: &[(&str, &str)]) -> Fields {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn push_message_emits_a_line_api_request_span() {
+    let capture = Capture::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+    let transport = StubTransport::new([
+        reply(500, "{}"),
+        reply_with_headers(
+            200,
+            &[("x-line-request-id", "push-request-id")],
+            r#"{"sentMessages":[{"id":"461230966842064897"}]}"#,
+        ),
+    ]);
+    let configuration = Configuration {
+        retry_policy: Some(RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            ..RetryPolicy::default()
+        }),
+        ..transport.configuration()
+    };
+    let request = PushMessageRequest::new("U1".to_string(), vec![TextMessage::new("Hi").into()]);
+
+    push_message(&configuration, request, None).await.unwrap();
+
+    assert_eq!(
+        *capture.spans.lock().unwrap(),
+        [Captured {
+            name: "line_api_request".to_string(),
+            fields: fields(&[
+                ("method", "POST"),
+                ("endpoint", "/v2/bot/message/push"),
+                ("status", "200"),
+                ("retries", "1"),
+                ("request_id", "push-request-id"),
+            ]),
+        }]
+    );
+    assert_eq!(
+        *capture.warnings.lock().unwrap(),
+        [fields(&[
+            ("message", "retrying LINE API request"),
+            ("status", "500"),
+            ("retry", "1"),
+            ("delay_ms", "1"),
+        ])]
+    );
+}
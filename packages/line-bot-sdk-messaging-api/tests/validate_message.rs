@@ -0,0 +1,107 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    validate_push, validate_reply, ValidatePushError,
+};
+use line_bot_sdk_messaging_api::apis::Error;
+use line_bot_sdk_messaging_api::models::{
+    FlexBubble, FlexMessage, InvalidMessageProperty, MessageValidationError, TextMessage,
+    ValidateMessageRequest,
+};
+
+/// The response to a push with a valid text and a flex message missing its contents.
+const INVALID_FLEX: &str = r#"{
+    "message": "The request body has 2 error(s)",
+    "details": [
+        {"message": "must be specified", "property": "messages[1].contents"},
+        {"message": "may not be empty", "property": "messages[1].altText"}
+    ]
+}"#;
+
+#[tokio::test]
+async fn valid_messages_pass() {
+    let transport = StubTransport::new([reply(200, "{}")]);
+
+    validate_reply(
+        &transport.configuration(),
+        ValidateMessageRequest::new(vec![TextMessage::new("Hello").into()]),
+    )
+    .await
+    .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/message/validate/reply"
+    );
+    assert_eq!(
+        requests[0].json(),
+        serde_json::json!({"messages": [{"type": "text", "text": "Hello"}]})
+    );
+}
+
+#[tokio::test]
+async fn invalid_flex_message_reports_each_property() {
+    let transport = StubTransport::new([reply(400, INVALID_FLEX)]);
+    let request = ValidateMessageRequest::new(vec![
+        TextMessage::new("Hello").into(),
+        FlexMessage::new(String::new(), FlexBubble::builder().build().into()).into(),
+    ]);
+
+    let err = validate_push(&transport.configuration(), request)
+        .await
+        .unwrap_err();
+
+    let Error::ResponseError(content) = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert_eq!(content.status, 400);
+    let Some(ValidatePushError::Status400(error)) = content.entity else {
+        panic!("unexpected entity: {:?}", content.entity);
+    };
+    assert_eq!(
+        error,
+        MessageValidationError {
+            message: "The request body has 2 error(s)".to_string(),
+            details: vec![
+                InvalidMessageProperty {
+                    message_index: Some(1),
+                    property: Some("messages[1].contents".to_string()),
+                    message: Some("must be specified".to_string()),
+                },
+                InvalidMessageProperty {
+                    message_index: Some(1),
+                    property: Some("messages[1].altText".to_string()),
+                    message: Some("may not be empty".to_string()),
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "The request body has 2 error(s); messages[1].contents: must be specified; messages[1].altText: may not be empty"
+    );
+}
+
+#[test]
+fn properties_outside_messages_have_no_index() {
+    let error: MessageValidationError = serde_json::from_str(
+        r#"{"message":"The request body has 1 error(s)","details":[{"message":"invalid","property":"notificationDisabled"}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(error.details[0].message_index, None);
+}
+
+#[test]
+fn round_trips_through_the_error_body() {
+    let error: MessageValidationError = serde_json::from_str(INVALID_FLEX).unwrap();
+
+    let json = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::from_str::<serde_json::Value>(INVALID_FLEX).unwrap()
+    );
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{reply, StubTransport};
+use line_bot_sdk_messaging_api::apis::messaging_api_api::{
+    get_webhook_endpoint, set_webhook_endpoint, test_webhook_endpoint,
+};
+use line_bot_sdk_messaging_api::models::{SetWebhookEndpointRequest, TestWebhookEndpointRequest};
+use serde_json::json;
+
+#[tokio::test]
+async fn set_and_get_webhook_endpoint() {
+    let transport = StubTransport::new([
+        reply(200, "{}"),
+        reply(
+            200,
+            r#"{"endpoint":"https://example.com/callback","active":true}"#,
+        ),
+    ]);
+    let configuration = transport.configuration();
+
+    set_webhook_endpoint(
+        &configuration,
+        SetWebhookEndpointRequest::new("https://example.com/callback".to_string()),
+    )
+    .await
+    .unwrap();
+    let endpoint = get_webhook_endpoint(&configuration).await.unwrap();
+
+    assert_eq!(endpoint.endpoint, "https://example.com/callback");
+    assert!(endpoint.active);
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "PUT");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/channel/webhook/endpoint"
+    );
+    assert_eq!(
+        requests[0].json(),
+        json!({"endpoint": "https://example.com/callback"})
+    );
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(requests[1].url, requests[0].url);
+}
+
+#[tokio::test]
+async fn test_webhook_endpoint_parses_a_successful_test() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{
+            "success": true,
+            "timestamp": "2020-09-30T05:38:20.031Z",
+            "statusCode": 200,
+            "reason": "OK",
+            "detail": "200"
+        }"#,
+    )]);
+
+    let result = test_webhook_endpoint(
+        &transport.configuration(),
+        Some(TestWebhookEndpointRequest {
+            endpoint: Some("https://example.com/callback".to_string()),
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.success, Some(true));
+    assert_eq!(result.status_code, 200);
+    assert_eq!(result.reason, "OK");
+    assert_eq!(result.detail, "200");
+    assert_eq!(result.timestamp, "2020-09-30T05:38:20.031Z");
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.line.me/v2/bot/channel/webhook/test"
+    );
+    assert_eq!(
+        requests[0].json(),
+        json!({"endpoint": "https://example.com/callback"})
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_endpoint_parses_a_failed_test() {
+    let transport = StubTransport::new([reply(
+        200,
+        r#"{
+            "success": false,
+            "timestamp": "2020-09-30T05:38:20.031Z",
+            "statusCode": 404,
+            "reason": "ERROR_STATUS_CODE",
+            "detail": "404 Not Found"
+        }"#,
+    )]);
+
+    let result = test_webhook_endpoint(&transport.configuration(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(result.success, Some(false));
+    assert_eq!(result.status_code, 404);
+    assert_eq!(result.reason, "ERROR_STATUS_CODE");
+    assert_eq!(result.detail, "404 Not Found");
+}
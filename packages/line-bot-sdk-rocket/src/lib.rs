@@ -0,0 +1,126 @@
+//! Rocket integration for LINE webhooks.
+//!
+//! [`LineWebhook`] is a data guard that reads the request body, verifies the
+//! `X-Line-Signature` header and deserializes the [`CallbackRequest`],
+//! failing the request before the handler runs if any step fails.
+
+use std::fmt;
+
+use line_bot_sdk_utils::signature::SIGNATURE_HEADER;
+use line_bot_sdk_webhook::{models::CallbackRequest, WebhookError, WebhookParser};
+use rocket::{
+    data::{Data, FromData, Limits, Outcome},
+    http::Status,
+    request::Request,
+};
+
+/// Data guard for a verified LINE webhook request.
+///
+/// The [`WebhookParser`] holding the channel secret is taken from the managed
+/// state. The body may be at most the `json` limit, 1 MiB by default.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_rocket::LineWebhook;
+/// use line_bot_sdk_webhook::WebhookParser;
+///
+/// #[rocket::post("/callback", data = "<webhook>")]
+/// fn callback(webhook: LineWebhook) -> String {
+///     format!("{} events", webhook.0.events.len())
+/// }
+///
+/// let rocket = rocket::build()
+///     .manage(WebhookParser::new("your_channel_secret").unwrap())
+///     .mount("/", rocket::routes![callback]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineWebhook(pub CallbackRequest);
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for LineWebhook {
+    type Error = LineWebhookRejection;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let parser = match req.rocket().state::<WebhookParser>() {
+            Some(parser) => parser,
+            None => return LineWebhookRejection::MissingParser.into_outcome(),
+        };
+        let signature = match req.headers().get_one(SIGNATURE_HEADER) {
+            Some(signature) => signature,
+            None => return LineWebhookRejection::MissingSignature.into_outcome(),
+        };
+
+        let limit = req.limits().get("json").unwrap_or(Limits::JSON);
+        let body = match data.open(limit).into_bytes().await {
+            Ok(body) if body.is_complete() => body.into_inner(),
+            Ok(_) => return LineWebhookRejection::TooLarge(limit.as_u64()).into_outcome(),
+            Err(e) => return LineWebhookRejection::Body(e.to_string()).into_outcome(),
+        };
+
+        match parser.parse(&body, signature) {
+            Ok(callback) => Outcome::Success(LineWebhook(callback)),
+            Err(e) => LineWebhookRejection::Webhook(e).into_outcome(),
+        }
+    }
+}
+
+/// Why a request was rejected by the [`LineWebhook`] guard.
+///
+/// Signature mismatches fail with `401 Unauthorized`, an oversized body with
+/// `413 Payload Too Large` and a missing [`WebhookParser`] with
+/// `500 Internal Server Error`; everything else fails with `400 Bad Request`.
+#[derive(Debug)]
+pub enum LineWebhookRejection {
+    /// No [`WebhookParser`] is managed by the Rocket instance.
+    MissingParser,
+    /// The `X-Line-Signature` header is missing.
+    MissingSignature,
+    /// The request body is larger than the limit, in bytes.
+    TooLarge(u64),
+    /// The request body could not be read.
+    Body(String),
+    /// The signature did not match or the body could not be parsed.
+    Webhook(WebhookError),
+}
+
+impl LineWebhookRejection {
+    /// The status the request fails with.
+    pub fn status(&self) -> Status {
+        match self {
+            LineWebhookRejection::MissingParser => Status::InternalServerError,
+            LineWebhookRejection::TooLarge(_) => Status::PayloadTooLarge,
+            LineWebhookRejection::Webhook(WebhookError::InvalidSignature) => Status::Unauthorized,
+            _ => Status::BadRequest,
+        }
+    }
+
+    fn into_outcome<'r>(self) -> Outcome<'r, LineWebhook> {
+        Outcome::Error((self.status(), self))
+    }
+}
+
+impl fmt::Display for LineWebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWebhookRejection::MissingParser => {
+                write!(f, "no WebhookParser is managed by the Rocket instance")
+            }
+            LineWebhookRejection::MissingSignature => write!(f, "missing X-Line-Signature header"),
+            LineWebhookRejection::TooLarge(limit) => {
+                write!(f, "request body is larger than {} bytes", limit)
+            }
+            LineWebhookRejection::Body(e) => write!(f, "error reading request body: {}", e),
+            LineWebhookRejection::Webhook(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for LineWebhookRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineWebhookRejection::Webhook(e) => Some(e),
+            _ => None,
+        }
+    }
+}
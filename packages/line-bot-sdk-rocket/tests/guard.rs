@@ -0,0 +1,86 @@
+use line_bot_sdk_rocket::LineWebhook;
+use line_bot_sdk_utils::signature::compute_signature;
+use line_bot_sdk_webhook::WebhookParser;
+use rocket::data::{Limits, ToByteUnit};
+use rocket::http::{Header, Status};
+use rocket::local::blocking::Client;
+use rocket::{Build, Rocket};
+
+const CHANNEL_SECRET: &str = "testsecret";
+const BODY: &str = r#"{"destination":"U0123456789abcdef0123456789abcdef","events":[]}"#;
+
+#[rocket::post("/callback", data = "<webhook>")]
+fn callback(webhook: LineWebhook) -> String {
+    format!(
+        "{} events for {}",
+        webhook.0.events.len(),
+        webhook.0.destination
+    )
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build().mount("/", rocket::routes![callback])
+}
+
+fn with_parser(rocket: Rocket<Build>) -> Rocket<Build> {
+    rocket.manage(WebhookParser::new(CHANNEL_SECRET).unwrap())
+}
+
+fn post(rocket: Rocket<Build>, signature: Option<String>) -> (Status, String) {
+    let client = Client::tracked(rocket).unwrap();
+    let mut request = client.post("/callback").body(BODY);
+    if let Some(signature) = signature {
+        request = request.header(Header::new("x-line-signature", signature));
+    }
+    let response = request.dispatch();
+    (
+        response.status(),
+        response.into_string().unwrap_or_default(),
+    )
+}
+
+fn valid_signature() -> Option<String> {
+    Some(compute_signature(BODY.as_bytes(), CHANNEL_SECRET))
+}
+
+#[test]
+fn valid_signature_reaches_the_handler() {
+    let (status, body) = post(with_parser(rocket()), valid_signature());
+
+    assert_eq!(status, Status::Ok);
+    assert_eq!(body, "0 events for U0123456789abcdef0123456789abcdef");
+}
+
+#[test]
+fn bad_signature_is_unauthorized() {
+    let signature = compute_signature(BODY.as_bytes(), "another channel secret");
+
+    let (status, _) = post(with_parser(rocket()), Some(signature));
+
+    assert_eq!(status, Status::Unauthorized);
+}
+
+#[test]
+fn missing_signature_is_a_bad_request() {
+    let (status, _) = post(with_parser(rocket()), None);
+
+    assert_eq!(status, Status::BadRequest);
+}
+
+#[test]
+fn body_over_the_json_limit_is_too_large() {
+    let limits = Limits::default().limit("json", 16.bytes());
+    let rocket = rocket::custom(rocket::Config::figment().merge(("limits", limits)))
+        .mount("/", rocket::routes![callback]);
+
+    let (status, _) = post(with_parser(rocket), valid_signature());
+
+    assert_eq!(status, Status::PayloadTooLarge);
+}
+
+#[test]
+fn missing_parser_is_a_server_error() {
+    let (status, _) = post(rocket(), valid_signature());
+
+    assert_eq!(status, Status::InternalServerError);
+}
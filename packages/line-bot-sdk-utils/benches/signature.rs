@@ -0,0 +1,35 @@
+//! Signature validation over webhook bodies of increasing size.
+//!
+//! Run with `cargo bench -p line-bot-sdk-utils`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use line_bot_sdk_utils::signature::{compute_signature, validate_signature, SignatureValidator};
+
+const CHANNEL_SECRET: &str = "channel_secret";
+
+fn signature(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signature");
+    for size in [256, 4 * 1024, 64 * 1024, 1024 * 1024] {
+        let body = vec![b'a'; size];
+        let signature = compute_signature(&body, CHANNEL_SECRET);
+        let validator = SignatureValidator::new(CHANNEL_SECRET).unwrap();
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("validate_signature", size),
+            &body,
+            |b, body| {
+                b.iter(|| validate_signature(black_box(body), CHANNEL_SECRET, &signature).unwrap())
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("SignatureValidator::validate", size),
+            &body,
+            |b, body| b.iter(|| validator.validate(black_box(body), &signature).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, signature);
+criterion_main!(benches);
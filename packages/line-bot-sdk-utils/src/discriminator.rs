@@ -0,0 +1,34 @@
+//! Deserialization of the generated enums whose variants are told apart by `type`.
+//!
+//! The generator emits these enums as `#[serde(untagged)]`, which picks the
+//! first variant whose fields happen to fit. Their variants share most of
+//! their fields, so that silently drops data or picks the wrong variant.
+//! The clients' `Deserialize` impls read the discriminator with [`read_type`]
+//! and deserialize the matching struct with [`from_value`] instead.
+//!
+//! # Example
+//!
+//! ```
+//! use line_bot_sdk_utils::discriminator::read_type;
+//! use serde_json::json;
+//!
+//! let r#type = read_type::<serde_json::Error>(&json!({"type": "text"})).unwrap();
+//! assert_eq!(r#type, "text");
+//! assert!(read_type::<serde_json::Error>(&json!({})).is_err());
+//! ```
+
+use serde::de::{DeserializeOwned, Error};
+use serde_json::Value;
+
+/// Returns the `type` discriminator of `value`.
+pub fn read_type<E: Error>(value: &Value) -> Result<String, E> {
+    match value.get("type").and_then(|t| t.as_str()) {
+        Some(r#type) => Ok(r#type.to_owned()),
+        None => Err(E::missing_field("type")),
+    }
+}
+
+/// Deserializes a variant's struct, reporting errors through the outer deserializer.
+pub fn from_value<T: DeserializeOwned, E: Error>(value: Value) -> Result<T, E> {
+    serde_json::from_value(value).map_err(E::custom)
+}
@@ -0,0 +1,196 @@
+//! JWT client assertions for issuing channel access tokens v2.1
+//!
+//! LINE authenticates a v2.1 token request with a JWT signed by the private
+//! key of an assertion signing key pair registered in the LINE Developers
+//! Console. This module builds that JWT from the private key in JWK format.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use openssl::bn::BigNum;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rsa::Rsa;
+use openssl::sign::Signer;
+use serde::{Deserialize, Serialize};
+
+/// `aud` claim LINE expects in the client assertion.
+pub const AUDIENCE: &str = "https://api.line.me/";
+
+/// Longest validity LINE accepts for the client assertion itself.
+pub const MAX_ASSERTION_EXP: Duration = Duration::from_secs(30 * 60);
+
+/// Longest validity LINE accepts for the issued channel access token.
+pub const MAX_TOKEN_EXP: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Builds an RS256-signed client assertion for `issue_channel_token_by_jwt`
+/// in `line-bot-sdk-channel-access-token`.
+///
+/// * `kid` - The key ID shown in the LINE Developers Console after registering the public key
+/// * `channel_id` - Your channel ID, used as both `iss` and `sub`
+/// * `private_key_jwk` - The private key of the assertion signing key pair, as a JWK
+/// * `token_exp` - How long the issued channel access token should be valid, at most [`MAX_TOKEN_EXP`]
+///
+/// The assertion itself expires after [`MAX_ASSERTION_EXP`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use line_bot_sdk_utils::jwt::build_client_assertion;
+///
+/// let private_key_jwk = std::fs::read_to_string("private_key.jwk").unwrap();
+/// let assertion = build_client_assertion(
+///     "your_kid",
+///     "your_channel_id",
+///     &private_key_jwk,
+///     Duration::from_secs(24 * 60 * 60),
+/// )
+/// .unwrap();
+/// ```
+pub fn build_client_assertion(
+    kid: &str,
+    channel_id: &str,
+    private_key_jwk: &str,
+    token_exp: Duration,
+) -> Result<String, JwtError> {
+    if token_exp > MAX_TOKEN_EXP {
+        return Err(JwtError::TokenExpTooLong(token_exp));
+    }
+    let jwk: RsaPrivateJwk = serde_json::from_str(private_key_jwk).map_err(JwtError::Json)?;
+    let key = jwk.to_pkey()?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let header = Header {
+        alg: "RS256",
+        typ: "JWT",
+        kid,
+    };
+    let claims = Claims {
+        iss: channel_id,
+        sub: channel_id,
+        aud: AUDIENCE,
+        exp: (now + MAX_ASSERTION_EXP).as_secs(),
+        token_exp: token_exp.as_secs(),
+    };
+
+    let signing_input = format!("{}.{}", encode_json(&header)?, encode_json(&claims)?);
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).map_err(JwtError::Signing)?;
+    signer
+        .update(signing_input.as_bytes())
+        .map_err(JwtError::Signing)?;
+    let signature = signer.sign_to_vec().map_err(JwtError::Signing)?;
+
+    Ok(format!(
+        "{}.{}",
+        signing_input,
+        URL_SAFE_NO_PAD.encode(signature)
+    ))
+}
+
+#[derive(Serialize)]
+struct Header<'a> {
+    alg: &'a str,
+    typ: &'a str,
+    kid: &'a str,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    exp: u64,
+    token_exp: u64,
+}
+
+/// The members of an RSA private key JWK (RFC 7518, section 6.3).
+#[derive(Deserialize)]
+struct RsaPrivateJwk {
+    kty: String,
+    n: String,
+    e: String,
+    d: String,
+    p: String,
+    q: String,
+    dp: String,
+    dq: String,
+    qi: String,
+}
+
+impl RsaPrivateJwk {
+    fn to_pkey(&self) -> Result<PKey<openssl::pkey::Private>, JwtError> {
+        if self.kty != "RSA" {
+            return Err(JwtError::InvalidKey(format!(
+                "expected an RSA key, got `{}`",
+                self.kty
+            )));
+        }
+        let rsa = Rsa::from_private_components(
+            decode_bignum("n", &self.n)?,
+            decode_bignum("e", &self.e)?,
+            decode_bignum("d", &self.d)?,
+            decode_bignum("p", &self.p)?,
+            decode_bignum("q", &self.q)?,
+            decode_bignum("dp", &self.dp)?,
+            decode_bignum("dq", &self.dq)?,
+            decode_bignum("qi", &self.qi)?,
+        )
+        .map_err(JwtError::Signing)?;
+        PKey::from_rsa(rsa).map_err(JwtError::Signing)
+    }
+}
+
+fn decode_bignum(member: &str, value: &str) -> Result<BigNum, JwtError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(value)
+        .map_err(|_| JwtError::InvalidKey(format!("`{}` is not base64url encoded", member)))?;
+    BigNum::from_slice(&bytes).map_err(JwtError::Signing)
+}
+
+fn encode_json<T: Serialize>(value: &T) -> Result<String, JwtError> {
+    let json = serde_json::to_vec(value).map_err(JwtError::Json)?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Errors that can occur when building a client assertion.
+#[derive(Debug)]
+pub enum JwtError {
+    /// The JWK is not a valid RSA private key.
+    InvalidKey(String),
+    /// The JWK is not valid JSON or is missing members.
+    Json(serde_json::Error),
+    /// The requested token lifetime is longer than LINE accepts.
+    TokenExpTooLong(Duration),
+    /// OpenSSL failed to load the key or sign the assertion.
+    Signing(openssl::error::ErrorStack),
+}
+
+impl std::fmt::Display for JwtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwtError::InvalidKey(reason) => write!(f, "Invalid private key: {}", reason),
+            JwtError::Json(e) => write!(f, "Invalid JWK: {}", e),
+            JwtError::TokenExpTooLong(token_exp) => write!(
+                f,
+                "Token expiration must be at most {} seconds, got {}",
+                MAX_TOKEN_EXP.as_secs(),
+                token_exp.as_secs()
+            ),
+            JwtError::Signing(e) => write!(f, "Failed to sign client assertion: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for JwtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JwtError::Json(e) => Some(e),
+            JwtError::Signing(e) => Some(e),
+            JwtError::InvalidKey(_) | JwtError::TokenExpTooLong(_) => None,
+        }
+    }
+}
@@ -1 +1,7 @@
+#[cfg(feature = "discriminator")]
+pub mod discriminator;
+#[cfg(feature = "jwt")]
+pub mod jwt;
+pub mod reply_token;
+pub mod retry_key;
 pub mod signature;
@@ -0,0 +1,90 @@
+//! Reply tokens that remember when they were received.
+//!
+//! A reply token can be used only once and expires shortly after the webhook
+//! is delivered. The SDK can't ask LINE whether a token is still valid, but it
+//! can tell how long ago the token arrived, which is enough to skip a reply
+//! that would almost certainly be rejected.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// A reply token along with the moment it was received.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use line_bot_sdk_utils::reply_token::ReplyToken;
+///
+/// let token = ReplyToken::new("nHuyWiB7yP5Zw52FIkcQobQuGDXCTA");
+/// assert!(!token.is_likely_expired());
+///
+/// let token = token.with_ttl(Duration::ZERO);
+/// assert!(token.is_likely_expired());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplyToken {
+    token: String,
+    received_at: Instant,
+    ttl: Duration,
+}
+
+impl ReplyToken {
+    /// How long a token is assumed to stay valid unless set with [`with_ttl`](Self::with_ttl).
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+    /// Wraps a token received just now.
+    pub fn new(token: impl Into<String>) -> ReplyToken {
+        ReplyToken::received_at(token, Instant::now())
+    }
+
+    /// Wraps a token received at `received_at`.
+    pub fn received_at(token: impl Into<String>, received_at: Instant) -> ReplyToken {
+        ReplyToken {
+            token: token.into(),
+            received_at,
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    /// Sets how long the token is assumed to stay valid.
+    pub fn with_ttl(mut self, ttl: Duration) -> ReplyToken {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.token
+    }
+
+    pub fn into_string(self) -> String {
+        self.token
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Time elapsed since the token was received.
+    pub fn age(&self) -> Duration {
+        self.received_at.elapsed()
+    }
+
+    /// Whether the token is at least as old as its TTL.
+    pub fn is_likely_expired(&self) -> bool {
+        self.age() >= self.ttl
+    }
+}
+
+impl AsRef<str> for ReplyToken {
+    fn as_ref(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Display for ReplyToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.token)
+    }
+}
@@ -0,0 +1,52 @@
+//! Retry keys for deduplicating message sends.
+//!
+//! The push, multicast, broadcast and narrowcast endpoints accept an
+//! `X-Line-Retry-Key` header. Requests sent again with the same key are
+//! accepted only once, so a request can be retried without risking a
+//! duplicate message.
+
+use std::fmt;
+
+use uuid::Uuid;
+
+/// A value for the `X-Line-Retry-Key` header: a random (version 4) UUID.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_utils::retry_key::RetryKey;
+///
+/// let key = RetryKey::new();
+/// assert_eq!(key.as_str().len(), 36);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RetryKey(String);
+
+impl RetryKey {
+    /// Generates a new random retry key.
+    pub fn new() -> RetryKey {
+        RetryKey(Uuid::new_v4().to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for RetryKey {
+    fn default() -> Self {
+        RetryKey::new()
+    }
+}
+
+impl AsRef<str> for RetryKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RetryKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -3,9 +3,10 @@
 //! This module provides functions to validate webhook request signatures
 //! to ensure requests are actually sent from LINE servers.
 
-use base64::{engine::general_purpose, Engine as _};
+use base64::{engine::general_purpose, DecodeSliceError, Engine as _};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
+use subtle::ConstantTimeEq;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -24,7 +25,7 @@ type HmacSha256 = Hmac<Sha256>;
 /// # Returns
 ///
 /// Returns `Ok(true)` if the signature is valid, `Ok(false)` if invalid,
-/// or an error if signature decoding fails.
+/// or an error if the channel secret is empty or signature decoding fails.
 ///
 /// # Example
 ///
@@ -46,33 +47,308 @@ pub fn validate_signature(
     channel_secret: &str,
     signature: &str,
 ) -> Result<bool, SignatureValidationError> {
-    // Decode the base64 signature
-    let expected_signature = general_purpose::STANDARD
-        .decode(signature)
-        .map_err(|_| SignatureValidationError::InvalidSignatureFormat)?;
+    SignatureValidator::new(channel_secret)?.validate(body, signature)
+}
 
-    // Create HMAC-SHA256 hasher with channel secret as key
-    let mut mac = HmacSha256::new_from_slice(channel_secret.as_bytes())
-        .map_err(|_| SignatureValidationError::InvalidKey)?;
+/// Validates a LINE webhook signature, reporting why it doesn't match.
+///
+/// [`validate_signature`] returns `Ok(false)` for any signature that doesn't
+/// match and an error for one that isn't base64. This returns a
+/// [`SignatureOutcome`] instead, which separates a wrong signature from a
+/// malformed one, to help debug requests that should have validated.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_utils::signature::{
+///     compute_signature, validate_signature_detailed, SignatureOutcome,
+/// };
+///
+/// let body = b"{\"events\":[]}";
+/// let signature = compute_signature(body, "secret");
+/// let check = |signature: &str| validate_signature_detailed(body, "secret", signature).unwrap();
+///
+/// assert_eq!(check(&signature), SignatureOutcome::Valid);
+/// assert_eq!(check(&compute_signature(body, "other")), SignatureOutcome::Mismatch);
+/// assert_eq!(check(&signature[..24]), SignatureOutcome::LengthMismatch);
+/// assert_eq!(check("not base64!"), SignatureOutcome::DecodeError);
+/// ```
+pub fn validate_signature_detailed(
+    body: &[u8],
+    channel_secret: &str,
+    signature: &str,
+) -> Result<SignatureOutcome, SignatureValidationError> {
+    Ok(SignatureValidator::new(channel_secret)?.validate_detailed(body, signature))
+}
 
-    // Update with request body
+/// Validates a LINE webhook signature that has already been base64-decoded.
+///
+/// Same as [`validate_signature`] without the decoding step, for frameworks
+/// that hand over the `X-Line-Signature` header as raw bytes.
+///
+/// # Example
+///
+/// ```
+/// use base64::{engine::general_purpose, Engine as _};
+/// use line_bot_sdk_utils::signature::{
+///     compute_signature, validate_signature, validate_signature_bytes,
+/// };
+///
+/// let body = b"{\"events\":[]}";
+/// let signature = compute_signature(body, "secret");
+/// let decoded = general_purpose::STANDARD.decode(&signature).unwrap();
+///
+/// assert!(validate_signature(body, "secret", &signature).unwrap());
+/// assert!(validate_signature_bytes(body, "secret", &decoded).unwrap());
+/// assert!(!validate_signature(body, "other", &signature).unwrap());
+/// assert!(!validate_signature_bytes(body, "other", &decoded).unwrap());
+/// ```
+pub fn validate_signature_bytes(
+    body: &[u8],
+    channel_secret: &str,
+    signature: &[u8],
+) -> Result<bool, SignatureValidationError> {
+    SignatureValidator::new(channel_secret)?.validate_bytes(body, signature)
+}
+
+/// Validates a LINE webhook signature against several channel secrets.
+///
+/// Returns `Ok(true)` if the signature matches any of `secrets`. While a
+/// channel secret is being reissued, pass both the old and the new one so
+/// webhooks keep validating whichever LINE signs with. Every secret is
+/// checked, so the time taken doesn't reveal which one matched.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_utils::signature::{compute_signature, validate_signature_multi};
+///
+/// let body = b"{\"events\":[]}";
+/// let signature = compute_signature(body, "new_secret");
+///
+/// assert!(validate_signature_multi(body, &["old_secret", "new_secret"], &signature).unwrap());
+/// assert!(!validate_signature_multi(body, &["old_secret"], &signature).unwrap());
+/// ```
+pub fn validate_signature_multi(
+    body: &[u8],
+    secrets: &[&str],
+    signature: &str,
+) -> Result<bool, SignatureValidationError> {
+    with_decoded_signature(signature, |signature| {
+        let mut matched = false;
+        for secret in secrets {
+            matched |= validate_signature_bytes(body, secret, signature)?;
+        }
+        Ok(matched)
+    })?
+}
+
+/// Base64-decodes `signature` and passes it to `f`.
+///
+/// Signatures up to 64 characters, which includes every well-formed one, are
+/// decoded on the stack so validating a request doesn't allocate.
+fn with_decoded_signature<R>(
+    signature: &str,
+    f: impl FnOnce(&[u8]) -> R,
+) -> Result<R, SignatureValidationError> {
+    let mut buf = [0; 48];
+    match general_purpose::STANDARD.decode_slice(signature, &mut buf) {
+        Ok(len) => Ok(f(&buf[..len])),
+        Err(DecodeSliceError::OutputSliceTooSmall) => general_purpose::STANDARD
+            .decode(signature)
+            .map(|decoded| f(&decoded))
+            .map_err(|_| SignatureValidationError::InvalidSignatureFormat),
+        Err(DecodeSliceError::DecodeError(_)) => {
+            Err(SignatureValidationError::InvalidSignatureFormat)
+        }
+    }
+}
+
+/// Computes the base64-encoded HMAC-SHA256 signature LINE would send for `body`.
+///
+/// Useful for logging what the SDK computed when a signature doesn't match.
+/// Use [`validate_signature`] for the actual check, which compares in constant time.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_utils::signature::compute_signature;
+///
+/// let body = b"{\"events\":[]}";
+/// println!("expected signature: {}", compute_signature(body, "your_channel_secret"));
+/// ```
+pub fn compute_signature(body: &[u8], channel_secret: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(channel_secret.as_bytes())
+        .expect("HMAC can take a key of any size");
     mac.update(body);
+    general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Validates LINE webhook signatures for a single channel.
+///
+/// The HMAC key is prepared once in [`SignatureValidator::new`] and reused for
+/// every request, so a validator can be kept in shared application state.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_utils::signature::SignatureValidator;
+///
+/// let validator = SignatureValidator::new("your_channel_secret").unwrap();
+///
+/// for (body, signature) in [(&b"{\"events\":[]}"[..], "base64_encoded_signature")] {
+///     println!("{:?}", validator.validate(body, signature));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SignatureValidator {
+    mac: HmacSha256,
+}
+
+impl SignatureValidator {
+    /// Creates a validator for the given channel secret.
+    ///
+    /// An empty secret is rejected with [`SignatureValidationError::EmptyKey`]:
+    /// HMAC would accept it, but no signature from LINE would ever match.
+    ///
+    /// ```
+    /// use line_bot_sdk_utils::signature::{validate_signature, SignatureValidationError};
+    ///
+    /// assert!(matches!(
+    ///     validate_signature(b"{\"events\":[]}", "", "c2lnbmF0dXJl"),
+    ///     Err(SignatureValidationError::EmptyKey)
+    /// ));
+    /// ```
+    pub fn new(channel_secret: &str) -> Result<Self, SignatureValidationError> {
+        if channel_secret.is_empty() {
+            return Err(SignatureValidationError::EmptyKey);
+        }
+        // Create HMAC-SHA256 hasher with channel secret as key
+        let mac = HmacSha256::new_from_slice(channel_secret.as_bytes())
+            .map_err(|_| SignatureValidationError::InvalidKey)?;
+        Ok(SignatureValidator { mac })
+    }
+
+    /// Validates `signature` against `body`; see [`validate_signature`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_utils::signature::{compute_signature, SignatureValidator};
+    ///
+    /// let body = b"{\"events\":[]}";
+    /// let validator = SignatureValidator::new("secret").unwrap();
+    ///
+    /// let signature = compute_signature(body, "secret");
+    /// assert!(validator.validate(body, &signature).unwrap());
+    ///
+    /// // Same length, different contents
+    /// let other = compute_signature(body, "other secret");
+    /// assert!(!validator.validate(body, &other).unwrap());
+    ///
+    /// // Different length
+    /// assert!(!validator.validate(body, "c2hvcnQ=").unwrap());
+    /// ```
+    pub fn validate(&self, body: &[u8], signature: &str) -> Result<bool, SignatureValidationError> {
+        with_decoded_signature(signature, |signature| {
+            self.compare(body, signature) == SignatureOutcome::Valid
+        })
+    }
 
-    // Get the computed signature
-    let computed_signature = mac.finalize().into_bytes();
+    /// Validates an already-decoded `signature` against `body`; see [`validate_signature_bytes`].
+    pub fn validate_bytes(
+        &self,
+        body: &[u8],
+        expected_signature: &[u8],
+    ) -> Result<bool, SignatureValidationError> {
+        Ok(self.compare(body, expected_signature) == SignatureOutcome::Valid)
+    }
 
-    // Constant-time comparison to prevent timing attacks
-    if expected_signature.len() != computed_signature.len() {
-        return Ok(false);
+    /// Validates `signature` against `body`, telling apart why it doesn't
+    /// match; see [`validate_signature_detailed`].
+    pub fn validate_detailed(&self, body: &[u8], signature: &str) -> SignatureOutcome {
+        with_decoded_signature(signature, |signature| self.compare(body, signature))
+            .unwrap_or(SignatureOutcome::DecodeError)
     }
 
-    // Use constant-time comparison
-    let mut result = 0u8;
-    for (a, b) in expected_signature.iter().zip(computed_signature.iter()) {
-        result |= a ^ b;
+    fn compare(&self, body: &[u8], expected_signature: &[u8]) -> SignatureOutcome {
+        // Update a copy of the keyed hasher with request body
+        let mut mac = self.mac.clone();
+        mac.update(body);
+
+        // Get the computed signature
+        let computed_signature = mac.finalize().into_bytes();
+
+        // The length of a signature isn't secret, only its contents are
+        if expected_signature.len() != computed_signature.len() {
+            return SignatureOutcome::LengthMismatch;
+        }
+
+        // Constant-time comparison to prevent timing attacks
+        if bool::from(expected_signature.ct_eq(&computed_signature)) {
+            SignatureOutcome::Valid
+        } else {
+            SignatureOutcome::Mismatch
+        }
     }
+}
 
-    Ok(result == 0)
+/// The result of [`validate_signature_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureOutcome {
+    /// The signature matches the body.
+    Valid,
+    /// The signature is well-formed but doesn't match the body.
+    Mismatch,
+    /// The signature decodes to fewer or more bytes than an HMAC-SHA256
+    /// digest, e.g. because the header was truncated.
+    LengthMismatch,
+    /// The signature is not valid base64.
+    DecodeError,
+}
+
+impl std::fmt::Debug for SignatureValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the key material
+        f.debug_struct("SignatureValidator").finish_non_exhaustive()
+    }
+}
+
+/// Name of the header LINE uses to carry the webhook signature.
+pub const SIGNATURE_HEADER: &str = "x-line-signature";
+
+/// Validates a LINE webhook signature taken from the request headers.
+///
+/// Looks up the `X-Line-Signature` header and delegates to [`validate_signature`].
+/// A missing header and a header that isn't valid UTF-8 are reported as
+/// distinct errors so handlers can respond accordingly.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_utils::signature::validate_signature_from_headers;
+///
+/// let body = b"{\"events\":[]}";
+/// let headers = http::HeaderMap::new();
+///
+/// match validate_signature_from_headers(body, "your_channel_secret", &headers) {
+///     Ok(true) => println!("Signature is valid"),
+///     Ok(false) => println!("Signature is invalid"),
+///     Err(e) => eprintln!("Error validating signature: {}", e),
+/// }
+/// ```
+pub fn validate_signature_from_headers(
+    body: &[u8],
+    channel_secret: &str,
+    headers: &http::HeaderMap,
+) -> Result<bool, SignatureValidationError> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .ok_or(SignatureValidationError::MissingSignatureHeader)?
+        .to_str()
+        .map_err(|_| SignatureValidationError::InvalidSignatureHeader)?;
+
+    validate_signature(body, channel_secret, signature)
 }
 
 /// Errors that can occur during signature validation
@@ -82,6 +358,12 @@ pub enum SignatureValidationError {
     InvalidSignatureFormat,
     /// The channel secret key is invalid
     InvalidKey,
+    /// The channel secret is empty, which usually means it isn't configured
+    EmptyKey,
+    /// The `X-Line-Signature` header is missing
+    MissingSignatureHeader,
+    /// The `X-Line-Signature` header is not valid UTF-8
+    InvalidSignatureHeader,
 }
 
 impl std::fmt::Display for SignatureValidationError {
@@ -96,6 +378,15 @@ impl std::fmt::Display for SignatureValidationError {
             SignatureValidationError::InvalidKey => {
                 write!(f, "Invalid channel secret key")
             }
+            SignatureValidationError::EmptyKey => {
+                write!(f, "Channel secret is empty")
+            }
+            SignatureValidationError::MissingSignatureHeader => {
+                write!(f, "Missing X-Line-Signature header")
+            }
+            SignatureValidationError::InvalidSignatureHeader => {
+                write!(f, "Invalid X-Line-Signature header: value must be UTF-8")
+            }
         }
     }
 }
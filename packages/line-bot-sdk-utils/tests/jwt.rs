@@ -0,0 +1,118 @@
+#![cfg(feature = "jwt")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use line_bot_sdk_utils::jwt::{build_client_assertion, JwtError, MAX_TOKEN_EXP};
+use openssl::bn::BigNum;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rsa::Rsa;
+use openssl::sign::Verifier;
+use serde_json::{json, Value};
+
+/// A 2048-bit RSA key generated for these tests only.
+const PRIVATE_KEY_JWK: &str = r#"{
+    "kty": "RSA",
+    "alg": "RS256",
+    "use": "sig",
+    "n": "tbTvvdciz2W72YuZs4UXVVsGoDlL7YKH8n6Cnf4RNtdSHGmNEg7tfn7z8jp3Tfgz6imzdLIi6r_0JgprjaFik82S5TMbNw91pWc0yMZ1Egqk74AbmYqih_NPDAO0mqKy0Nn7jardxkoJbgPJDqOCMs3lFItRAWRtJLVxhCfe_HBROq6DynIN1Yj9Nj81c1D3wc7Jkf9SAGl7pwV0MvpVeXh60uP82w7Xh6njBP4--G9MYcvkgLNxuEAhuPtLcUBtWziEi6Dp7VwxjE6TGaUZn04H0DpNYRf5mtSPBGRumBo3lTis0NVVS8NH8ZDdAv4UiM8355nvNv5vMAUKuSUjTQ",
+    "e": "AQAB",
+    "d": "CaABeQVd1y2U40OlSyCYKVHOiosubfDVoa843QSZxdVnCJFAwz2eL-6qnmChdDRbD6LQ2jQbbGLpnGA97T0ouxqPFuv7m5GKkk3iumXkyqVcskEPdTUmQdxOCh1iIjTPEwgQ7qKn57qBQF4b9tYLbgSBB3MPweqfa2vfDPiNs8JqeLfSXxgiLCUoorLfcJv1viTDyK2FDLvGuIzHdfgBML0KJTfmtqhhwFsmf_2KLp9UBJje2Vdz2qXcHA5W4za90ymKsmz4GZ6o-xhrU5XNyZ_xBpnACHC4Xg-jkMAuShYZ8mpoKLyy7QxSFmP-QoyQ3HRh6vsltAv6_SNNrxeoow",
+    "p": "256tPnbXJEN9nT8MrpmAc_E7aHLMalCjAxb5Fb3uf65ZkQ43AZiMb_3JncKygZ6GvrCsha4egEAYUt8pr1csCMPxk1yxwdkpOHqFkb90mmQj6x4RfybrKh4hM_aJkfqocPHstD3yqWcEytiP-pcpHSEA_Sx1yx9h4kwwUeWlDAs",
+    "q": "085_XYVFWbaroqPd5WQgpeBkoabKW2h6cebYHvuX74x-5g_j9lCM62k3CkpxCoL7kaSFSaSBwWsM32mVzt7HzsBmG-uFQlyh_HFdKVIkTUZ3mvEBV9F6SsPmOrBd-XdA4bNPUh_bNhtxhwmVzEuLj8H5yMVQHYPHikNq6dYXzQc",
+    "dp": "waprm2mwzom1e7Tduhoi7BsOCE0zdY0mMtNPU5zpdC6LTR774qxjK17hK_1E7IieygmCGWAyBWaADJQ-biCw9AnWPdy_4_FcKDHjF2N9r6gfBHa_dyEav0lSfkApkFE5uF-eHFOEaV4vG7FdRd2Go-k2tgs-1KAX8Ta-ZXnc0YE",
+    "dq": "dVYGNPbpjmOl_bmt6bBobbw7w1m07KKagpMR00gpyWkZu7W94eyJxLhK54aJzPr3Bp4PwNZDk7UMMpA5j5rXMfBgIMN-1V6f1kuvAn8g4TZSeCFfTxkbGGQiHQ0rYlHwPZiqLqK7O-REJjznIYAQVpltGQ5F7bPdTPlANz6FfO0",
+    "qi": "qOrU1ga8f1VPps0_0JF_PkoIdcusRLurUqda1wVyQUG3MhuHlEHZyTYjiCMVOOzKemRZu4kkHN4UlOMdmynpqIJRqRoqbOYG1vd0AqSRnUZt46r_dOvhC4knu7QCjCIQ70SD-c7jW66kVE7UplK_tFIPLV3CyeU0d1pkIrwYhJY"
+}"#;
+
+fn decode_part(part: &str) -> Value {
+    serde_json::from_slice(&URL_SAFE_NO_PAD.decode(part).unwrap()).unwrap()
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+fn assertion_has_the_header_and_claims_line_expects() {
+    let before = now();
+    let assertion = build_client_assertion(
+        "sDTOzw5wIfxxxxPEzcmeQA",
+        "1234567890",
+        PRIVATE_KEY_JWK,
+        Duration::from_secs(24 * 60 * 60),
+    )
+    .unwrap();
+    let after = now();
+
+    let parts: Vec<_> = assertion.split('.').collect();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(
+        decode_part(parts[0]),
+        json!({"alg": "RS256", "typ": "JWT", "kid": "sDTOzw5wIfxxxxPEzcmeQA"})
+    );
+
+    let claims = decode_part(parts[1]);
+    assert_eq!(claims["iss"], "1234567890");
+    assert_eq!(claims["sub"], "1234567890");
+    assert_eq!(claims["aud"], "https://api.line.me/");
+    assert_eq!(claims["token_exp"], 86400);
+    let exp = claims["exp"].as_u64().unwrap();
+    assert!((before + 30 * 60..=after + 30 * 60).contains(&exp));
+}
+
+#[test]
+fn assertion_signature_verifies_with_the_public_key() {
+    let assertion = build_client_assertion(
+        "kid",
+        "1234567890",
+        PRIVATE_KEY_JWK,
+        Duration::from_secs(60),
+    )
+    .unwrap();
+    let (signing_input, signature) = assertion.rsplit_once('.').unwrap();
+
+    let jwk: Value = serde_json::from_str(PRIVATE_KEY_JWK).unwrap();
+    let component = |name: &str| {
+        BigNum::from_slice(&URL_SAFE_NO_PAD.decode(jwk[name].as_str().unwrap()).unwrap()).unwrap()
+    };
+    let public_key =
+        PKey::from_rsa(Rsa::from_public_components(component("n"), component("e")).unwrap())
+            .unwrap();
+    let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key).unwrap();
+    verifier.update(signing_input.as_bytes()).unwrap();
+
+    assert!(verifier
+        .verify(&URL_SAFE_NO_PAD.decode(signature).unwrap())
+        .unwrap());
+}
+
+#[test]
+fn token_exp_longer_than_30_days_is_rejected() {
+    let err = build_client_assertion(
+        "kid",
+        "1234567890",
+        PRIVATE_KEY_JWK,
+        MAX_TOKEN_EXP + Duration::from_secs(1),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, JwtError::TokenExpTooLong(_)));
+}
+
+#[test]
+fn non_rsa_key_is_rejected() {
+    let err = build_client_assertion(
+        "kid",
+        "1234567890",
+        r#"{"kty": "EC", "n": "", "e": "", "d": "", "p": "", "q": "", "dp": "", "dq": "", "qi": ""}"#,
+        Duration::from_secs(60),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, JwtError::InvalidKey(_)));
+}
@@ -0,0 +1,46 @@
+use std::time::{Duration, Instant};
+
+use line_bot_sdk_utils::reply_token::ReplyToken;
+
+const TOKEN: &str = "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA";
+
+fn received_ago(age: Duration) -> ReplyToken {
+    ReplyToken::received_at(TOKEN, Instant::now().checked_sub(age).unwrap())
+}
+
+#[test]
+fn token_just_under_the_default_ttl_is_not_expired() {
+    let token = received_ago(ReplyToken::DEFAULT_TTL - Duration::from_secs(1));
+
+    assert!(!token.is_likely_expired());
+}
+
+#[test]
+fn token_exactly_at_the_default_ttl_is_expired() {
+    let token = received_ago(ReplyToken::DEFAULT_TTL);
+
+    assert!(token.age() >= ReplyToken::DEFAULT_TTL);
+    assert!(token.is_likely_expired());
+}
+
+#[test]
+fn custom_ttl_moves_the_boundary() {
+    let ttl = Duration::from_secs(10);
+
+    assert!(!received_ago(ttl - Duration::from_secs(1))
+        .with_ttl(ttl)
+        .is_likely_expired());
+    assert!(received_ago(ttl).with_ttl(ttl).is_likely_expired());
+    assert!(!received_ago(ttl)
+        .with_ttl(ReplyToken::DEFAULT_TTL)
+        .is_likely_expired());
+}
+
+#[test]
+fn token_keeps_its_string() {
+    let token = ReplyToken::new(TOKEN);
+
+    assert_eq!(token.as_str(), TOKEN);
+    assert_eq!(token.to_string(), TOKEN);
+    assert_eq!(token.into_string(), TOKEN);
+}
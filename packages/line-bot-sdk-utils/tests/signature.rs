@@ -0,0 +1,78 @@
+use http::{HeaderMap, HeaderValue};
+use line_bot_sdk_utils::signature::{
+    compute_signature, validate_signature_from_headers, SignatureValidationError, SIGNATURE_HEADER,
+};
+
+const CHANNEL_SECRET: &str = "testsecret";
+const BODY: &[u8] = br#"{"destination":"U0123456789abcdef0123456789abcdef","events":[]}"#;
+
+fn headers(signature: HeaderValue) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(SIGNATURE_HEADER, signature);
+    headers
+}
+
+#[test]
+fn from_headers_accepts_a_valid_signature() {
+    let signature = compute_signature(BODY, CHANNEL_SECRET);
+    let headers = headers(HeaderValue::from_str(&signature).unwrap());
+
+    assert!(validate_signature_from_headers(BODY, CHANNEL_SECRET, &headers).unwrap());
+}
+
+#[test]
+fn from_headers_rejects_an_invalid_signature() {
+    let signature = compute_signature(BODY, "another channel secret");
+    let headers = headers(HeaderValue::from_str(&signature).unwrap());
+
+    assert!(!validate_signature_from_headers(BODY, CHANNEL_SECRET, &headers).unwrap());
+}
+
+#[test]
+fn from_headers_reports_a_missing_header() {
+    assert!(matches!(
+        validate_signature_from_headers(BODY, CHANNEL_SECRET, &HeaderMap::new()),
+        Err(SignatureValidationError::MissingSignatureHeader)
+    ));
+}
+
+#[test]
+fn from_headers_reports_a_non_utf8_header() {
+    let headers = headers(HeaderValue::from_bytes(b"\xff\xfe").unwrap());
+
+    assert!(matches!(
+        validate_signature_from_headers(BODY, CHANNEL_SECRET, &headers),
+        Err(SignatureValidationError::InvalidSignatureHeader)
+    ));
+}
+
+#[test]
+fn from_headers_matches_the_header_name_case_insensitively() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "X-Line-Signature",
+        HeaderValue::from_str(&compute_signature(BODY, CHANNEL_SECRET)).unwrap(),
+    );
+
+    assert!(validate_signature_from_headers(BODY, CHANNEL_SECRET, &headers).unwrap());
+}
+
+#[test]
+fn compute_signature_matches_a_known_signature() {
+    // Computed independently with
+    // `printf '%s' "$BODY" | openssl dgst -sha256 -hmac testsecret -binary | base64`.
+    assert_eq!(
+        compute_signature(BODY, CHANNEL_SECRET),
+        "68T4hrXlCXQMCJqA3fGlBzTyBPmhsLT3g4xth3uGq7Y="
+    );
+}
+
+#[test]
+fn compute_signature_matches_the_rfc_4231_test_vector() {
+    // RFC 4231, test case 2: HMAC-SHA256 of "what do ya want for nothing?" keyed
+    // with "Jefe" is 5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843.
+    assert_eq!(
+        compute_signature(b"what do ya want for nothing?", "Jefe"),
+        "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+    );
+}
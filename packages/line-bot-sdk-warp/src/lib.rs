@@ -0,0 +1,112 @@
+//! Warp integration for LINE webhooks.
+//!
+//! [`line_webhook`] builds a filter that reads the request body, verifies the
+//! `X-Line-Signature` header and deserializes the [`CallbackRequest`].
+//! Requests failing any step are rejected with a [`LineWebhookRejection`],
+//! which [`recover`] turns into a `401` or `400` response.
+
+use std::fmt;
+
+use line_bot_sdk_utils::signature::SIGNATURE_HEADER;
+use line_bot_sdk_webhook::{models::CallbackRequest, WebhookError, WebhookParser};
+use warp::{
+    http::StatusCode,
+    hyper::body::Bytes,
+    reject::{Reject, Rejection},
+    reply::Reply,
+    Filter,
+};
+
+/// Filter extracting a verified LINE webhook request.
+///
+/// Fails if `channel_secret` can't be used to validate signatures, e.g.
+/// because it is empty.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_warp::{line_webhook, recover};
+/// use line_bot_sdk_webhook::models::CallbackRequest;
+/// use warp::Filter;
+///
+/// let route = warp::post()
+///     .and(warp::path("callback"))
+///     .and(line_webhook("your_channel_secret").unwrap())
+///     .map(|req: CallbackRequest| format!("{} events", req.events.len()))
+///     .recover(recover);
+/// ```
+pub fn line_webhook(
+    channel_secret: &str,
+) -> Result<impl Filter<Extract = (CallbackRequest,), Error = Rejection> + Clone, WebhookError> {
+    let parser = WebhookParser::new(channel_secret)?;
+    Ok(warp::header::optional::<String>(SIGNATURE_HEADER)
+        .and(warp::body::bytes())
+        .and_then(move |signature: Option<String>, body: Bytes| {
+            let result = parse(&parser, signature, &body).map_err(warp::reject::custom);
+            async move { result }
+        }))
+}
+
+fn parse(
+    parser: &WebhookParser,
+    signature: Option<String>,
+    body: &[u8],
+) -> Result<CallbackRequest, LineWebhookRejection> {
+    let signature = signature.ok_or(LineWebhookRejection::MissingSignature)?;
+    parser
+        .parse(body, &signature)
+        .map_err(LineWebhookRejection::Webhook)
+}
+
+/// Why a request was rejected by the [`line_webhook`] filter.
+///
+/// Signature mismatches respond with `401 Unauthorized`; everything else
+/// responds with `400 Bad Request`.
+#[derive(Debug)]
+pub enum LineWebhookRejection {
+    /// The `X-Line-Signature` header is missing.
+    MissingSignature,
+    /// The signature did not match or the body could not be parsed.
+    Webhook(WebhookError),
+}
+
+impl LineWebhookRejection {
+    /// The status code this rejection responds with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            LineWebhookRejection::Webhook(WebhookError::InvalidSignature) => {
+                StatusCode::UNAUTHORIZED
+            }
+            _ => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl fmt::Display for LineWebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineWebhookRejection::MissingSignature => write!(f, "missing X-Line-Signature header"),
+            LineWebhookRejection::Webhook(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for LineWebhookRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineWebhookRejection::Webhook(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Reject for LineWebhookRejection {}
+
+/// Turns a [`LineWebhookRejection`] into its response, for use with
+/// [`Filter::recover`]. Other rejections are passed on unchanged.
+pub async fn recover(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    match rejection.find::<LineWebhookRejection>() {
+        Some(e) => Ok(warp::reply::with_status(e.to_string(), e.status())),
+        None => Err(rejection),
+    }
+}
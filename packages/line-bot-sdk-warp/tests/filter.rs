@@ -0,0 +1,59 @@
+use line_bot_sdk_utils::signature::compute_signature;
+use line_bot_sdk_warp::{line_webhook, recover};
+use line_bot_sdk_webhook::models::CallbackRequest;
+use warp::http::StatusCode;
+use warp::Filter;
+
+const CHANNEL_SECRET: &str = "testsecret";
+const BODY: &str = r#"{"destination":"U0123456789abcdef0123456789abcdef","events":[]}"#;
+
+async fn call(signature: Option<&str>) -> (StatusCode, String) {
+    let route = warp::post()
+        .and(warp::path("callback"))
+        .and(line_webhook(CHANNEL_SECRET).unwrap())
+        .map(|req: CallbackRequest| format!("{} events for {}", req.events.len(), req.destination))
+        .recover(recover);
+
+    let mut request = warp::test::request()
+        .method("POST")
+        .path("/callback")
+        .header("content-type", "application/json");
+    if let Some(signature) = signature {
+        request = request.header("x-line-signature", signature);
+    }
+    let response = request.body(BODY).reply(&route).await;
+    let body = String::from_utf8(response.body().to_vec()).unwrap();
+    (response.status(), body)
+}
+
+#[tokio::test]
+async fn valid_signature_reaches_the_handler() {
+    let signature = compute_signature(BODY.as_bytes(), CHANNEL_SECRET);
+
+    let (status, body) = call(Some(&signature)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "0 events for U0123456789abcdef0123456789abcdef");
+}
+
+#[tokio::test]
+async fn bad_signature_is_unauthorized() {
+    let signature = compute_signature(BODY.as_bytes(), "another channel secret");
+
+    let (status, _) = call(Some(&signature)).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn missing_signature_is_a_bad_request() {
+    let (status, body) = call(None).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, "missing X-Line-Signature header");
+}
+
+#[test]
+fn empty_channel_secret_is_rejected() {
+    assert!(line_webhook("").is_err());
+}
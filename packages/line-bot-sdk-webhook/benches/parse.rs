@@ -0,0 +1,73 @@
+//! Owned versus borrowed parsing of a webhook body with many message events.
+//!
+//! Run with `cargo bench -p line-bot-sdk-webhook`. The number of allocations
+//! per parse is printed before the timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use line_bot_sdk_webhook::models::{CallbackRequest, CallbackRequestRef};
+
+/// Counts allocations made through the global allocator.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const EVENT: &str = r#"{
+    "type": "message",
+    "message": {"type": "text", "id": "14353798921116", "text": "Hello, world", "quoteToken": "q3Plxr4AgKd"},
+    "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+    "deliveryContext": {"isRedelivery": false},
+    "timestamp": 1625665242211,
+    "source": {"type": "user", "userId": "U80696558e1aa831a4f4f6bbbf8a5d4ab"},
+    "replyToken": "757913772c4646b784d4b7ce46d12671",
+    "mode": "active"
+}"#;
+
+fn body(events: usize) -> Vec<u8> {
+    let events = vec![EVENT; events].join(",");
+    format!(r#"{{"destination":"U0123456789abcdef0123456789abcdef","events":[{events}]}}"#)
+        .into_bytes()
+}
+
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(black_box(f()));
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn parse(c: &mut Criterion) {
+    let body = body(100);
+    println!(
+        "allocations for 100 events: owned {}, borrowed {}",
+        allocations(|| serde_json::from_slice::<CallbackRequest>(&body).unwrap()),
+        allocations(|| CallbackRequestRef::from_slice(&body).unwrap()),
+    );
+
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("owned", |b| {
+        b.iter(|| serde_json::from_slice::<CallbackRequest>(black_box(&body)).unwrap())
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| CallbackRequestRef::from_slice(black_box(&body)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,197 @@
+//! Routing webhook events to per-type handler methods.
+
+use std::future::Future;
+
+use crate::models::{self, CallbackRequest, Event, MessageContent};
+
+/// Callbacks for the webhook events a bot handles.
+///
+/// Every method defaults to doing nothing, so an implementation only
+/// overrides the events it cares about. Events without a dedicated method,
+/// including types this SDK doesn't know yet, go to [`on_other`](Self::on_other).
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_webhook::dispatch::{dispatch, EventHandler};
+/// use line_bot_sdk_webhook::models::{CallbackRequest, FollowEvent, MessageEvent, TextMessageContent};
+///
+/// struct EchoBot;
+///
+/// impl EventHandler for EchoBot {
+///     async fn on_text_message(&self, event: &MessageEvent, message: &TextMessageContent) {
+///         println!("{:?} said {}", event.reply_token, message.text);
+///     }
+///
+///     async fn on_follow(&self, event: &FollowEvent) {
+///         println!("followed, reply token {}", event.reply_token);
+///     }
+/// }
+///
+/// async fn handle(callback: &CallbackRequest) {
+///     dispatch(&EchoBot, callback).await;
+/// }
+/// ```
+pub trait EventHandler: Sync {
+    fn on_text_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::TextMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_image_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::ImageMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_video_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::VideoMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_audio_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::AudioMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_file_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::FileMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_location_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::LocationMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_sticker_message(
+        &self,
+        _event: &models::MessageEvent,
+        _message: &models::StickerMessageContent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_unsend(&self, _event: &models::UnsendEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_follow(&self, _event: &models::FollowEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_unfollow(&self, _event: &models::UnfollowEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_join(&self, _event: &models::JoinEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_leave(&self, _event: &models::LeaveEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_member_joined(
+        &self,
+        _event: &models::MemberJoinedEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_member_left(&self, _event: &models::MemberLeftEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_postback(&self, _event: &models::PostbackEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_video_play_complete(
+        &self,
+        _event: &models::VideoPlayCompleteEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_beacon(&self, _event: &models::BeaconEvent) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    fn on_account_link(
+        &self,
+        _event: &models::AccountLinkEvent,
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Any event without a dedicated method above.
+    fn on_other(&self, _event: &Event) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+/// Calls the matching [`EventHandler`] method for each event of `callback`, in order.
+pub async fn dispatch<H: EventHandler>(handler: &H, callback: &CallbackRequest) {
+    for event in &callback.events {
+        dispatch_event(handler, event).await;
+    }
+}
+
+/// Calls the [`EventHandler`] method matching `event`.
+pub async fn dispatch_event<H: EventHandler>(handler: &H, event: &Event) {
+    match event {
+        Event::MessageEvent(event) => match event.message.as_ref() {
+            MessageContent::TextMessageContent(message) => {
+                handler.on_text_message(event, message).await
+            }
+            MessageContent::ImageMessageContent(message) => {
+                handler.on_image_message(event, message).await
+            }
+            MessageContent::VideoMessageContent(message) => {
+                handler.on_video_message(event, message).await
+            }
+            MessageContent::AudioMessageContent(message) => {
+                handler.on_audio_message(event, message).await
+            }
+            MessageContent::FileMessageContent(message) => {
+                handler.on_file_message(event, message).await
+            }
+            MessageContent::LocationMessageContent(message) => {
+                handler.on_location_message(event, message).await
+            }
+            MessageContent::StickerMessageContent(message) => {
+                handler.on_sticker_message(event, message).await
+            }
+        },
+        Event::UnsendEvent(event) => handler.on_unsend(event).await,
+        Event::FollowEvent(event) => handler.on_follow(event).await,
+        Event::UnfollowEvent(event) => handler.on_unfollow(event).await,
+        Event::JoinEvent(event) => handler.on_join(event).await,
+        Event::LeaveEvent(event) => handler.on_leave(event).await,
+        Event::MemberJoinedEvent(event) => handler.on_member_joined(event).await,
+        Event::MemberLeftEvent(event) => handler.on_member_left(event).await,
+        Event::PostbackEvent(event) => handler.on_postback(event).await,
+        Event::VideoPlayCompleteEvent(event) => handler.on_video_play_complete(event).await,
+        Event::BeaconEvent(event) => handler.on_beacon(event).await,
+        Event::AccountLinkEvent(event) => handler.on_account_link(event).await,
+        _ => handler.on_other(event).await,
+    }
+}
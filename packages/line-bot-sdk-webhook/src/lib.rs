@@ -8,4 +8,8 @@ extern crate serde_repr;
 extern crate url;
 
 pub mod apis;
+pub mod dispatch;
 pub mod models;
+pub mod parse;
+
+pub use parse::{parse_webhook, WebhookError, WebhookParser};
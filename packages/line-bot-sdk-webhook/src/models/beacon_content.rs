@@ -32,6 +32,20 @@ impl BeaconContent {
             dm: None,
         }
     }
+
+    /// Decodes the hex-encoded device message.
+    ///
+    /// Returns `None` when there is no device message or it isn't valid hex.
+    pub fn device_message_bytes(&self) -> Option<Vec<u8>> {
+        let dm = self.dm.as_deref()?;
+        if dm.len() % 2 != 0 || !dm.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        (0..dm.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&dm[i..i + 2], 16).ok())
+            .collect()
+    }
 }
 /// Type of beacon event.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -30,4 +30,76 @@ impl CallbackRequest {
             events,
         }
     }
+
+    /// User ID of the bot the events were sent to, for routing between bots.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// Parses a webhook body, rejecting it without looking at its contents
+    /// if it is larger than `max_bytes`.
+    ///
+    /// Bounds the memory a single request can make the parser allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_webhook::models::CallbackRequest;
+    /// use line_bot_sdk_webhook::WebhookError;
+    ///
+    /// let body = br#"{"destination":"U123","events":[]}"#;
+    ///
+    /// let callback = CallbackRequest::parse_with_limit(body, body.len()).unwrap();
+    /// assert_eq!(callback.destination, "U123");
+    ///
+    /// assert!(matches!(
+    ///     CallbackRequest::parse_with_limit(body, body.len() - 1),
+    ///     Err(WebhookError::PayloadTooLarge { max_bytes, actual_bytes })
+    ///         if max_bytes == body.len() - 1 && actual_bytes == body.len()
+    /// ));
+    /// ```
+    pub fn parse_with_limit(
+        body: &[u8],
+        max_bytes: usize,
+    ) -> Result<CallbackRequest, crate::WebhookError> {
+        crate::parse::check_size(body, max_bytes)?;
+        serde_json::from_slice(body).map_err(crate::WebhookError::ParseError)
+    }
+
+    /// Parses the events of a webhook body one by one, so that a malformed
+    /// event doesn't drop the others.
+    ///
+    /// Returns the events that parsed and an error for each one that didn't.
+    /// Deserializing with serde stays strict and fails on the first bad event.
+    pub fn parse_lenient(body: &[u8]) -> (Vec<models::Event>, Vec<models::EventParseError>) {
+        #[derive(Deserialize)]
+        struct RawCallbackRequest {
+            events: Vec<serde_json::Value>,
+        }
+
+        let raw: RawCallbackRequest = match serde_json::from_slice(body) {
+            Ok(raw) => raw,
+            Err(error) => {
+                let error = models::EventParseError {
+                    index: None,
+                    raw: None,
+                    error,
+                };
+                return (Vec::new(), vec![error]);
+            }
+        };
+        let mut events = Vec::with_capacity(raw.events.len());
+        let mut errors = Vec::new();
+        for (index, value) in raw.events.into_iter().enumerate() {
+            match serde_json::from_value(value.clone()) {
+                Ok(event) => events.push(event),
+                Err(error) => errors.push(models::EventParseError {
+                    index: Some(index),
+                    raw: Some(value),
+                    error,
+                }),
+            }
+        }
+        (events, errors)
+    }
 }
@@ -0,0 +1,209 @@
+//! A borrowing view of a webhook body for high-volume processing.
+//!
+//! [`CallbackRequestRef`] keeps the fields most handlers route on as
+//! `Cow<'a, str>` pointing into the request body. Strings without JSON escapes
+//! are borrowed rather than copied, so parsing a typical webhook only
+//! allocates the `events` vector. Use [`CallbackRequest`](crate::models::CallbackRequest)
+//! for the full, owned event objects.
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::models;
+
+/// The destination and events of a webhook body, borrowing from the body.
+///
+/// # Example
+///
+/// ```
+/// use line_bot_sdk_webhook::models::{CallbackRequest, CallbackRequestRef};
+///
+/// let body = br#"{
+///     "destination": "U0123456789abcdef0123456789abcdef",
+///     "events": [
+///         {
+///             "type": "message",
+///             "message": {"type": "text", "id": "14353798921116", "text": "Hello", "quoteToken": "q3Plxr4AgKd"},
+///             "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+///             "deliveryContext": {"isRedelivery": false},
+///             "timestamp": 1625665242211,
+///             "source": {"type": "user", "userId": "U80696558e1aa831a4f4f6bbbf8a5d4ab"},
+///             "replyToken": "757913772c4646b784d4b7ce46d12671",
+///             "mode": "active"
+///         },
+///         {
+///             "type": "postback",
+///             "postback": {"data": "action=buy&itemid=111"},
+///             "webhookEventId": "01FZ74ASS536FW97EX38NKCZQK",
+///             "deliveryContext": {"isRedelivery": true},
+///             "timestamp": 1625665242212,
+///             "source": {"type": "group", "groupId": "Ca56f94637c", "userId": "U4af4980629"},
+///             "replyToken": "b60d432864f44d079f6d8efe86cf404b",
+///             "mode": "active"
+///         }
+///     ]
+/// }"#;
+///
+/// let owned: CallbackRequest = serde_json::from_slice(body).unwrap();
+/// let borrowed = CallbackRequestRef::from_slice(body).unwrap();
+///
+/// assert_eq!(borrowed.destination, owned.destination);
+/// assert_eq!(borrowed.events.len(), owned.events.len());
+/// for (event, owned) in borrowed.events.iter().zip(&owned.events) {
+///     assert_eq!(event.r#type, owned.event_type());
+///     assert_eq!(event.webhook_event_id, owned.webhook_event_id());
+///     assert_eq!(event.timestamp, owned.timestamp());
+///     assert_eq!(event.reply_token.as_deref(), owned.reply_token());
+///     assert_eq!(event.is_redelivery(), owned.is_redelivery());
+///     let (source, owned_source) = (event.source.as_ref().unwrap(), owned.source().unwrap());
+///     assert_eq!(source.user_id.as_deref(), owned_source.user_id());
+///     assert_eq!(source.id(), owned_source.id());
+/// }
+///
+/// let text = owned.events[0].as_message_event().unwrap().message.as_text().unwrap();
+/// let message = borrowed.events[0].message.as_ref().unwrap();
+/// assert_eq!(message.id, text.id);
+/// assert_eq!(message.text.as_deref(), Some(text.text.as_str()));
+/// assert!(matches!(message.text, Some(std::borrow::Cow::Borrowed(_))));
+///
+/// let postback = &owned.events[1].as_postback_event().unwrap().postback;
+/// assert_eq!(borrowed.events[1].postback.as_ref().unwrap().data, postback.data);
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct CallbackRequestRef<'a> {
+    /// User ID of the bot that should receive the events.
+    #[serde(rename = "destination", borrow)]
+    pub destination: Cow<'a, str>,
+    #[serde(rename = "events", borrow)]
+    pub events: Vec<EventRef<'a>>,
+}
+
+impl<'a> CallbackRequestRef<'a> {
+    /// Parses a webhook body, borrowing strings from `body` where possible.
+    pub fn from_slice(body: &'a [u8]) -> Result<CallbackRequestRef<'a>, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+}
+
+/// The fields shared by every webhook event, plus the message or postback
+/// of message and postback events. Other fields are skipped.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct EventRef<'a> {
+    /// Type of the event, e.g. `message` or `postback`.
+    #[serde(rename = "type", borrow)]
+    pub r#type: Cow<'a, str>,
+    #[serde(rename = "source", borrow, default)]
+    pub source: Option<SourceRef<'a>>,
+    /// Time of the event in milliseconds.
+    #[serde(rename = "timestamp")]
+    pub timestamp: i64,
+    #[serde(rename = "mode")]
+    pub mode: models::EventMode,
+    #[serde(rename = "webhookEventId", borrow)]
+    pub webhook_event_id: Cow<'a, str>,
+    #[serde(rename = "deliveryContext", default)]
+    pub delivery_context: Option<models::DeliveryContext>,
+    #[serde(
+        rename = "replyToken",
+        borrow,
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub reply_token: Option<Cow<'a, str>>,
+    /// The message of a `message` event.
+    #[serde(rename = "message", borrow, default)]
+    pub message: Option<MessageRef<'a>>,
+    /// The postback of a `postback` event.
+    #[serde(rename = "postback", borrow, default)]
+    pub postback: Option<PostbackRef<'a>>,
+}
+
+impl EventRef<'_> {
+    /// Whether LINE is resending this event because an earlier delivery failed.
+    pub fn is_redelivery(&self) -> bool {
+        self.delivery_context
+            .as_ref()
+            .is_some_and(|context| context.is_redelivery)
+    }
+}
+
+/// Where an event came from. Only the IDs matching `type` are present.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SourceRef<'a> {
+    /// `user`, `group` or `room`.
+    #[serde(rename = "type", borrow)]
+    pub r#type: Cow<'a, str>,
+    #[serde(
+        rename = "userId",
+        borrow,
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub user_id: Option<Cow<'a, str>>,
+    #[serde(
+        rename = "groupId",
+        borrow,
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub group_id: Option<Cow<'a, str>>,
+    #[serde(
+        rename = "roomId",
+        borrow,
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub room_id: Option<Cow<'a, str>>,
+}
+
+impl SourceRef<'_> {
+    /// ID of the conversation: the group ID, room ID or user ID; see
+    /// [`Source::id`](crate::models::Source::id).
+    pub fn id(&self) -> &str {
+        self.group_id
+            .as_deref()
+            .or(self.room_id.as_deref())
+            .or(self.user_id.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// The message of a message event. `text` is only present for text messages.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct MessageRef<'a> {
+    /// Type of the message, e.g. `text` or `image`.
+    #[serde(rename = "type", borrow)]
+    pub r#type: Cow<'a, str>,
+    #[serde(rename = "id", borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(rename = "text", borrow, default, deserialize_with = "borrow_optional")]
+    pub text: Option<Cow<'a, str>>,
+    #[serde(
+        rename = "quoteToken",
+        borrow,
+        default,
+        deserialize_with = "borrow_optional"
+    )]
+    pub quote_token: Option<Cow<'a, str>>,
+}
+
+/// The postback of a postback event.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct PostbackRef<'a> {
+    #[serde(rename = "data", borrow)]
+    pub data: Cow<'a, str>,
+}
+
+/// Deserializes an optional string, borrowing it like a `Cow<'a, str>` field.
+///
+/// Serde only borrows `Cow<'a, str>` itself; inside an `Option` it always copies.
+fn borrow_optional<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Ok(Option::<Borrowed<'a>>::deserialize(deserializer)?.map(|borrowed| borrowed.0))
+}
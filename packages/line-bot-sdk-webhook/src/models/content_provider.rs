@@ -34,6 +34,15 @@ impl ContentProvider {
             preview_image_url: None,
         }
     }
+
+    /// URL of the file when it is hosted externally, or `None` when it has
+    /// to be downloaded with the message content API.
+    pub fn content_url(&self) -> Option<&str> {
+        match self.r#type {
+            Type::External => self.original_content_url.as_deref(),
+            Type::Line => None,
+        }
+    }
 }
 /// Provider of the image file.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -10,11 +10,13 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use line_bot_sdk_utils::reply_token::ReplyToken;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Event enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Event {
     MessageEvent(Box<models::MessageEvent>),
@@ -36,6 +38,223 @@ pub enum Event {
     BotSuspendedEvent(Box<models::BotSuspendedEvent>),
     BotResumedEvent(Box<models::BotResumedEvent>),
     PnpDeliveryCompletionEvent(Box<models::PnpDeliveryCompletionEvent>),
+    /// An event type this SDK doesn't know yet, kept as the raw JSON.
+    Unknown(serde_json::Value),
+}
+
+// Every event shares the same base fields, so an untagged match would pick the
+// first variant whose required fields happen to be present. Dispatch on the
+// `type` discriminator instead. Unrecognized types become `Event::Unknown` so that
+// a new event type doesn't fail the whole webhook batch.
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "message" => Event::MessageEvent(Box::new(from_value(value)?)),
+            "unsend" => Event::UnsendEvent(Box::new(from_value(value)?)),
+            "follow" => Event::FollowEvent(Box::new(from_value(value)?)),
+            "unfollow" => Event::UnfollowEvent(Box::new(from_value(value)?)),
+            "join" => Event::JoinEvent(Box::new(from_value(value)?)),
+            "leave" => Event::LeaveEvent(Box::new(from_value(value)?)),
+            "memberJoined" => Event::MemberJoinedEvent(Box::new(from_value(value)?)),
+            "memberLeft" => Event::MemberLeftEvent(Box::new(from_value(value)?)),
+            "postback" => Event::PostbackEvent(Box::new(from_value(value)?)),
+            "videoPlayComplete" => Event::VideoPlayCompleteEvent(Box::new(from_value(value)?)),
+            "beacon" => Event::BeaconEvent(Box::new(from_value(value)?)),
+            "accountLink" => Event::AccountLinkEvent(Box::new(from_value(value)?)),
+            "membership" => Event::MembershipEvent(Box::new(from_value(value)?)),
+            "module" => Event::ModuleEvent(Box::new(from_value(value)?)),
+            "activated" => Event::ActivatedEvent(Box::new(from_value(value)?)),
+            "deactivated" => Event::DeactivatedEvent(Box::new(from_value(value)?)),
+            "botSuspended" => Event::BotSuspendedEvent(Box::new(from_value(value)?)),
+            "botResumed" => Event::BotResumedEvent(Box::new(from_value(value)?)),
+            "delivery" => Event::PnpDeliveryCompletionEvent(Box::new(from_value(value)?)),
+            _ => Event::Unknown(value),
+        })
+    }
+}
+
+/// Expands `$expr` once per variant with `$event` bound to the inner struct,
+/// and `$unknown` for [`Event::Unknown`] with `$value` bound to the raw JSON.
+macro_rules! with_event {
+    ($self:expr, $event:ident => $expr:expr, $value:ident => $unknown:expr) => {
+        match $self {
+            Event::MessageEvent($event) => $expr,
+            Event::UnsendEvent($event) => $expr,
+            Event::FollowEvent($event) => $expr,
+            Event::UnfollowEvent($event) => $expr,
+            Event::JoinEvent($event) => $expr,
+            Event::LeaveEvent($event) => $expr,
+            Event::MemberJoinedEvent($event) => $expr,
+            Event::MemberLeftEvent($event) => $expr,
+            Event::PostbackEvent($event) => $expr,
+            Event::VideoPlayCompleteEvent($event) => $expr,
+            Event::BeaconEvent($event) => $expr,
+            Event::AccountLinkEvent($event) => $expr,
+            Event::MembershipEvent($event) => $expr,
+            Event::ModuleEvent($event) => $expr,
+            Event::ActivatedEvent($event) => $expr,
+            Event::DeactivatedEvent($event) => $expr,
+            Event::BotSuspendedEvent($event) => $expr,
+            Event::BotResumedEvent($event) => $expr,
+            Event::PnpDeliveryCompletionEvent($event) => $expr,
+            Event::Unknown($value) => $unknown,
+        }
+    };
+}
+
+impl Event {
+    /// Source user, group chat, or multi-person chat object, if any.
+    pub fn source(&self) -> Option<&models::Source> {
+        with_event!(self, event => event.source.as_deref(), _value => None)
+    }
+
+    /// Time of the event in milliseconds, or 0 if an unknown event has none.
+    pub fn timestamp(&self) -> i64 {
+        with_event!(self, event => event.timestamp, value => {
+            value.get("timestamp").and_then(|t| t.as_i64()).unwrap_or_default()
+        })
+    }
+
+    /// Reply token for the event, for event types that can be replied to.
+    pub fn reply_token(&self) -> Option<&str> {
+        match self {
+            Event::MessageEvent(event) => event.reply_token.as_deref(),
+            Event::PostbackEvent(event) => event.reply_token.as_deref(),
+            Event::AccountLinkEvent(event) => event.reply_token.as_deref(),
+            Event::FollowEvent(event) => Some(&event.reply_token),
+            Event::JoinEvent(event) => Some(&event.reply_token),
+            Event::MemberJoinedEvent(event) => Some(&event.reply_token),
+            Event::VideoPlayCompleteEvent(event) => Some(&event.reply_token),
+            Event::BeaconEvent(event) => Some(&event.reply_token),
+            Event::MembershipEvent(event) => Some(&event.reply_token),
+            _ => None,
+        }
+    }
+
+    /// Webhook event ID, a ULID that uniquely identifies the event.
+    ///
+    /// Stays the same when LINE redelivers the event, so it can be used to
+    /// deduplicate.
+    pub fn webhook_event_id(&self) -> &str {
+        with_event!(self, event => &event.webhook_event_id, value => {
+            value.get("webhookEventId").and_then(|id| id.as_str()).unwrap_or_default()
+        })
+    }
+
+    /// Whether this event is a redelivery of one that wasn't acknowledged before.
+    pub fn is_redelivery(&self) -> bool {
+        with_event!(self, event => event.delivery_context.is_redelivery, value => {
+            value
+                .pointer("/deliveryContext/isRedelivery")
+                .and_then(|r| r.as_bool())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Event type as sent by LINE, such as `message` or `follow`.
+    pub fn event_type(&self) -> &str {
+        with_event!(self, event => &event.r#type, value => {
+            value.get("type").and_then(|t| t.as_str()).unwrap_or_default()
+        })
+    }
+
+    /// Raw JSON of an event type this SDK doesn't know yet.
+    pub fn as_unknown(&self) -> Option<&serde_json::Value> {
+        match self {
+            Event::Unknown(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The reply token, stamped with the current time to track its expiry.
+    ///
+    /// Call this as soon as the webhook has been parsed, so the token's age
+    /// reflects when it was received.
+    pub fn tracked_reply_token(&self) -> Option<ReplyToken> {
+        self.reply_token().map(ReplyToken::new)
+    }
+
+    pub fn as_message_event(&self) -> Option<&models::MessageEvent> {
+        match self {
+            Event::MessageEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_postback_event(&self) -> Option<&models::PostbackEvent> {
+        match self {
+            Event::PostbackEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_unsend_event(&self) -> Option<&models::UnsendEvent> {
+        match self {
+            Event::UnsendEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_follow_event(&self) -> Option<&models::FollowEvent> {
+        match self {
+            Event::FollowEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_unfollow_event(&self) -> Option<&models::UnfollowEvent> {
+        match self {
+            Event::UnfollowEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_join_event(&self) -> Option<&models::JoinEvent> {
+        match self {
+            Event::JoinEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_leave_event(&self) -> Option<&models::LeaveEvent> {
+        match self {
+            Event::LeaveEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_member_joined_event(&self) -> Option<&models::MemberJoinedEvent> {
+        match self {
+            Event::MemberJoinedEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_member_left_event(&self) -> Option<&models::MemberLeftEvent> {
+        match self {
+            Event::MemberLeftEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_video_play_complete_event(&self) -> Option<&models::VideoPlayCompleteEvent> {
+        match self {
+            Event::VideoPlayCompleteEvent(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn as_beacon_event(&self) -> Option<&models::BeaconEvent> {
+        match self {
+            Event::BeaconEvent(event) => Some(event),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Event {
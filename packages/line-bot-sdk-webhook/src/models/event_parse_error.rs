@@ -0,0 +1,29 @@
+//! Errors collected by [`CallbackRequest::parse_lenient`](crate::models::CallbackRequest::parse_lenient).
+
+use std::fmt;
+
+/// An event, or the whole body, that couldn't be deserialized.
+#[derive(Debug)]
+pub struct EventParseError {
+    /// Position of the event in the `events` array, or `None` if the body
+    /// itself isn't a callback request.
+    pub index: Option<usize>,
+    /// Raw JSON of the event, or `None` if the body itself couldn't be parsed.
+    pub raw: Option<serde_json::Value>,
+    pub error: serde_json::Error,
+}
+
+impl fmt::Display for EventParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "error parsing event {}: {}", index, self.error),
+            None => write!(f, "error parsing webhook body: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for EventParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
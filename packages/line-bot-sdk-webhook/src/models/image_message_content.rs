@@ -47,4 +47,34 @@ impl ImageMessageContent {
             mark_as_read_token: None,
         }
     }
+
+    /// URL of the image when it is hosted externally, or `None` when it has
+    /// to be downloaded with the message content API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_webhook::models::ImageMessageContent;
+    ///
+    /// let line: ImageMessageContent = serde_json::from_str(
+    ///     r#"{"type": "image", "id": "354718705033693859", "quoteToken": "q3Plxr4AgKd",
+    ///         "contentProvider": {"type": "line"}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(line.content_url(), None);
+    ///
+    /// let external: ImageMessageContent = serde_json::from_str(
+    ///     r#"{"type": "image", "id": "354718705033693861", "quoteToken": "yHAz4Ua2wx7",
+    ///         "contentProvider": {
+    ///             "type": "external",
+    ///             "originalContentUrl": "https://example.com/original.jpg",
+    ///             "previewImageUrl": "https://example.com/preview.jpg"
+    ///         }}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(external.content_url(), Some("https://example.com/original.jpg"));
+    /// ```
+    pub fn content_url(&self) -> Option<&str> {
+        self.content_provider.content_url()
+    }
 }
@@ -10,17 +10,78 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// Mentionee enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Mentionee {
     UserMentionee(Box<models::UserMentionee>),
     AllMentionee(Box<models::AllMentionee>),
 }
 
+// A user mentionee matches the fields of an `@All` mentionee, so an untagged
+// match would always pick it. Dispatch on the `type` discriminator instead.
+impl<'de> Deserialize<'de> for Mentionee {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "user" => Mentionee::UserMentionee(Box::new(from_value(value)?)),
+            "all" => Mentionee::AllMentionee(Box::new(from_value(value)?)),
+            other => {
+                return Err(D::Error::custom(format!(
+                    "unknown mentionee type `{other}`"
+                )))
+            }
+        })
+    }
+}
+
+impl Mentionee {
+    /// Position of the mention in the text, in UTF-16 code units.
+    pub fn index(&self) -> i32 {
+        match self {
+            Mentionee::UserMentionee(mentionee) => mentionee.index,
+            Mentionee::AllMentionee(mentionee) => mentionee.index,
+        }
+    }
+
+    /// Length of the mention in the text, in UTF-16 code units.
+    pub fn length(&self) -> i32 {
+        match self {
+            Mentionee::UserMentionee(mentionee) => mentionee.length,
+            Mentionee::AllMentionee(mentionee) => mentionee.length,
+        }
+    }
+
+    /// ID of the mentioned user, if they consented to sharing their profile.
+    pub fn user_id(&self) -> Option<&str> {
+        match self {
+            Mentionee::UserMentionee(mentionee) => mentionee.user_id.as_deref(),
+            Mentionee::AllMentionee(_) => None,
+        }
+    }
+
+    /// Whether this mentions the bot receiving the webhook.
+    pub fn is_self(&self) -> bool {
+        match self {
+            Mentionee::UserMentionee(mentionee) => mentionee.is_self == Some(true),
+            Mentionee::AllMentionee(_) => false,
+        }
+    }
+
+    /// Whether this is an `@All` mention of the whole group.
+    pub fn is_all(&self) -> bool {
+        matches!(self, Mentionee::AllMentionee(_))
+    }
+}
+
 impl Default for Mentionee {
     fn default() -> Self {
         Self::UserMentionee(Box::new(Default::default()))
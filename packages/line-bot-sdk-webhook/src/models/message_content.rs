@@ -10,11 +10,12 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// MessageContent enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageContent {
     TextMessageContent(Box<models::TextMessageContent>),
@@ -26,6 +27,44 @@ pub enum MessageContent {
     StickerMessageContent(Box<models::StickerMessageContent>),
 }
 
+// Image, video, audio, and file contents share most of their fields, so an
+// untagged match is ambiguous. Dispatch on the `type` discriminator instead.
+impl<'de> Deserialize<'de> for MessageContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "text" => MessageContent::TextMessageContent(Box::new(from_value(value)?)),
+            "image" => MessageContent::ImageMessageContent(Box::new(from_value(value)?)),
+            "video" => MessageContent::VideoMessageContent(Box::new(from_value(value)?)),
+            "audio" => MessageContent::AudioMessageContent(Box::new(from_value(value)?)),
+            "file" => MessageContent::FileMessageContent(Box::new(from_value(value)?)),
+            "location" => MessageContent::LocationMessageContent(Box::new(from_value(value)?)),
+            "sticker" => MessageContent::StickerMessageContent(Box::new(from_value(value)?)),
+            other => return Err(D::Error::custom(format!("unknown message type `{other}`"))),
+        })
+    }
+}
+
+impl MessageContent {
+    pub fn as_text(&self) -> Option<&models::TextMessageContent> {
+        match self {
+            MessageContent::TextMessageContent(content) => Some(content),
+            _ => None,
+        }
+    }
+
+    pub fn as_sticker(&self) -> Option<&models::StickerMessageContent> {
+        match self {
+            MessageContent::StickerMessageContent(content) => Some(content),
+            _ => None,
+        }
+    }
+}
+
 impl Default for MessageContent {
     fn default() -> Self {
         Self::TextMessageContent(Box::new(Default::default()))
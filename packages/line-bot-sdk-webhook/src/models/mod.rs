@@ -18,6 +18,8 @@ pub mod bot_suspended_event;
 pub use self::bot_suspended_event::BotSuspendedEvent;
 pub mod callback_request;
 pub use self::callback_request::CallbackRequest;
+pub mod callback_request_ref;
+pub use self::callback_request_ref::CallbackRequestRef;
 pub mod chat_control;
 pub use self::chat_control::ChatControl;
 pub mod content_provider;
@@ -34,6 +36,8 @@ pub mod event;
 pub use self::event::Event;
 pub mod event_mode;
 pub use self::event_mode::EventMode;
+pub mod event_parse_error;
+pub use self::event_parse_error::EventParseError;
 pub mod file_message_content;
 pub use self::file_message_content::FileMessageContent;
 pub mod follow_detail;
@@ -90,6 +94,8 @@ pub mod postback_content;
 pub use self::postback_content::PostbackContent;
 pub mod postback_event;
 pub use self::postback_event::PostbackEvent;
+pub mod postback_params;
+pub use self::postback_params::PostbackParams;
 pub mod renewed_membership_content;
 pub use self::renewed_membership_content::RenewedMembershipContent;
 pub mod room_source;
@@ -17,7 +17,7 @@ pub struct PostbackContent {
     #[serde(rename = "data")]
     pub data: String,
     #[serde(rename = "params", skip_serializing_if = "Option::is_none")]
-    pub params: Option<std::collections::HashMap<String, String>>,
+    pub params: Option<models::PostbackParams>,
 }
 
 impl PostbackContent {
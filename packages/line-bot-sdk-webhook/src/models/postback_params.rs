@@ -0,0 +1,26 @@
+//! Typed `params` of a postback event.
+
+use serde::{Deserialize, Serialize};
+
+/// Parameters LINE attaches to a postback, depending on the action that sent it.
+///
+/// Datetime picker actions set one of `date`, `time` or `datetime`; rich menu
+/// switch actions set `new_rich_menu_alias_id` and `status`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostbackParams {
+    /// Date selected by the user, in `yyyy-MM-dd` format.
+    #[serde(rename = "date", skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Time selected by the user, in `HH:mm` format.
+    #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Date and time selected by the user, in `yyyy-MM-ddTHH:mm` format.
+    #[serde(rename = "datetime", skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<String>,
+    /// Alias ID of the rich menu the user switched to.
+    #[serde(rename = "newRichMenuAliasId", skip_serializing_if = "Option::is_none")]
+    pub new_rich_menu_alias_id: Option<String>,
+    /// Result of the rich menu switch, e.g. `SUCCESS`.
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
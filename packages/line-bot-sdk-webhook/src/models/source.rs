@@ -10,11 +10,12 @@
  */
 
 use crate::models;
-use serde::{Deserialize, Serialize};
+use line_bot_sdk_utils::discriminator::{from_value, read_type};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 
 /// Source enum using newtype pattern (wraps structs in Box)
 /// This is automatically generated from discriminated schemas
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Source {
     UserSource(Box<models::UserSource>),
@@ -22,6 +23,61 @@ pub enum Source {
     RoomSource(Box<models::RoomSource>),
 }
 
+// A user source matches the fields of every other source type, so an untagged
+// match would always pick it. Dispatch on the `type` discriminator instead.
+impl<'de> Deserialize<'de> for Source {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let r#type = read_type::<D::Error>(&value)?;
+        Ok(match r#type.as_str() {
+            "user" => Source::UserSource(Box::new(from_value(value)?)),
+            "group" => Source::GroupSource(Box::new(from_value(value)?)),
+            "room" => Source::RoomSource(Box::new(from_value(value)?)),
+            other => return Err(D::Error::custom(format!("unknown source type `{other}`"))),
+        })
+    }
+}
+
+impl Source {
+    /// ID of the user, if known. Group and room sources only include it for message events.
+    pub fn user_id(&self) -> Option<&str> {
+        match self {
+            Source::UserSource(source) => source.user_id.as_deref(),
+            Source::GroupSource(source) => source.user_id.as_deref(),
+            Source::RoomSource(source) => source.user_id.as_deref(),
+        }
+    }
+
+    pub fn group_id(&self) -> Option<&str> {
+        match self {
+            Source::GroupSource(source) => Some(&source.group_id),
+            _ => None,
+        }
+    }
+
+    pub fn room_id(&self) -> Option<&str> {
+        match self {
+            Source::RoomSource(source) => Some(&source.room_id),
+            _ => None,
+        }
+    }
+
+    /// ID of the conversation: the group ID, room ID or user ID.
+    ///
+    /// This is the ID to push replies to. It is empty only for a user source
+    /// without a user ID.
+    pub fn id(&self) -> &str {
+        match self {
+            Source::UserSource(source) => source.user_id.as_deref().unwrap_or_default(),
+            Source::GroupSource(source) => &source.group_id,
+            Source::RoomSource(source) => &source.room_id,
+        }
+    }
+}
+
 impl Default for Source {
     fn default() -> Self {
         Self::UserSource(Box::new(Default::default()))
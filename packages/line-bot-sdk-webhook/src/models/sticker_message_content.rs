@@ -66,6 +66,38 @@ impl StickerMessageContent {
             mark_as_read_token: None,
         }
     }
+
+    /// Keywords describing the sticker, empty if LINE sent none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_webhook::models::sticker_message_content::StickerResourceType;
+    /// use line_bot_sdk_webhook::models::MessageContent;
+    ///
+    /// let message: MessageContent = serde_json::from_str(
+    ///     r#"{
+    ///         "type": "sticker",
+    ///         "id": "1501597916",
+    ///         "quoteToken": "q3Plxr4AgKd",
+    ///         "stickerId": "52002738",
+    ///         "packageId": "11537",
+    ///         "stickerResourceType": "ANIMATION",
+    ///         "keywords": ["cony", "sally", "Staring", "hi", "whatsup", "line", "howdy", "HEY"]
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let sticker = message.as_sticker().unwrap();
+    /// assert_eq!((sticker.package_id.as_str(), sticker.sticker_id.as_str()), ("11537", "52002738"));
+    /// assert_eq!(sticker.sticker_resource_type, StickerResourceType::Animation);
+    /// assert!(sticker.sticker_resource_type.is_animated());
+    /// assert!(!sticker.sticker_resource_type.has_sound());
+    /// assert!(sticker.keywords().iter().any(|keyword| keyword == "hi"));
+    /// ```
+    pub fn keywords(&self) -> &[String] {
+        self.keywords.as_deref().unwrap_or_default()
+    }
 }
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -92,6 +124,21 @@ pub enum StickerResourceType {
     PerStickerText,
 }
 
+impl StickerResourceType {
+    /// Whether the sticker moves, including pop-up stickers.
+    pub fn is_animated(&self) -> bool {
+        matches!(
+            self,
+            Self::Animation | Self::AnimationSound | Self::Popup | Self::PopupSound
+        )
+    }
+
+    /// Whether the sticker plays a sound.
+    pub fn has_sound(&self) -> bool {
+        matches!(self, Self::Sound | Self::AnimationSound | Self::PopupSound)
+    }
+}
+
 impl Default for StickerResourceType {
     fn default() -> StickerResourceType {
         Self::Static
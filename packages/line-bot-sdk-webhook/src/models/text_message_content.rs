@@ -56,4 +56,103 @@ impl TextMessageContent {
             mark_as_read_token: None,
         }
     }
+
+    /// Whether the bot receiving the webhook is @-mentioned by name.
+    ///
+    /// An `@All` mention doesn't count; check [`models::Mentionee::is_all`]
+    /// for that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_webhook::models::TextMessageContent;
+    ///
+    /// let text: TextMessageContent = serde_json::from_str(
+    ///     r#"{
+    ///         "type": "text",
+    ///         "id": "444573844083572737",
+    ///         "quoteToken": "q3Plxr4AgKd",
+    ///         "text": "@example @All good morning",
+    ///         "mention": {
+    ///             "mentionees": [
+    ///                 {"index": 0, "length": 8, "type": "user", "userId": "U49585cd0d5", "isSelf": true},
+    ///                 {"index": 9, "length": 4, "type": "all"}
+    ///             ]
+    ///         }
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(text.mentions_self());
+    /// assert_eq!(text.mentioned_user_ids(), ["U49585cd0d5"]);
+    /// let mentionees = &text.mention.as_ref().unwrap().mentionees;
+    /// assert!(!mentionees[0].is_all());
+    /// assert!(mentionees[1].is_all());
+    /// assert_eq!((mentionees[1].index(), mentionees[1].length()), (9, 4));
+    /// ```
+    pub fn mentions_self(&self) -> bool {
+        self.mentionees().any(models::Mentionee::is_self)
+    }
+
+    /// IDs of the mentioned users that consented to sharing their profile,
+    /// in the order they are mentioned.
+    pub fn mentioned_user_ids(&self) -> Vec<&str> {
+        self.mentionees()
+            .filter_map(models::Mentionee::user_id)
+            .collect()
+    }
+
+    /// The text with the placeholders of LINE emoji, such as `(love)`, removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_webhook::models::TextMessageContent;
+    ///
+    /// let text: TextMessageContent = serde_json::from_str(
+    ///     r#"{
+    ///         "type": "text",
+    ///         "id": "325708",
+    ///         "quoteToken": "q3Plxr4AgKd",
+    ///         "text": "(love)Good morning(sun)",
+    ///         "emojis": [
+    ///             {"index": 0, "length": 6, "productId": "5ac1bfd5040ab15980c9b435", "emojiId": "001"},
+    ///             {"index": 18, "length": 5, "productId": "5ac1bfd5040ab15980c9b435", "emojiId": "002"}
+    ///         ]
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let emojis = text.emojis.as_ref().unwrap();
+    /// assert_eq!(emojis.len(), 2);
+    /// assert_eq!(emojis[1].emoji_id, "002");
+    /// assert_eq!(text.text_without_emojis(), "Good morning");
+    /// ```
+    pub fn text_without_emojis(&self) -> String {
+        let emojis = match self.emojis {
+            Some(ref emojis) if !emojis.is_empty() => emojis,
+            _ => return self.text.clone(),
+        };
+        // Emoji positions count UTF-16 code units, like the JavaScript string
+        // LINE built the text from.
+        let mut position = 0;
+        let mut text = String::with_capacity(self.text.len());
+        for c in self.text.chars() {
+            let in_emoji = emojis.iter().any(|emoji| {
+                (i64::from(emoji.index)..i64::from(emoji.index) + i64::from(emoji.length))
+                    .contains(&position)
+            });
+            if !in_emoji {
+                text.push(c);
+            }
+            position += c.len_utf16() as i64;
+        }
+        text
+    }
+
+    fn mentionees(&self) -> impl Iterator<Item = &models::Mentionee> {
+        self.mention
+            .iter()
+            .flat_map(|mention| mention.mentionees.iter())
+    }
 }
@@ -48,4 +48,10 @@ impl VideoMessageContent {
             mark_as_read_token: None,
         }
     }
+
+    /// URL of the video when it is hosted externally, or `None` when it has
+    /// to be downloaded with the message content API.
+    pub fn content_url(&self) -> Option<&str> {
+        self.content_provider.content_url()
+    }
 }
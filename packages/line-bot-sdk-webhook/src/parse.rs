@@ -0,0 +1,196 @@
+//! Verifying and parsing webhook request bodies in one step.
+
+use std::fmt;
+
+use line_bot_sdk_utils::signature::{SignatureValidationError, SignatureValidator};
+
+use crate::models::{CallbackRequest, CallbackRequestRef};
+
+/// Verifies the `X-Line-Signature` of a webhook request and parses its body.
+///
+/// The body is only deserialized once the signature has been checked, so a
+/// tampered request never reaches the JSON parser.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_webhook::parse_webhook;
+///
+/// let body = b"{\"destination\":\"U123\",\"events\":[]}";
+///
+/// match parse_webhook(body, "your_channel_secret", "base64_encoded_signature") {
+///     Ok(callback) => println!("{} events", callback.events.len()),
+///     Err(e) => eprintln!("Rejected webhook: {}", e),
+/// }
+/// ```
+pub fn parse_webhook(
+    body: &[u8],
+    channel_secret: &str,
+    signature: &str,
+) -> Result<CallbackRequest, WebhookError> {
+    WebhookParser::new(channel_secret)?.parse(body, signature)
+}
+
+/// Verifies and parses webhook requests for a single channel.
+///
+/// Construct it once with the channel secret and keep it in shared state;
+/// the HMAC key is prepared up front and reused for every request.
+///
+/// # Example
+///
+/// ```no_run
+/// use line_bot_sdk_webhook::WebhookParser;
+///
+/// let parser = WebhookParser::new("your_channel_secret").unwrap();
+/// let body = b"{\"destination\":\"U123\",\"events\":[]}";
+///
+/// match parser.parse(body, "base64_encoded_signature") {
+///     Ok(callback) => println!("{} events", callback.events.len()),
+///     Err(e) => eprintln!("Rejected webhook: {}", e),
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookParser {
+    validator: SignatureValidator,
+}
+
+impl WebhookParser {
+    /// Creates a parser for the given channel secret.
+    pub fn new(channel_secret: &str) -> Result<Self, WebhookError> {
+        Ok(WebhookParser {
+            validator: SignatureValidator::new(channel_secret)?,
+        })
+    }
+
+    /// Same as [`parse_webhook`], using this parser's channel secret.
+    pub fn parse(&self, body: &[u8], signature: &str) -> Result<CallbackRequest, WebhookError> {
+        if !self.validator.validate(body, signature)? {
+            return Err(WebhookError::InvalidSignature);
+        }
+        serde_json::from_slice(body).map_err(WebhookError::ParseError)
+    }
+
+    /// Same as [`parse`](Self::parse), but first rejects bodies larger than
+    /// `max_bytes`.
+    ///
+    /// The size is checked before the signature and the signature before the
+    /// JSON, so an oversized or forged request costs neither a full HMAC
+    /// nor a parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_bot_sdk_utils::signature::compute_signature;
+    /// use line_bot_sdk_webhook::{WebhookError, WebhookParser};
+    ///
+    /// let parser = WebhookParser::new("secret").unwrap();
+    /// let body = br#"{"destination":"U123","events":[]}"#;
+    /// let signature = compute_signature(body, "secret");
+    ///
+    /// let callback = parser.parse_checked(body, &signature, 1024).unwrap();
+    /// assert_eq!(callback.destination, "U123");
+    ///
+    /// // Oversized bodies are rejected even with a valid signature.
+    /// assert!(matches!(
+    ///     parser.parse_checked(body, &signature, 16),
+    ///     Err(WebhookError::PayloadTooLarge { max_bytes: 16, actual_bytes }) if actual_bytes == body.len()
+    /// ));
+    ///
+    /// let forged = compute_signature(body, "other");
+    /// assert!(matches!(
+    ///     parser.parse_checked(body, &forged, 1024),
+    ///     Err(WebhookError::InvalidSignature)
+    /// ));
+    ///
+    /// let malformed = br#"{"destination":"U123""#;
+    /// let signature = compute_signature(malformed, "secret");
+    /// assert!(matches!(
+    ///     parser.parse_checked(malformed, &signature, 1024),
+    ///     Err(WebhookError::ParseError(e)) if e.is_eof()
+    /// ));
+    /// ```
+    pub fn parse_checked(
+        &self,
+        body: &[u8],
+        signature: &str,
+        max_bytes: usize,
+    ) -> Result<CallbackRequest, WebhookError> {
+        check_size(body, max_bytes)?;
+        self.parse(body, signature)
+    }
+
+    /// Same as [`parse`](Self::parse), but borrows the event fields from
+    /// `body` instead of copying them; see [`CallbackRequestRef`].
+    pub fn parse_borrowed<'a>(
+        &self,
+        body: &'a [u8],
+        signature: &str,
+    ) -> Result<CallbackRequestRef<'a>, WebhookError> {
+        if !self.validator.validate(body, signature)? {
+            return Err(WebhookError::InvalidSignature);
+        }
+        CallbackRequestRef::from_slice(body).map_err(WebhookError::ParseError)
+    }
+}
+
+/// Rejects bodies larger than `max_bytes`.
+pub(crate) fn check_size(body: &[u8], max_bytes: usize) -> Result<(), WebhookError> {
+    if body.len() > max_bytes {
+        return Err(WebhookError::PayloadTooLarge {
+            max_bytes,
+            actual_bytes: body.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Errors returned by [`parse_webhook`].
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The signature does not match the body.
+    InvalidSignature,
+    /// The signature could not be checked, e.g. because it is not base64.
+    Signature(SignatureValidationError),
+    /// The signature is valid but the body is not a valid callback request.
+    ParseError(serde_json::Error),
+    /// The body is larger than the limit passed to
+    /// [`CallbackRequest::parse_with_limit`].
+    PayloadTooLarge {
+        max_bytes: usize,
+        actual_bytes: usize,
+    },
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::InvalidSignature => write!(f, "invalid webhook signature"),
+            WebhookError::Signature(e) => write!(f, "error validating webhook signature: {}", e),
+            WebhookError::ParseError(e) => write!(f, "error parsing webhook body: {}", e),
+            WebhookError::PayloadTooLarge {
+                max_bytes,
+                actual_bytes,
+            } => write!(
+                f,
+                "webhook body must be at most {} bytes, got {}",
+                max_bytes, actual_bytes
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WebhookError::InvalidSignature | WebhookError::PayloadTooLarge { .. } => None,
+            WebhookError::Signature(e) => Some(e),
+            WebhookError::ParseError(e) => Some(e),
+        }
+    }
+}
+
+impl From<SignatureValidationError> for WebhookError {
+    fn from(e: SignatureValidationError) -> Self {
+        WebhookError::Signature(e)
+    }
+}
@@ -0,0 +1,49 @@
+use line_bot_sdk_webhook::models::beacon_content::Type;
+use line_bot_sdk_webhook::models::{BeaconContent, Event};
+
+#[test]
+fn enter_beacon_event_parses() {
+    let event: Event = serde_json::from_str(
+        r#"{
+            "type": "beacon",
+            "timestamp": 1462629479859,
+            "source": {"type": "user", "userId": "U4af4980629..."},
+            "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+            "mode": "active",
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false},
+            "beacon": {"hwid": "d41d8cd98f", "type": "enter", "dm": "1234567890abcdef"}
+        }"#,
+    )
+    .unwrap();
+
+    let beacon = &event.as_beacon_event().unwrap().beacon;
+    assert_eq!(beacon.hwid, "d41d8cd98f");
+    assert_eq!(beacon.r#type, Type::Enter);
+    assert_eq!(
+        beacon.device_message_bytes(),
+        Some(vec![0x12, 0x34, 0x56, 0x78, 0x90, 0xab, 0xcd, 0xef])
+    );
+}
+
+#[test]
+fn device_message_bytes_accepts_upper_case_hex() {
+    let beacon = BeaconContent {
+        dm: Some("00FF7f".to_string()),
+        ..BeaconContent::new("d41d8cd98f".to_string(), Type::Banner)
+    };
+
+    assert_eq!(beacon.device_message_bytes(), Some(vec![0x00, 0xff, 0x7f]));
+}
+
+#[test]
+fn device_message_bytes_rejects_missing_or_invalid_hex() {
+    for dm in [None, Some("abc"), Some("zz"), Some("1234567g")] {
+        let beacon = BeaconContent {
+            dm: dm.map(str::to_string),
+            ..BeaconContent::new("d41d8cd98f".to_string(), Type::Stay)
+        };
+
+        assert_eq!(beacon.device_message_bytes(), None, "dm: {dm:?}");
+    }
+}
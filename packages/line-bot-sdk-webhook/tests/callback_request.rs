@@ -0,0 +1,92 @@
+use line_bot_sdk_webhook::models::CallbackRequest;
+
+const TEXT_MESSAGE_EVENT: &str = r#"{
+    "type": "message",
+    "message": {"type": "text", "id": "468789577898262530", "quoteToken": "q3Plxr4AgKd...", "text": "Hello"},
+    "webhookEventId": "01H810YECXQQZ37VAXPF6H9E6T",
+    "deliveryContext": {"isRedelivery": false},
+    "timestamp": 1692251666727,
+    "source": {"type": "user", "userId": "U4af4980629..."},
+    "replyToken": "38ef843bde154d9b91c21320ffd17a0f",
+    "mode": "active"
+}"#;
+
+/// A follow event without its required `follow` object.
+const BROKEN_FOLLOW_EVENT: &str = r#"{
+    "type": "follow",
+    "timestamp": 1462629479859,
+    "source": {"type": "user", "userId": "U4af4980629..."},
+    "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+    "mode": "active",
+    "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+    "deliveryContext": {"isRedelivery": false}
+}"#;
+
+#[test]
+fn parse_lenient_keeps_the_valid_events() {
+    let body = format!(
+        r#"{{"destination": "U0123456789abcdef0123456789abcdef", "events": [{BROKEN_FOLLOW_EVENT}, {TEXT_MESSAGE_EVENT}]}}"#
+    );
+
+    let (events, errors) = CallbackRequest::parse_lenient(body.as_bytes());
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0]
+            .as_message_event()
+            .unwrap()
+            .message
+            .as_text()
+            .unwrap()
+            .text,
+        "Hello"
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, Some(0));
+    assert_eq!(errors[0].raw.as_ref().unwrap()["type"], "follow");
+    assert!(errors[0].to_string().starts_with("error parsing event 0:"));
+}
+
+#[test]
+fn strict_parsing_rejects_the_whole_batch() {
+    let body = format!(
+        r#"{{"destination": "U0123456789abcdef0123456789abcdef", "events": [{TEXT_MESSAGE_EVENT}, {BROKEN_FOLLOW_EVENT}]}}"#
+    );
+
+    assert!(serde_json::from_str::<CallbackRequest>(&body).is_err());
+}
+
+#[test]
+fn parse_lenient_reports_a_malformed_body() {
+    let (events, errors) = CallbackRequest::parse_lenient(b"{\"events\": [");
+
+    assert!(events.is_empty());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, None);
+    assert!(errors[0].raw.is_none());
+}
+
+#[test]
+fn destination_and_redelivery_are_exposed() {
+    let redelivered =
+        TEXT_MESSAGE_EVENT.replace(r#""isRedelivery": false"#, r#""isRedelivery": true"#);
+    let body = format!(
+        r#"{{"destination": "U0123456789abcdef0123456789abcdef", "events": [{TEXT_MESSAGE_EVENT}, {redelivered}]}}"#
+    );
+
+    let request: CallbackRequest = serde_json::from_str(&body).unwrap();
+
+    assert_eq!(request.destination(), "U0123456789abcdef0123456789abcdef");
+    let events: Vec<_> = request
+        .events
+        .iter()
+        .map(|event| (event.webhook_event_id(), event.is_redelivery()))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            ("01H810YECXQQZ37VAXPF6H9E6T", false),
+            ("01H810YECXQQZ37VAXPF6H9E6T", true)
+        ]
+    );
+}
@@ -0,0 +1,155 @@
+use line_bot_sdk_webhook::models::{CallbackRequest, Event};
+
+/// A text message event, as sent by LINE.
+const TEXT_MESSAGE_EVENT: &str = r#"{
+    "type": "message",
+    "message": {
+        "type": "text",
+        "id": "468789577898262530",
+        "quoteToken": "q3Plxr4AgKd...",
+        "text": "Hello, world!"
+    },
+    "webhookEventId": "01H810YECXQQZ37VAXPF6H9E6T",
+    "deliveryContext": {"isRedelivery": false},
+    "timestamp": 1692251666727,
+    "source": {"type": "user", "userId": "U4af4980629..."},
+    "replyToken": "38ef843bde154d9b91c21320ffd17a0f",
+    "mode": "active"
+}"#;
+
+fn event(json: &str) -> Event {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn message_event_exposes_typed_fields() {
+    let event = event(TEXT_MESSAGE_EVENT);
+
+    assert_eq!(event.event_type(), "message");
+    assert_eq!(
+        event.reply_token(),
+        Some("38ef843bde154d9b91c21320ffd17a0f")
+    );
+    assert_eq!(event.timestamp(), 1692251666727);
+    assert_eq!(event.source().unwrap().user_id(), Some("U4af4980629..."));
+
+    let message_event = event.as_message_event().unwrap();
+    let text = message_event.message.as_text().unwrap();
+    assert_eq!(text.text, "Hello, world!");
+    assert!(event.as_postback_event().is_none());
+}
+
+#[test]
+fn callback_request_parses_a_webhook_body() {
+    let body = format!(
+        r#"{{"destination": "xxxxxxxxxx", "events": [{TEXT_MESSAGE_EVENT}, {{
+            "type": "follow",
+            "timestamp": 1462629479859,
+            "source": {{"type": "user", "userId": "U4af4980629..."}},
+            "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+            "mode": "active",
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {{"isRedelivery": false}},
+            "follow": {{"isUnblocked": false}}
+        }}]}}"#
+    );
+    let request: CallbackRequest = serde_json::from_str(&body).unwrap();
+
+    let reply_tokens: Vec<_> = request.events.iter().map(Event::reply_token).collect();
+    assert_eq!(
+        reply_tokens,
+        [
+            Some("38ef843bde154d9b91c21320ffd17a0f"),
+            Some("nHuyWiB7yP5Zw52FIkcQobQuGDXCTA")
+        ]
+    );
+    assert!(request.events[0].as_message_event().is_some());
+    assert!(request.events[1].as_message_event().is_none());
+}
+
+#[test]
+fn unsend_event_exposes_the_message_id() {
+    let event = event(
+        r#"{
+            "type": "unsend",
+            "mode": "active",
+            "timestamp": 1462629479859,
+            "source": {"type": "group", "groupId": "Ca56f94637c...", "userId": "U4af4980629..."},
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false},
+            "unsend": {"messageId": "325708"}
+        }"#,
+    );
+
+    assert_eq!(event.as_unsend_event().unwrap().unsend.message_id, "325708");
+    assert_eq!(event.reply_token(), None);
+}
+
+#[test]
+fn video_play_complete_event_exposes_the_tracking_id() {
+    let event = event(
+        r#"{
+            "type": "videoPlayComplete",
+            "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+            "mode": "active",
+            "timestamp": 1462629479859,
+            "source": {"type": "user", "userId": "U4af4980629..."},
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false},
+            "videoPlayComplete": {"trackingId": "track-id"}
+        }"#,
+    );
+
+    let video_play_complete = event.as_video_play_complete_event().unwrap();
+    assert_eq!(
+        video_play_complete.video_play_complete.tracking_id,
+        "track-id"
+    );
+    assert_eq!(event.reply_token(), Some("nHuyWiB7yP5Zw52FIkcQobQuGDXCTA"));
+}
+
+#[test]
+fn unknown_event_type_parses_as_unknown() {
+    let event = event(
+        r#"{
+            "type": "pollAnswered",
+            "mode": "active",
+            "timestamp": 1462629479859,
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false}
+        }"#,
+    );
+
+    assert_eq!(event.event_type(), "pollAnswered");
+    assert!(event.as_unknown().is_some());
+    assert!(event.as_unsend_event().is_none());
+}
+
+#[test]
+fn future_event_type_keeps_the_raw_payload() {
+    let payload = serde_json::json!({
+        "type": "someFutureEvent",
+        "mode": "active",
+        "timestamp": 1462629479859_i64,
+        "source": {"type": "user", "userId": "U4af4980629..."},
+        "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+        "deliveryContext": {"isRedelivery": true},
+        "someFutureField": {"nested": [1, 2, 3]}
+    });
+    let body = serde_json::json!({"destination": "Uxxxxxxxxxx", "events": [payload]});
+
+    let request: CallbackRequest = serde_json::from_value(body).unwrap();
+
+    let event = &request.events[0];
+    assert!(matches!(event, Event::Unknown(_)));
+    assert_eq!(event.as_unknown(), Some(&payload));
+    assert_eq!(event.event_type(), "someFutureEvent");
+    assert_eq!(event.webhook_event_id(), "01FZ74A0TDDPYRVKNK77XKC3ZR");
+    assert!(event.is_redelivery());
+    assert_eq!(event.source(), None);
+    assert_eq!(
+        serde_json::to_value(event).unwrap(),
+        payload,
+        "re-serializing an unknown event should give back the original payload"
+    );
+}
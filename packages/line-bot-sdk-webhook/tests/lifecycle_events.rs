@@ -0,0 +1,64 @@
+use line_bot_sdk_webhook::models::Event;
+
+#[test]
+fn follow_event_parses() {
+    let event: Event = serde_json::from_str(
+        r#"{
+            "type": "follow",
+            "timestamp": 1462629479859,
+            "source": {"type": "user", "userId": "U4af4980629..."},
+            "replyToken": "85cbe770fa8b4f45bbe077b1d4be4a36",
+            "mode": "active",
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false},
+            "follow": {"isUnblocked": true}
+        }"#,
+    )
+    .unwrap();
+
+    let follow = event.as_follow_event().unwrap();
+    assert!(follow.follow.is_unblocked);
+    assert_eq!(
+        event.reply_token(),
+        Some("85cbe770fa8b4f45bbe077b1d4be4a36")
+    );
+    assert_eq!(event.source().unwrap().user_id(), Some("U4af4980629..."));
+    assert!(event.as_unfollow_event().is_none());
+}
+
+#[test]
+fn member_joined_event_lists_the_joined_members() {
+    let event: Event = serde_json::from_str(
+        r#"{
+            "type": "memberJoined",
+            "timestamp": 1462629479960,
+            "source": {"type": "group", "groupId": "C4af4980629..."},
+            "replyToken": "0f3779fba3b349968c5d07db31eabf65",
+            "mode": "active",
+            "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+            "deliveryContext": {"isRedelivery": false},
+            "joined": {
+                "members": [
+                    {"type": "user", "userId": "U4af4980629..."},
+                    {"type": "user", "userId": "U91eeaf62d9..."}
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let member_joined = event.as_member_joined_event().unwrap();
+    let user_ids: Vec<_> = member_joined
+        .joined
+        .members
+        .iter()
+        .map(|member| member.user_id.as_deref())
+        .collect();
+    assert_eq!(user_ids, [Some("U4af4980629..."), Some("U91eeaf62d9...")]);
+    assert_eq!(event.source().unwrap().group_id(), Some("C4af4980629..."));
+    assert_eq!(
+        event.reply_token(),
+        Some("0f3779fba3b349968c5d07db31eabf65")
+    );
+    assert!(event.as_member_left_event().is_none());
+}
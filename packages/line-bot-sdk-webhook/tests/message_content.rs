@@ -0,0 +1,66 @@
+use line_bot_sdk_webhook::models::{MessageContent, MessageEvent};
+
+/// A text message event with LINE emojis and a mention, as sent by LINE.
+const TEXT_MESSAGE_EVENT: &str = r#"{
+    "replyToken": "nHuyWiB7yP5Zw52FIkcQobQuGDXCTA",
+    "type": "message",
+    "mode": "active",
+    "timestamp": 1462629479859,
+    "source": {"type": "group", "groupId": "Ca56f94637c...", "userId": "U4af4980629..."},
+    "webhookEventId": "01FZ74A0TDDPYRVKNK77XKC3ZR",
+    "deliveryContext": {"isRedelivery": false},
+    "message": {
+        "id": "444573844083572737",
+        "type": "text",
+        "quoteToken": "q3Plxr4AgKd...",
+        "text": "@All @example Good Morning!! (love)",
+        "emojis": [
+            {"index": 29, "length": 6, "productId": "5ac1bfd5040ab15980c9b435", "emojiId": "001"}
+        ],
+        "mention": {
+            "mentionees": [
+                {"index": 0, "length": 4, "type": "all"},
+                {"index": 5, "length": 8, "userId": "U49585cd0d5...", "type": "user", "isSelf": false}
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn text_message_event_parses_into_text_content() {
+    let event: MessageEvent = serde_json::from_str(TEXT_MESSAGE_EVENT).unwrap();
+    assert!(matches!(
+        *event.message,
+        MessageContent::TextMessageContent(_)
+    ));
+
+    let text = event.message.as_text().unwrap();
+    assert_eq!(text.id, "444573844083572737");
+    assert_eq!(text.text, "@All @example Good Morning!! (love)");
+    assert_eq!(text.quote_token, "q3Plxr4AgKd...");
+
+    let emojis = text.emojis.as_ref().unwrap();
+    assert_eq!(emojis.len(), 1);
+    assert_eq!(emojis[0].index, 29);
+    assert_eq!(emojis[0].product_id, "5ac1bfd5040ab15980c9b435");
+
+    let mentionees = &text.mention.as_ref().unwrap().mentionees;
+    assert_eq!(mentionees.len(), 2);
+    assert_eq!(text.mentioned_user_ids(), ["U49585cd0d5..."]);
+}
+
+#[test]
+fn other_message_types_are_not_text() {
+    let sticker = r#"{
+        "type": "sticker",
+        "id": "1501597916",
+        "quoteToken": "q3Plxr4AgKd...",
+        "stickerId": "52002738",
+        "packageId": "11537",
+        "stickerResourceType": "ANIMATION",
+        "keywords": ["cony", "sally", "Staring", "hi"]
+    }"#;
+    let content: MessageContent = serde_json::from_str(sticker).unwrap();
+    assert!(content.as_text().is_none());
+    assert_eq!(content.as_sticker().unwrap().sticker_id, "52002738");
+}
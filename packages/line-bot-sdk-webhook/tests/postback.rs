@@ -0,0 +1,87 @@
+use line_bot_sdk_webhook::models::{Event, PostbackContent, PostbackParams};
+
+fn postback_event(postback: &str) -> Event {
+    serde_json::from_str(&format!(
+        r#"{{
+            "type": "postback",
+            "source": {{"type": "user", "userId": "U4af4980629..."}},
+            "timestamp": 1692251666727,
+            "mode": "active",
+            "webhookEventId": "01H810YECXQQZ37VAXPF6H9E6T",
+            "deliveryContext": {{"isRedelivery": false}},
+            "replyToken": "b60d432864f44d079f6d8efe86cf404b",
+            "postback": {postback}
+        }}"#
+    ))
+    .unwrap()
+}
+
+fn postback(event: &Event) -> &PostbackContent {
+    &event.as_postback_event().unwrap().postback
+}
+
+#[test]
+fn date_picker_params() {
+    let event = postback_event(r#"{"data": "storeId=12345", "params": {"date": "2017-09-03"}}"#);
+
+    assert_eq!(
+        postback(&event).params,
+        Some(PostbackParams {
+            date: Some("2017-09-03".to_string()),
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn time_picker_params() {
+    let event = postback_event(r#"{"data": "storeId=12345", "params": {"time": "14:10"}}"#);
+
+    let params = postback(&event).params.as_ref().unwrap();
+    assert_eq!(params.time.as_deref(), Some("14:10"));
+    assert_eq!(params.date, None);
+}
+
+#[test]
+fn datetime_picker_params() {
+    let event =
+        postback_event(r#"{"data": "storeId=12345", "params": {"datetime": "2017-09-03T14:10"}}"#);
+
+    let postback = postback(&event);
+    assert_eq!(postback.data, "storeId=12345");
+    assert_eq!(
+        postback.params.as_ref().unwrap().datetime.as_deref(),
+        Some("2017-09-03T14:10")
+    );
+}
+
+#[test]
+fn rich_menu_switch_params() {
+    let event = postback_event(
+        r#"{"data": "richmenu-changed-to-b", "params": {"newRichMenuAliasId": "richmenu-alias-b", "status": "SUCCESS"}}"#,
+    );
+
+    assert_eq!(
+        postback(&event).params,
+        Some(PostbackParams {
+            new_rich_menu_alias_id: Some("richmenu-alias-b".to_string()),
+            status: Some("SUCCESS".to_string()),
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn postback_without_params() {
+    let event = postback_event(r#"{"data": "action=buy&itemid=111"}"#);
+
+    assert_eq!(postback(&event).data, "action=buy&itemid=111");
+    assert_eq!(postback(&event).params, None);
+}
+
+#[test]
+fn postback_with_empty_params() {
+    let event = postback_event(r#"{"data": "action=buy&itemid=111", "params": {}}"#);
+
+    assert_eq!(postback(&event).params, Some(PostbackParams::default()));
+}
@@ -0,0 +1,54 @@
+use line_bot_sdk_webhook::models::Source;
+
+fn source(json: &str) -> Source {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn user_source() {
+    let source = source(r#"{"type": "user", "userId": "U4af4980629..."}"#);
+
+    assert!(matches!(source, Source::UserSource(_)));
+    assert_eq!(source.user_id(), Some("U4af4980629..."));
+    assert_eq!(source.group_id(), None);
+    assert_eq!(source.room_id(), None);
+    assert_eq!(source.id(), "U4af4980629...");
+}
+
+#[test]
+fn group_source() {
+    let source =
+        source(r#"{"type": "group", "groupId": "Ca56f94637c...", "userId": "U4af4980629..."}"#);
+
+    assert!(matches!(source, Source::GroupSource(_)));
+    assert_eq!(source.user_id(), Some("U4af4980629..."));
+    assert_eq!(source.group_id(), Some("Ca56f94637c..."));
+    assert_eq!(source.room_id(), None);
+    assert_eq!(source.id(), "Ca56f94637c...");
+}
+
+#[test]
+fn room_source() {
+    let source = source(r#"{"type": "room", "roomId": "Ra8dbf4673c..."}"#);
+
+    assert!(matches!(source, Source::RoomSource(_)));
+    assert_eq!(source.user_id(), None);
+    assert_eq!(source.group_id(), None);
+    assert_eq!(source.room_id(), Some("Ra8dbf4673c..."));
+    assert_eq!(source.id(), "Ra8dbf4673c...");
+}
+
+#[test]
+fn user_source_without_a_user_id_has_an_empty_id() {
+    let source = source(r#"{"type": "user"}"#);
+
+    assert_eq!(source.user_id(), None);
+    assert_eq!(source.id(), "");
+}
+
+#[test]
+fn unknown_source_type_is_rejected() {
+    let err = serde_json::from_str::<Source>(r#"{"type": "square"}"#).unwrap_err();
+
+    assert!(err.to_string().contains("unknown source type `square`"));
+}